@@ -0,0 +1,91 @@
+// Output plumbing shared by the example crates.
+//
+// Each example crate starts with `#[macro_use] extern crate chapter;`, which
+// replaces the standard `print!` and `println!` with the versions below. They
+// still go to stdout by default, but a caller can redirect everything a
+// chapter prints into its own `std::io::Write` with `capture`. `snapshot`
+// checks output against golden files.
+
+pub mod snapshot;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write};
+
+thread_local! {
+    // One buffer per `capture` call that is still running on this thread.
+    static SINKS: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+#[macro_export]
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::print_fmt(format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! println {
+    () => {
+        $crate::print_fmt(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::print_fmt(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+// Writes to the innermost sink, or to stdout when nothing is capturing.
+#[doc(hidden)]
+pub fn print_fmt(args: fmt::Arguments) {
+    let captured = SINKS.with(|sinks| match sinks.borrow_mut().last_mut() {
+        Some(sink) => {
+            // Writing into a `Vec<u8>` never fails.
+            sink.write_fmt(args).unwrap();
+            true
+        }
+        None => false,
+    });
+    if !captured {
+        std::print!("{args}");
+    }
+}
+
+// Pops the buffer of a `capture` call and hands it to its writer. Also runs
+// when the chapter unwinds, so the output up to a panic is never lost.
+struct Capture<'a> {
+    out: &'a mut dyn Write,
+    done: bool,
+}
+
+impl Capture<'_> {
+    fn finish(&mut self) -> io::Result<()> {
+        self.done = true;
+        let buffer = SINKS.with(|sinks| sinks.borrow_mut().pop()).unwrap_or_default();
+        self.out.write_all(&buffer)
+    }
+}
+
+impl Drop for Capture<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            let _ = self.finish();
+        }
+    }
+}
+
+// Runs `f`, sending everything it prints with `print!`/`println!` to `out`
+// instead of stdout. Captures nest: the innermost one gets the output.
+pub fn capture<F: FnOnce()>(out: &mut dyn Write, f: F) -> io::Result<()> {
+    SINKS.with(|sinks| sinks.borrow_mut().push(Vec::new()));
+    let mut capture = Capture { out, done: false };
+    f();
+    capture.finish()
+}
+
+// Convenience wrapper around `capture` that collects the output as text.
+pub fn capture_string<F: FnOnce()>(f: F) -> String {
+    let mut out = Vec::new();
+    // Writing into a `Vec<u8>` never fails.
+    capture(&mut out, f).unwrap();
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,121 @@
+// Golden-file checks shared by the runner's chapter snapshots and the example
+// crates' own golden tests.
+//
+// `check` compares output with the file at a path and reports any drift as a
+// line diff. Files are only ever written when `UPDATE_SNAPSHOTS` is set, so a
+// missing file fails like a changed one instead of quietly being recorded.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Unchanged lines shown around each change in a diff.
+const CONTEXT: usize = 2;
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// Whether checks should record their output instead of comparing it.
+pub fn updating() -> bool {
+    env::var_os("UPDATE_SNAPSHOTS").is_some()
+}
+
+// Compares `actual` with the file at `path`, or records it there when
+// `UPDATE_SNAPSHOTS` is set. The error says what differs and how to accept it.
+pub fn check(path: &Path, actual: &str) -> Result<(), String> {
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(format!("can't read {}: {e}", path.display())),
+    };
+    if expected.as_deref() == Some(actual) {
+        return Ok(());
+    }
+    if updating() {
+        let write = fs::create_dir_all(path.parent().unwrap()).and_then(|()| fs::write(path, actual));
+        return write.map_err(|e| format!("can't write {}: {e}", path.display()));
+    }
+    match expected {
+        None => Err(format!(
+            "{} is missing (rerun with UPDATE_SNAPSHOTS=1 to record it)\n",
+            path.display()
+        )),
+        Some(expected) => Err(format!(
+            "{} differs (rerun with UPDATE_SNAPSHOTS=1 to accept it)\n{}",
+            path.display(),
+            render(&diff(&expected, actual))
+        )),
+    }
+}
+
+// Line diff from `expected` to `actual` using the longest common subsequence.
+fn diff<'a>(expected: &'a str, actual: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] is the LCS length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+// Renders a diff with `-`/`+` markers, keeping only the context around changes.
+fn render(lines: &[Line]) -> String {
+    let changed: Vec<bool> = lines.iter().map(|l| !matches!(l, Line::Same(_))).collect();
+    let near_change = |i: usize| {
+        let lo = i.saturating_sub(CONTEXT);
+        let hi = (i + CONTEXT + 1).min(lines.len());
+        changed[lo..hi].iter().any(|&c| c)
+    };
+
+    let mut out = String::new();
+    let mut skipped = false;
+    for (i, line) in lines.iter().enumerate() {
+        if !near_change(i) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            out.push_str("  ...\n");
+            skipped = false;
+        }
+        let (mark, text) = match line {
+            Line::Same(text) => (' ', text),
+            Line::Removed(text) => ('-', text),
+            Line::Added(text) => ('+', text),
+        };
+        out.push_str(&format!("{mark} {text}\n"));
+    }
+    if out.is_empty() {
+        out.push_str("  (only the trailing newline differs)\n");
+    }
+    out
+}
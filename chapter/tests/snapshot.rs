@@ -0,0 +1,37 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use chapter::snapshot;
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("chapter-snapshot-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn a_missing_file_fails_instead_of_being_recorded() {
+    // Recording is exactly what this checks doesn't happen.
+    if snapshot::updating() {
+        return;
+    }
+    let path = scratch("missing.txt");
+    let error = snapshot::check(&path, "output\n").unwrap_err();
+    assert!(error.contains("is missing"), "{error}");
+    assert!(!path.exists());
+}
+
+#[test]
+fn drift_is_shown_as_a_diff() {
+    if snapshot::updating() {
+        return;
+    }
+    let path = scratch("drift.txt");
+    fs::write(&path, "one\ntwo\nthree\n").unwrap();
+    assert_eq!(snapshot::check(&path, "one\ntwo\nthree\n"), Ok(()));
+    let error = snapshot::check(&path, "one\n2\nthree\n").unwrap_err();
+    assert!(error.ends_with("  one\n- two\n+ 2\n  three\n"), "{error}");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "one\ntwo\nthree\n");
+}
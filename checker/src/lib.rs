@@ -0,0 +1,315 @@
+// Checks the "try uncommenting" exercises in the example crates.
+//
+// An exercise is a commented-out line followed by a marker such as
+//
+//     // Error! `a` can no longer access the data
+//     // println!("a contains: {}", a);
+//     // TODO ^ Try uncommenting this line (E0382)
+//
+// The error code at the end of the marker is the error rustc is expected to
+// report once the line is uncommented. The checker copies the crate to a
+// scratch directory, uncomments the line there and compiles it with the local
+// `rustc` to see whether that error still happens.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// The example crates that contain exercises.
+pub const CRATES: &[&str] = &[
+    "hello_world",
+    "primitives",
+    "custom_types",
+    "flow_control",
+    "functions",
+    "modules",
+    "crates",
+    "generics",
+    "scoping_rules",
+    "traits",
+    "macro_rules",
+    "error_handling",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub krate: String,
+    // Path of the source file relative to the crate's `src` directory.
+    pub file: PathBuf,
+    // 1-based line number of the marker.
+    pub marker_line: usize,
+    // 0-based indices of the lines to uncomment.
+    pub lines: Vec<usize>,
+    // Error codes rustc should report, e.g. `E0382`.
+    pub expected: Vec<String>,
+    // The first uncommented line, for reports.
+    pub snippet: String,
+}
+
+impl Exercise {
+    // `crate/src/file.rs:line`, for reports.
+    pub fn location(&self) -> String {
+        format!(
+            "{}/src/{}:{}",
+            self.krate,
+            self.file.display(),
+            self.marker_line
+        )
+    }
+}
+
+// How many commented lines above `marker` belong to the exercise, or `None`
+// when the comment is not an exercise marker.
+fn marker_span(marker: &str) -> Option<Span> {
+    let lower = marker.to_lowercase();
+    if !lower.contains("todo") {
+        return None;
+    }
+    if lower.contains("try uncommenting these lines") {
+        Some(Span::Block)
+    } else if lower.contains("uncomment two lines above") {
+        Some(Span::Lines(2))
+    } else if lower.contains("try uncommenting") {
+        Some(Span::Lines(1))
+    } else {
+        None
+    }
+}
+
+enum Span {
+    Lines(usize),
+    // Every commented line directly above the marker.
+    Block,
+}
+
+// `E0382`-style codes mentioned in `text`.
+fn error_codes(text: &str) -> Vec<String> {
+    let bytes = text.as_bytes();
+    let mut codes: Vec<String> = Vec::new();
+    for (i, _) in text.match_indices('E') {
+        let digits = &bytes[i + 1..(i + 5).min(bytes.len())];
+        let ends = bytes.get(i + 5).is_none_or(|b| !b.is_ascii_alphanumeric());
+        if digits.len() == 4 && digits.iter().all(u8::is_ascii_digit) && ends {
+            let code = text[i..i + 5].to_string();
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+    }
+    codes
+}
+
+fn comment_of(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("//")
+}
+
+// Finds the exercises in one source file.
+pub fn parse_exercises(krate: &str, file: &Path, source: &str) -> Vec<Exercise> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut exercises = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let Some(span) = comment_of(line).and_then(marker_span) else {
+            continue;
+        };
+        let commented = lines[..index]
+            .iter()
+            .rev()
+            .take_while(|l| comment_of(l).is_some())
+            .count();
+        let count = match span {
+            Span::Lines(n) => n.min(commented),
+            Span::Block => commented,
+        };
+        let first = index - count;
+        exercises.push(Exercise {
+            krate: krate.to_string(),
+            file: file.to_path_buf(),
+            marker_line: index + 1,
+            lines: (first..index).collect(),
+            expected: error_codes(line),
+            snippet: uncomment(lines[first]).trim().to_string(),
+        });
+    }
+    exercises
+}
+
+// Finds the exercises in every source file of `krate`.
+pub fn find_exercises(workspace: &Path, krate: &str) -> io::Result<Vec<Exercise>> {
+    let src = workspace.join(krate).join("src");
+    let mut files = Vec::new();
+    collect_sources(&src, &mut files)?;
+    files.sort();
+
+    let mut exercises = Vec::new();
+    for path in files {
+        let source = fs::read_to_string(&path)?;
+        let file = path.strip_prefix(&src).unwrap();
+        exercises.extend(parse_exercises(krate, file, &source));
+    }
+    Ok(exercises)
+}
+
+fn collect_sources(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_sources(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, &target)?;
+        }
+    }
+    Ok(())
+}
+
+// Removes the `//` (and one following space) from a commented-out line,
+// keeping its indentation.
+fn uncomment(line: &str) -> String {
+    let indent = line.len() - line.trim_start().len();
+    let code = comment_of(line).unwrap_or(line);
+    let code = code.strip_prefix(' ').unwrap_or(code);
+    format!("{}{}", &line[..indent], code)
+}
+
+// What rustc said about an exercise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    pub exercise: Exercise,
+    // Error codes rustc reported with the lines uncommented.
+    pub found: Vec<String>,
+}
+
+impl Checked {
+    pub fn passed(&self) -> bool {
+        !self.exercise.expected.is_empty()
+            && self.exercise.expected.iter().all(|code| self.found.contains(code))
+    }
+}
+
+// Compiles variants of the example crates in a scratch directory that is
+// removed again when the checker is dropped.
+pub struct Checker {
+    workspace: PathBuf,
+    scratch: PathBuf,
+    rustc: OsString,
+}
+
+impl Checker {
+    // Prepares the scratch directory and builds the `chapter` crate that
+    // every example depends on.
+    pub fn new(workspace: &Path) -> io::Result<Checker> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let scratch = env::temp_dir().join(format!(
+            "exercise-checker-{}-{}",
+            process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&scratch)?;
+        let checker = Checker {
+            workspace: workspace.to_path_buf(),
+            scratch,
+            rustc: env::var_os("RUSTC").unwrap_or_else(|| OsString::from("rustc")),
+        };
+
+        let chapter = workspace.join("chapter").join("src").join("lib.rs");
+        let output = checker.rustc("chapter", &chapter).output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "can't build the `chapter` crate:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+        Ok(checker)
+    }
+
+    fn rustc(&self, krate: &str, root: &Path) -> Command {
+        let mut command = Command::new(&self.rustc);
+        command
+            .args(["--edition", "2021", "--crate-type", "lib", "--emit", "metadata"])
+            .args(["--error-format", "short", "--cap-lints", "allow"])
+            .args(["--crate-name", krate])
+            .arg("--out-dir")
+            .arg(self.scratch.join("out"))
+            .arg("--extern")
+            .arg(format!("chapter={}", self.scratch.join("out").join("libchapter.rmeta").display()))
+            .arg(root);
+        command
+    }
+
+    // Copies `krate` into the scratch directory, applies `edit` to `file` and
+    // returns the error codes rustc reports for the result.
+    fn compile_with(
+        &self,
+        krate: &str,
+        file: &Path,
+        edit: impl FnOnce(&str) -> String,
+    ) -> io::Result<Vec<String>> {
+        let src = self.scratch.join(krate).join("src");
+        if src.exists() {
+            fs::remove_dir_all(&src)?;
+        }
+        copy_dir(&self.workspace.join(krate).join("src"), &src)?;
+
+        let path = src.join(file);
+        let source = fs::read_to_string(&path)?;
+        fs::write(&path, edit(&source))?;
+
+        let output = self.rustc(krate, &src.join("lib.rs")).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut codes = Vec::new();
+        for line in stderr.lines().filter(|l| l.contains("error[E")) {
+            for code in error_codes(line) {
+                if !codes.contains(&code) {
+                    codes.push(code);
+                }
+            }
+        }
+        if !output.status.success() && codes.is_empty() {
+            // An error without a code, e.g. a syntax error.
+            codes.push(String::from("error"));
+        }
+        Ok(codes)
+    }
+
+    // Error codes reported for `krate` as it is, which should be none.
+    pub fn baseline(&self, krate: &str) -> io::Result<Vec<String>> {
+        self.compile_with(krate, Path::new("lib.rs"), str::to_string)
+    }
+
+    pub fn check(&self, exercise: &Exercise) -> io::Result<Checked> {
+        let found = self.compile_with(&exercise.krate, &exercise.file, |source| {
+            let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+            for &index in &exercise.lines {
+                lines[index] = uncomment(&lines[index]);
+            }
+            lines.join("\n") + "\n"
+        })?;
+        Ok(Checked {
+            exercise: exercise.clone(),
+            found,
+        })
+    }
+}
+
+impl Drop for Checker {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.scratch);
+    }
+}
@@ -0,0 +1,102 @@
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+use checker::{find_exercises, Checked, Checker, CRATES};
+
+const USAGE: &str = "\
+usage: checker [CRATE...]
+
+Uncomments each `TODO ^ Try uncommenting` exercise in the given example
+crates (all of them by default), compiles it with the local `rustc` and
+reports whether the error code named in the marker still occurs.";
+
+fn codes(codes: &[String]) -> String {
+    if codes.is_empty() {
+        String::from("-")
+    } else {
+        codes.join(",")
+    }
+}
+
+fn check(krates: &[&str]) -> io::Result<Vec<Checked>> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let checker = Checker::new(workspace)?;
+
+    let mut results = Vec::new();
+    for krate in krates {
+        let baseline = checker.baseline(krate)?;
+        if !baseline.is_empty() {
+            return Err(io::Error::other(format!(
+                "`{krate}` does not compile as it is ({}), fix that first",
+                codes(&baseline)
+            )));
+        }
+        for exercise in find_exercises(workspace, krate)? {
+            results.push(checker.check(&exercise)?);
+        }
+    }
+    Ok(results)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    if let Some(unknown) = args.iter().find(|a| !CRATES.contains(&a.as_str())) {
+        eprintln!("error: `{unknown}` is not an example crate\n\n{USAGE}");
+        return ExitCode::from(2);
+    }
+    let krates: Vec<&str> = if args.is_empty() {
+        CRATES.to_vec()
+    } else {
+        args.iter().map(String::as_str).collect()
+    };
+
+    let results = match check(&krates) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let width = results
+        .iter()
+        .map(|c| c.exercise.location().len())
+        .chain(Some("exercise".len()))
+        .max()
+        .unwrap();
+    println!("{:width$}  {:8}  {:8}  result    code", "exercise", "expected", "got");
+    for checked in &results {
+        let result = match (checked.passed(), checked.exercise.expected.is_empty()) {
+            (true, _) => "ok",
+            (false, true) => "NO CODE",
+            (false, false) => "MISMATCH",
+        };
+        println!(
+            "{:width$}  {:8}  {:8}  {:8}  {}",
+            checked.exercise.location(),
+            codes(&checked.exercise.expected),
+            codes(&checked.found),
+            result,
+            checked.exercise.snippet
+        );
+    }
+
+    let failed = results.iter().filter(|c| !c.passed()).count();
+    println!(
+        "{} exercises: {} confirmed, {} failed",
+        results.len(),
+        results.len() - failed,
+        failed
+    );
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
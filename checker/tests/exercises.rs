@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use checker::{find_exercises, parse_exercises, Checker, CRATES};
+
+#[test]
+fn markers_select_the_commented_lines_above_them() {
+    let source = "\
+fn f() {
+    // Error! `a` was moved
+    // println!(\"{}\", a);
+    // TODO ^ Try uncommenting this line (E0382)
+
+    // first();
+    // second();
+    // ^ TODO: Try uncommenting these lines (E0382).
+
+    // one();
+    // two();
+    // TODO: uncomment two lines above and see compiler errors (E0382, E0505).
+    _ => unreachable!(),
+    // TODO ^ uncomment to fix compilation
+}
+";
+    let exercises = parse_exercises("demo", Path::new("lib.rs"), source);
+    let spans: Vec<(usize, Vec<usize>, Vec<String>)> = exercises
+        .into_iter()
+        .map(|e| (e.marker_line, e.lines, e.expected))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            (4, vec![2], vec!["E0382".to_string()]),
+            (8, vec![5, 6], vec!["E0382".to_string()]),
+            (12, vec![9, 10], vec!["E0382".to_string(), "E0505".to_string()]),
+        ]
+    );
+}
+
+// Compiles every exercise, so this takes a few seconds.
+#[test]
+fn every_exercise_still_fails_with_its_error_code() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let checker = Checker::new(workspace).unwrap();
+
+    let mut failures = Vec::new();
+    for krate in CRATES {
+        assert_eq!(checker.baseline(krate).unwrap(), Vec::<String>::new(), "{krate}");
+        for exercise in find_exercises(workspace, krate).unwrap() {
+            let checked = checker.check(&exercise).unwrap();
+            if !checked.passed() {
+                failures.push(format!(
+                    "{}: expected {:?}, got {:?}",
+                    exercise.location(),
+                    exercise.expected,
+                    checked.found
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
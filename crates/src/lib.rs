@@ -1,6 +1,12 @@
-// extern crate rary;
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
 
-fn main() {
+// `rary.rs` is meant to be compiled on its own with `rustc --crate-type=lib`
+// and linked with `--extern`; inside the workspace Cargo builds it as a module.
+mod rary;
+
+pub fn extern_crate() {
     rary::public_function();
 
     // Error! `private_function` is private
@@ -11,7 +17,7 @@ fn main() {
 
 // # Where library.rlib is the path to the compiled library, assumed that it's
 // # in the same directory here:
-// $ rustc executable.rs --extern rary=library.rlib && ./executable 
+// $ rustc executable.rs --extern rary=library.rlib && ./executable
 // called rary's `public_function()`
 // called rary's `indirect_access()`, that
-// > called rary's `private_function()`
\ No newline at end of file
+// > called rary's `private_function()`
@@ -0,0 +1,121 @@
+// Times `Quadtree` against scanning every rectangle, for hit-testing clicks
+// against on-screen regions, range queries and nearest neighbours.
+//
+//     cargo bench -p custom_types --bench quadtree
+//
+// Written against `std::time` rather than a benchmarking crate, so the
+// numbers are rough: each case runs a fixed number of times and reports the
+// mean. Bulk loading isn't faster to build than inserting; what it buys is a
+// root fitted to the data.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use custom_types::geometry::{ItemId, Point, Quadtree, Rectangle, Shape};
+use testutil::Rng;
+
+const SCREEN: f64 = 4000.0;
+
+// A click anywhere on the screen.
+fn click(rng: &mut Rng) -> Point {
+    Point::new(rng.unit() * SCREEN, rng.unit() * SCREEN)
+}
+
+// A button-sized region.
+fn region(rng: &mut Rng) -> Rectangle {
+    Rectangle::from_size(click(rng), 10.0 + rng.unit() * 90.0, 10.0 + rng.unit() * 40.0)
+}
+
+// The mean time of `run` over `times` runs.
+fn time<R>(times: u32, mut run: impl FnMut() -> R) -> Duration {
+    let start = Instant::now();
+    for _ in 0..times {
+        black_box(run());
+    }
+    start.elapsed() / times
+}
+
+fn inserted_tree(regions: &[Rectangle]) -> Quadtree<()> {
+    let bounds = Rectangle::from_size(Point::origin(), SCREEN + 100.0, SCREEN + 50.0);
+    let mut tree = Quadtree::new(bounds);
+    for &r in regions {
+        tree.insert(r, ());
+    }
+    tree
+}
+
+fn row(case: &str, tree: Duration, scan: Duration) {
+    let speedup = scan.as_secs_f64() / tree.as_secs_f64();
+    println!("{case:<24} {:>12.2?} {:>12.2?} {speedup:>9.1}x", tree, scan);
+}
+
+fn main() {
+    for count in [1_000, 10_000, 100_000] {
+        let mut rng = Rng(0x853C_49E6_748F_EA9B);
+        let regions: Vec<Rectangle> = (0..count).map(|_| region(&mut rng)).collect();
+        let clicks: Vec<Point> = (0..1000).map(|_| click(&mut rng)).collect();
+        let areas: Vec<Rectangle> =
+            (0..1000).map(|_| Rectangle::from_size(click(&mut rng), 200.0, 200.0)).collect();
+
+        println!("{count} regions");
+        println!("{:<24} {:>12} {:>12} {:>10}", "", "quadtree", "scan", "");
+
+        let bulk = time(5, || Quadtree::bulk_load(regions.iter().map(|&r| (r, ())).collect()));
+        let scan = time(5, || regions.to_vec());
+        row("bulk load / copy", bulk, scan);
+        let inserted = time(5, || inserted_tree(&regions));
+        row("insert one by one", inserted, scan);
+
+        // Every other region, from a tree built by inserting; a flat list
+        // drops them in one pass.
+        let built = inserted_tree(&regions);
+        let ids: Vec<ItemId> = built.iter().map(|(id, _, _)| id).step_by(2).collect();
+        let tree_remove = time(5, || {
+            let mut tree = built.clone();
+            for &id in &ids {
+                tree.remove(id);
+            }
+            tree
+        });
+        let scan_remove = time(5, || {
+            let mut list = regions.to_vec();
+            let mut keep = false;
+            list.retain(|_| {
+                keep = !keep;
+                !keep
+            });
+            list
+        });
+        row("remove half", tree_remove, scan_remove);
+
+        let tree = Quadtree::bulk_load(regions.iter().map(|&r| (r, ())).collect());
+        let tree_hits = time(3, || clicks.iter().map(|&c| tree.query_point(c).len()).sum::<usize>());
+        let scan_hits = time(3, || {
+            clicks
+                .iter()
+                .map(|&c| regions.iter().filter(|r| r.contains(c)).count())
+                .sum::<usize>()
+        });
+        row("1000 clicks", tree_hits, scan_hits);
+
+        let tree_range = time(3, || areas.iter().map(|a| tree.query(a).len()).sum::<usize>());
+        let scan_range = time(3, || {
+            areas.iter().map(|a| regions.iter().filter(|r| r.intersects(a)).count()).sum::<usize>()
+        });
+        row("1000 range queries", tree_range, scan_range);
+
+        let tree_knn = time(3, || clicks.iter().map(|&c| tree.nearest(c, 5).len()).sum::<usize>());
+        let scan_knn = time(3, || {
+            clicks
+                .iter()
+                .map(|&c| {
+                    let mut distances: Vec<f64> = regions.iter().map(|r| r.distance_to(c)).collect();
+                    distances.select_nth_unstable_by(4, f64::total_cmp);
+                    distances.len()
+                })
+                .sum::<usize>()
+        });
+        row("1000 x 5 nearest", tree_knn, scan_knn);
+        println!();
+    }
+}
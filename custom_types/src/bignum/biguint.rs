@@ -0,0 +1,485 @@
+// Unsigned integers of any size, as little-endian base 2^32 limbs.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::ParseNumberError;
+
+// Below this many limbs in the shorter operand Karatsuba splits cost more
+// than they save.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+// The largest power of ten in a limb, for converting to and from decimal.
+const TEN_9: u32 = 1_000_000_000;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // Least significant first, without trailing zeros, so zero is empty.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint::default()
+    }
+
+    pub fn one() -> BigUint {
+        BigUint::from(1u32)
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    // How many bits the number needs, 0 for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(self.limbs.iter().rev().fold(0, |n, &limb| n << 32 | limb as u128))
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result *= &base;
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    pub fn checked_sub(&self, rhs: &BigUint) -> Option<BigUint> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut limbs = self.limbs.clone();
+        let mut borrow = 0;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let r = rhs.limbs.get(i).copied().unwrap_or(0) as u64 + borrow;
+            if i >= rhs.limbs.len() && borrow == 0 {
+                break;
+            }
+            let (d, under) = (*limb as u64).overflowing_sub(r);
+            *limb = d as u32;
+            borrow = under as u64;
+        }
+        Some(BigUint::from_limbs(limbs))
+    }
+
+    // `self * factor + addend`, for small factors.
+    fn mul_add_small(&self, factor: u32, addend: u32) -> BigUint {
+        let mut carry = addend as u64;
+        let mut limbs: Vec<u32> = self
+            .limbs
+            .iter()
+            .map(|&limb| {
+                let n = limb as u64 * factor as u64 + carry;
+                carry = n >> 32;
+                n as u32
+            })
+            .collect();
+        limbs.push(carry as u32);
+        BigUint::from_limbs(limbs)
+    }
+
+    // Quotient and remainder by a non-zero limb.
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut rem = 0u64;
+        let mut limbs = vec![0; self.limbs.len()];
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let n = rem << 32 | limb as u64;
+            limbs[i] = (n / divisor as u64) as u32;
+            rem = n % divisor as u64;
+        }
+        (BigUint::from_limbs(limbs), rem as u32)
+    }
+
+    // Long division, Knuth's algorithm D. Panics on division by zero.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "division by zero");
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if let [d] = divisor.limbs[..] {
+            let (q, r) = self.div_rem_small(d);
+            return (q, BigUint::from(r));
+        }
+
+        // Shift both so the divisor's top limb has its high bit set, which
+        // keeps each estimated quotient limb at most two too large.
+        let shift = divisor.limbs.last().unwrap().leading_zeros();
+        let v = shl_bits(&divisor.limbs, shift);
+        let mut u = shl_bits(&self.limbs, shift);
+        if u.len() == self.limbs.len() {
+            u.push(0);
+        }
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let (top, second) = (v[n - 1] as u64, v[n - 2] as u64);
+        let mut q = vec![0u32; m + 1];
+        const BASE: u64 = 1 << 32;
+
+        for j in (0..=m).rev() {
+            let numerator = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+            let mut qhat = numerator / top;
+            let mut rhat = numerator % top;
+            while qhat >= BASE || qhat * second > (rhat << 32 | u[j + n - 2] as u64) {
+                qhat -= 1;
+                rhat += top;
+                if rhat >= BASE {
+                    break;
+                }
+            }
+
+            // u[j..=j + n] -= qhat * v
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let p = qhat * v[i] as u64 + carry;
+                carry = p >> 32;
+                let t = u[i + j] as i64 - borrow - (p & 0xffff_ffff) as i64;
+                u[i + j] = t as u32;
+                borrow = (t < 0) as i64;
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+
+            if t < 0 {
+                // `qhat` was one too large: add the divisor back once.
+                qhat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let s = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = s as u32;
+                    carry = s >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+            q[j] = qhat as u32;
+        }
+
+        u.truncate(n);
+        (BigUint::from_limbs(q), BigUint::from_limbs(shr_bits(&u, shift)))
+    }
+
+    // Grade-school multiplication, O(n·m).
+    pub fn mul_schoolbook(&self, rhs: &BigUint) -> BigUint {
+        BigUint::from_limbs(schoolbook(&self.limbs, &rhs.limbs))
+    }
+
+    // Karatsuba multiplication, O(n^1.585), falling back to the schoolbook
+    // method for short operands.
+    pub fn mul_karatsuba(&self, rhs: &BigUint) -> BigUint {
+        BigUint::from_limbs(karatsuba(&self.limbs, &rhs.limbs))
+    }
+}
+
+fn shl_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut out = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+    for &limb in limbs {
+        out.push(limb << shift | carry);
+        carry = limb >> (32 - shift);
+    }
+    if carry != 0 {
+        out.push(carry);
+    }
+    out
+}
+
+fn shr_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut out = vec![0; limbs.len()];
+    for (i, &limb) in limbs.iter().enumerate() {
+        out[i] = limb >> shift;
+        if let Some(&next) = limbs.get(i + 1) {
+            out[i] |= next << (32 - shift);
+        }
+    }
+    out
+}
+
+fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let n = x as u64 * y as u64 + out[i + j] as u64 + carry;
+            out[i + j] = n as u32;
+            carry = n >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    out
+}
+
+// `out[offset..] += add`, growing `out` as needed.
+fn add_into(out: &mut Vec<u32>, add: &[u32], offset: usize) {
+    if out.len() < offset + add.len() + 1 {
+        out.resize(offset + add.len() + 1, 0);
+    }
+    let mut carry = 0u64;
+    let mut i = offset;
+    for &limb in add {
+        let s = out[i] as u64 + limb as u64 + carry;
+        out[i] = s as u32;
+        carry = s >> 32;
+        i += 1;
+    }
+    while carry != 0 {
+        if i == out.len() {
+            out.push(0);
+        }
+        let s = out[i] as u64 + carry;
+        out[i] = s as u32;
+        carry = s >> 32;
+        i += 1;
+    }
+}
+
+fn karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    // a = a1·B^half + a0, b = b1·B^half + b0
+    let half = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(half.min(a.len()));
+    let (b0, b1) = b.split_at(half.min(b.len()));
+    let z0 = BigUint::from_limbs(karatsuba(a0, b0));
+    let z2 = BigUint::from_limbs(karatsuba(a1, b1));
+    let sum_a = BigUint::from_limbs(a0.to_vec()) + BigUint::from_limbs(a1.to_vec());
+    let sum_b = BigUint::from_limbs(b0.to_vec()) + BigUint::from_limbs(b1.to_vec());
+    // (a0 + a1)(b0 + b1) - z0 - z2 = a0·b1 + a1·b0
+    let z1 = BigUint::from_limbs(karatsuba(&sum_a.limbs, &sum_b.limbs)) - &z0 - &z2;
+
+    let mut out = z0.limbs;
+    add_into(&mut out, &z1.limbs, half);
+    add_into(&mut out, &z2.limbs, 2 * half);
+    out
+}
+
+impl From<u32> for BigUint {
+    fn from(n: u32) -> BigUint {
+        BigUint::from_limbs(vec![n])
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> BigUint {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> BigUint {
+        BigUint::from_limbs((0..4).map(|i| (n >> (32 * i)) as u32).collect())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = self.limbs.clone();
+        add_into(&mut limbs, &rhs.limbs, 0);
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    // Panics if `rhs` is larger, like `u32` does in debug builds.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        self.checked_sub(rhs).expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        self.mul_karatsuba(rhs)
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).1
+    }
+}
+
+// The owned forms of each operator, in terms of the borrowed one.
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident;)*) => {
+        $(
+            impl $op<BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: BigUint) -> BigUint {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $op<&BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: &BigUint) -> BigUint {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op<BigUint> for &BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: BigUint) -> BigUint {
+                    self.$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_ops! {
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem;
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        add_into(&mut self.limbs, &rhs.limbs, 0);
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl SubAssign<&BigUint> for BigUint {
+    fn sub_assign(&mut self, rhs: &BigUint) {
+        *self = &*self - rhs;
+    }
+}
+
+impl MulAssign<&BigUint> for BigUint {
+    fn mul_assign(&mut self, rhs: &BigUint) {
+        *self = &*self * rhs;
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseNumberError;
+
+    // Decimal digits, with `_` allowed between them.
+    fn from_str(s: &str) -> Result<BigUint, ParseNumberError> {
+        if s.is_empty() {
+            return Err(ParseNumberError::Empty);
+        }
+        let mut n = BigUint::zero();
+        let mut chunk = 0u32;
+        let mut chunk_len = 0;
+        let mut digits = 0;
+        for (index, c) in s.char_indices() {
+            if c == '_' && digits > 0 {
+                continue;
+            }
+            let Some(digit) = c.to_digit(10) else {
+                return Err(ParseNumberError::InvalidDigit { index, found: c });
+            };
+            chunk = chunk * 10 + digit;
+            chunk_len += 1;
+            digits += 1;
+            if chunk_len == 9 {
+                n = n.mul_add_small(TEN_9, chunk);
+                chunk = 0;
+                chunk_len = 0;
+            }
+        }
+        if s.ends_with('_') {
+            return Err(ParseNumberError::InvalidDigit { index: s.len() - 1, found: '_' });
+        }
+        if chunk_len > 0 {
+            n = n.mul_add_small(10u32.pow(chunk_len), chunk);
+        }
+        Ok(n)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Nine digits at a time, least significant first.
+        let mut chunks = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem_small(TEN_9);
+            chunks.push(r);
+            n = q;
+        }
+        let mut digits = match chunks.pop() {
+            Some(top) => top.to_string(),
+            None => "0".to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
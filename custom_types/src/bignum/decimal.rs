@@ -0,0 +1,431 @@
+// Signed decimals of any size and precision: an integer count of units of
+// 10^-scale. `+`, `-` and `*` are exact; division rounds to a requested
+// number of decimal places.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use super::{BigUint, ParseNumberError};
+
+// Exponents beyond this are almost certainly a typo, and would take a very
+// long time to expand.
+const MAX_EXPONENT: i64 = 100_000;
+
+// How to round away the digits that don't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    // Toward zero.
+    Down,
+    // To the nearest, ties away from zero.
+    HalfUp,
+    // To the nearest, ties to an even last digit.
+    HalfEven,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivisionByZero;
+
+impl fmt::Display for DivisionByZero {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "division by zero")
+    }
+}
+
+impl std::error::Error for DivisionByZero {}
+
+// Equal values with different scales, like 1.5 and 1.50, compare equal but
+// keep their own digits in `Display`.
+#[derive(Debug, Clone, Default)]
+pub struct BigDecimal {
+    // Never set for zero.
+    negative: bool,
+    digits: BigUint,
+    scale: u32,
+}
+
+fn ten_pow(n: u32) -> BigUint {
+    BigUint::from(10u32).pow(n)
+}
+
+impl BigDecimal {
+    pub fn zero() -> BigDecimal {
+        BigDecimal::default()
+    }
+
+    // `digits · 10^-scale`, negated if `negative`.
+    pub fn new(negative: bool, digits: BigUint, scale: u32) -> BigDecimal {
+        let negative = negative && !digits.is_zero();
+        BigDecimal { negative, digits, scale }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    // Digits after the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    // The digits without the decimal point, as an integer.
+    pub fn unscaled(&self) -> &BigUint {
+        &self.digits
+    }
+
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal { negative: false, ..self.clone() }
+    }
+
+    // The same value with `scale` digits after the point, rounded if there
+    // were more.
+    pub fn with_scale(&self, scale: u32, rounding: Rounding) -> BigDecimal {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => self.clone(),
+            Ordering::Greater => {
+                let digits = &self.digits * ten_pow(scale - self.scale);
+                BigDecimal::new(self.negative, digits, scale)
+            }
+            Ordering::Less => {
+                let divisor = ten_pow(self.scale - scale);
+                let digits = round_quotient(&self.digits, &divisor, rounding);
+                BigDecimal::new(self.negative, digits, scale)
+            }
+        }
+    }
+
+    // The shortest form of the value, without trailing zeros after the point.
+    pub fn normalized(&self) -> BigDecimal {
+        let mut digits = self.digits.clone();
+        let mut scale = self.scale;
+        let ten = BigUint::from(10u32);
+        while scale > 0 && !digits.is_zero() {
+            let (q, r) = digits.div_rem(&ten);
+            if !r.is_zero() {
+                break;
+            }
+            digits = q;
+            scale -= 1;
+        }
+        if digits.is_zero() {
+            scale = 0;
+        }
+        BigDecimal::new(self.negative, digits, scale)
+    }
+
+    // `self / rhs` with `scale` digits after the point.
+    pub fn div(
+        &self,
+        rhs: &BigDecimal,
+        scale: u32,
+        rounding: Rounding,
+    ) -> Result<BigDecimal, DivisionByZero> {
+        if rhs.is_zero() {
+            return Err(DivisionByZero);
+        }
+        // self / rhs = (a · 10^-sa) / (b · 10^-sb), wanted as q · 10^-scale,
+        // so q = a · 10^(scale + sb - sa) / b.
+        let shift = scale as i64 + rhs.scale as i64 - self.scale as i64;
+        let (numerator, denominator) = if shift >= 0 {
+            (&self.digits * ten_pow(shift as u32), rhs.digits.clone())
+        } else {
+            (self.digits.clone(), &rhs.digits * ten_pow((-shift) as u32))
+        };
+        let digits = round_quotient(&numerator, &denominator, rounding);
+        Ok(BigDecimal::new(self.negative != rhs.negative, digits, scale))
+    }
+
+    // The exact value of a finite float, which always has a finite decimal
+    // expansion.
+    pub fn from_f64(value: f64) -> Option<BigDecimal> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let negative = bits >> 63 == 1;
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        // value = mantissa · 2^power
+        let (mantissa, power) = match exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | 1 << 52, exponent - 1075),
+        };
+        let mantissa = BigUint::from(mantissa);
+        Some(if power >= 0 {
+            BigDecimal::new(negative, mantissa * BigUint::from(2u32).pow(power as u32), 0)
+        } else {
+            // m · 2^-k = m · 5^k · 10^-k
+            let k = (-power) as u32;
+            BigDecimal::new(negative, mantissa * BigUint::from(5u32).pow(k), k).normalized()
+        })
+    }
+
+    // The nearest `f64`, through its decimal text.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap()
+    }
+
+    // Both magnitudes at the larger of the two scales.
+    fn aligned(&self, other: &BigDecimal) -> (BigUint, BigUint, u32) {
+        let scale = self.scale.max(other.scale);
+        let a = &self.digits * ten_pow(scale - self.scale);
+        let b = &other.digits * ten_pow(scale - other.scale);
+        (a, b, scale)
+    }
+}
+
+// `n / d`, rounded.
+fn round_quotient(n: &BigUint, d: &BigUint, rounding: Rounding) -> BigUint {
+    let (q, r) = n.div_rem(d);
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::HalfUp | Rounding::HalfEven => {
+            let twice = &r + &r;
+            match twice.cmp(d) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => rounding == Rounding::HalfUp || !q.is_even(),
+            }
+        }
+    };
+    if round_up {
+        q + BigUint::one()
+    } else {
+        q
+    }
+}
+
+impl From<BigUint> for BigDecimal {
+    fn from(n: BigUint) -> BigDecimal {
+        BigDecimal::new(false, n, 0)
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigDecimal {
+                fn from(n: $t) -> BigDecimal {
+                    BigDecimal::new(n < 0, BigUint::from(n.unsigned_abs() as u64), 0)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64);
+
+macro_rules! from_uint {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigDecimal {
+                fn from(n: $t) -> BigDecimal {
+                    BigDecimal::new(false, BigUint::from(n as u64), 0)
+                }
+            }
+        )*
+    };
+}
+
+from_uint!(u8, u16, u32, u64);
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &BigDecimal) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let (a, b, _) = self.aligned(other);
+                let ordering = a.cmp(&b);
+                if negative {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &BigDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &BigDecimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        BigDecimal::new(!self.negative, self.digits.clone(), self.scale)
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        -&self
+    }
+}
+
+impl Add<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    // Exact, at the larger of the two scales.
+    fn add(self, rhs: &BigDecimal) -> BigDecimal {
+        let (a, b, scale) = self.aligned(rhs);
+        if self.negative == rhs.negative {
+            return BigDecimal::new(self.negative, a + b, scale);
+        }
+        // Opposite signs: the larger magnitude decides the sign.
+        match a.cmp(&b) {
+            Ordering::Less => BigDecimal::new(rhs.negative, b - a, scale),
+            _ => BigDecimal::new(self.negative, a - b, scale),
+        }
+    }
+}
+
+impl Sub<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, rhs: &BigDecimal) -> BigDecimal {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    // Exact, with the scales added.
+    fn mul(self, rhs: &BigDecimal) -> BigDecimal {
+        let digits = &self.digits * &rhs.digits;
+        BigDecimal::new(self.negative != rhs.negative, digits, self.scale + rhs.scale)
+    }
+}
+
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident;)*) => {
+        $(
+            impl $op<BigDecimal> for BigDecimal {
+                type Output = BigDecimal;
+
+                fn $method(self, rhs: BigDecimal) -> BigDecimal {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $op<&BigDecimal> for BigDecimal {
+                type Output = BigDecimal;
+
+                fn $method(self, rhs: &BigDecimal) -> BigDecimal {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op<BigDecimal> for &BigDecimal {
+                type Output = BigDecimal;
+
+                fn $method(self, rhs: BigDecimal) -> BigDecimal {
+                    self.$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_ops! {
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+}
+
+impl FromStr for BigDecimal {
+    type Err = ParseNumberError;
+
+    // `-12.50`, `+3`, `.5`, `6.` and `1.5e-3` all work. The digits after the
+    // point, exponent included, become the scale, so `1.50` keeps its zero.
+    fn from_str(s: &str) -> Result<BigDecimal, ParseNumberError> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let offset = s.len() - rest.len();
+        let (mantissa, exponent) = match rest.find(['e', 'E']) {
+            Some(e) => (&rest[..e], Some((e, &rest[e + 1..]))),
+            None => (rest, None),
+        };
+        let (whole, fraction) = match mantissa.find('.') {
+            Some(dot) => (&mantissa[..dot], &mantissa[dot + 1..]),
+            None => (mantissa, ""),
+        };
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseNumberError::Empty);
+        }
+        // `_` only goes between digits, and the point splits two numbers.
+        if whole.ends_with('_') {
+            let index = offset + whole.len() - 1;
+            return Err(ParseNumberError::InvalidDigit { index, found: '_' });
+        }
+        if fraction.starts_with('_') {
+            let index = offset + whole.len() + 1;
+            return Err(ParseNumberError::InvalidDigit { index, found: '_' });
+        }
+        // Parse the digits as one integer, pointing errors into `s`.
+        let digits: String = whole.chars().chain(fraction.chars()).collect();
+        let digits: BigUint = digits.parse().map_err(|err| match err {
+            ParseNumberError::InvalidDigit { index, found } => {
+                let skipped = if index >= whole.len() { 1 } else { 0 };
+                ParseNumberError::InvalidDigit { index: offset + index + skipped, found }
+            }
+            err => err,
+        })?;
+        let mut scale = fraction.chars().filter(|&c| c != '_').count() as i64;
+        if let Some((e, text)) = exponent {
+            let exponent: i64 = text
+                .parse()
+                .ok()
+                .filter(|n: &i64| n.abs() <= MAX_EXPONENT)
+                .ok_or(ParseNumberError::InvalidExponent { index: offset + e })?;
+            scale -= exponent;
+        }
+        Ok(if scale >= 0 {
+            BigDecimal::new(negative, digits, scale as u32)
+        } else {
+            BigDecimal::new(negative, digits * ten_pow((-scale) as u32), 0)
+        })
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    // All digits, `scale` of them after the point. A precision rounds half
+    // to even to that many places first: `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match f.precision() {
+            Some(places) => self.with_scale(places as u32, Rounding::HalfEven),
+            None => self.clone(),
+        };
+        let mut text = value.digits.to_string();
+        let scale = value.scale as usize;
+        if scale > 0 {
+            if text.len() <= scale {
+                text.insert_str(0, &"0".repeat(scale + 1 - text.len()));
+            }
+            text.insert(text.len() - scale, '.');
+        }
+        f.pad_integral(!value.negative, "", &text)
+    }
+}
@@ -0,0 +1,38 @@
+// Numbers that don't lose digits, for the `PI` in `constants`, which an `f64`
+// cuts down to 16 significant digits.
+
+mod biguint;
+mod decimal;
+
+pub use biguint::BigUint;
+pub use decimal::{BigDecimal, DivisionByZero, Rounding};
+
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseNumberError {
+    // No digits at all.
+    Empty,
+    // `index` is a byte offset into the text.
+    InvalidDigit { index: usize, found: char },
+    // The exponent starting at the `e` at `index` isn't a number, or is too
+    // large to expand.
+    InvalidExponent { index: usize },
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseNumberError::Empty => write!(f, "no digits"),
+            ParseNumberError::InvalidDigit { index, found } => {
+                write!(f, "invalid digit {found:?} at byte {index}")
+            }
+            ParseNumberError::InvalidExponent { index } => {
+                write!(f, "invalid exponent at byte {index}")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseNumberError {}
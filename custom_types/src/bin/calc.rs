@@ -0,0 +1,91 @@
+// An interactive calculator over `custom_types::calc`.
+//
+//     $ cargo run -p custom_types --bin calc
+//     > (3 + 4) * 2
+//     14
+//     > :rpn
+//     rpn> 3 4 + 2 *
+//     14
+
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use custom_types::calc::{eval_infix, eval_rpn, to_rpn, CalcError};
+
+const HELP: &str = "\
+Enter an expression to evaluate it. Operators, loosest first:
+    |   ^   &   << >>   + -   * / %   neg (or a leading -)   **
+Commands:
+    :infix        read infix expressions (the default)
+    :rpn          read RPN expressions, e.g. `3 4 + 2 *`
+    :show EXPR    print an infix expression in RPN
+    :help         print this text
+    :quit         leave (so does end of input)";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Infix,
+    Rpn,
+}
+
+// Prints an error, pointing at its column under the input when the input was
+// typed after `prompt`. Columns count characters, not bytes.
+fn report(out: &mut impl Write, prompt: Option<&str>, err: &CalcError) -> io::Result<()> {
+    if let Some(prompt) = prompt {
+        let indent = prompt.chars().count() + err.column - 1;
+        writeln!(out, "{:indent$}^", "")?;
+    }
+    writeln!(out, "error: {err}")
+}
+
+fn main() -> io::Result<()> {
+    let interactive = io::stdin().is_terminal();
+    let mut out = io::stdout().lock();
+    let mut mode = Mode::Infix;
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        let prompt = match mode {
+            Mode::Infix => "> ",
+            Mode::Rpn => "rpn> ",
+        };
+        if interactive {
+            write!(out, "{prompt}")?;
+            out.flush()?;
+        }
+        let Some(line) = lines.next().transpose()? else {
+            break;
+        };
+        let shown = interactive.then_some(prompt);
+        let line = line.trim_end();
+        match line.trim_start() {
+            "" => {}
+            ":quit" | ":q" => break,
+            ":help" => writeln!(out, "{HELP}")?,
+            ":infix" => mode = Mode::Infix,
+            ":rpn" => mode = Mode::Rpn,
+            command if command.starts_with(":show") => {
+                let offset = line.find(":show").unwrap() + ":show".len();
+                match to_rpn(&line[offset..]) {
+                    Ok(rpn) => writeln!(out, "{rpn}")?,
+                    Err(err) => {
+                        let column = err.column + line[..offset].chars().count();
+                        report(&mut out, shown, &CalcError { column, ..err })?;
+                    }
+                }
+            }
+            command if command.starts_with(':') => {
+                writeln!(out, "error: unknown command `{command}`, try :help")?;
+            }
+            _ => {
+                let result = match mode {
+                    Mode::Infix => eval_infix(line),
+                    Mode::Rpn => eval_rpn(line),
+                };
+                match result {
+                    Ok(n) => writeln!(out, "{n}")?,
+                    Err(err) => report(&mut out, shown, &err)?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,446 @@
+// The `TodoWithNumbers` from `enums_impls`, grown into a small integer
+// calculator.
+//
+// Every operation is checked: overflow, division by zero and the like come
+// back as a `MathError` instead of panicking or wrapping. On top of that sit
+// an RPN evaluator and an infix front end that turns the usual notation into
+// RPN with the shunting-yard algorithm:
+//
+//     3 4 + 2 *        (3 + 4) * 2
+//     2 3 ** neg       -2 ** 3
+//
+// The `calc` binary is a REPL around both.
+
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TodoWithNumbers {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl TodoWithNumbers {
+    pub const ALL: [TodoWithNumbers; 11] = [
+        TodoWithNumbers::Add,
+        TodoWithNumbers::Subtract,
+        TodoWithNumbers::Multiply,
+        TodoWithNumbers::Divide,
+        TodoWithNumbers::Remainder,
+        TodoWithNumbers::Power,
+        TodoWithNumbers::And,
+        TodoWithNumbers::Or,
+        TodoWithNumbers::Xor,
+        TodoWithNumbers::ShiftLeft,
+        TodoWithNumbers::ShiftRight,
+    ];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TodoWithNumbers::Add => "+",
+            TodoWithNumbers::Subtract => "-",
+            TodoWithNumbers::Multiply => "*",
+            TodoWithNumbers::Divide => "/",
+            TodoWithNumbers::Remainder => "%",
+            TodoWithNumbers::Power => "**",
+            TodoWithNumbers::And => "&",
+            TodoWithNumbers::Or => "|",
+            TodoWithNumbers::Xor => "^",
+            TodoWithNumbers::ShiftLeft => "<<",
+            TodoWithNumbers::ShiftRight => ">>",
+        }
+    }
+
+    pub fn run(&self, x: i32, y: i32) -> Result<i32, MathError> {
+        match self {
+            TodoWithNumbers::Divide | TodoWithNumbers::Remainder if y == 0 => {
+                return Err(MathError::DivisionByZero);
+            }
+            TodoWithNumbers::Power if y < 0 => return Err(MathError::NegativeExponent),
+            TodoWithNumbers::ShiftLeft | TodoWithNumbers::ShiftRight if !(0..32).contains(&y) => {
+                return Err(MathError::ShiftOutOfRange(y));
+            }
+            _ => {}
+        }
+        let result = match self {
+            TodoWithNumbers::Add => x.checked_add(y),
+            TodoWithNumbers::Subtract => x.checked_sub(y),
+            TodoWithNumbers::Multiply => x.checked_mul(y),
+            TodoWithNumbers::Divide => x.checked_div(y),
+            TodoWithNumbers::Remainder => x.checked_rem(y),
+            TodoWithNumbers::Power => x.checked_pow(y as u32),
+            TodoWithNumbers::And => Some(x & y),
+            TodoWithNumbers::Or => Some(x | y),
+            TodoWithNumbers::Xor => Some(x ^ y),
+            // A left shift that drops set bits or changes the sign overflowed.
+            TodoWithNumbers::ShiftLeft => Some(x << y).filter(|r| r >> y == x),
+            TodoWithNumbers::ShiftRight => Some(x >> y),
+        };
+        result.ok_or(MathError::Overflow(Operator::Binary(*self)))
+    }
+}
+
+impl fmt::Display for TodoWithNumbers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+// The one unary operator, kept out of `TodoWithNumbers` so `run` always has
+// both operands.
+pub fn negate(x: i32) -> Result<i32, MathError> {
+    x.checked_neg().ok_or(MathError::Overflow(Operator::Negate))
+}
+
+// Any operator an expression can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Binary(TodoWithNumbers),
+    Negate,
+}
+
+impl Operator {
+    // How the operator is written in RPN. Infix also accepts `-` for `neg`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Binary(op) => op.symbol(),
+            Operator::Negate => "neg",
+        }
+    }
+
+    pub fn from_symbol(symbol: &str) -> Option<Operator> {
+        match symbol {
+            "neg" => Some(Operator::Negate),
+            _ => {
+                let binary = TodoWithNumbers::ALL.into_iter().find(|op| op.symbol() == symbol);
+                binary.map(Operator::Binary)
+            }
+        }
+    }
+
+    // How many operands the operator takes.
+    pub fn arity(&self) -> usize {
+        match self {
+            Operator::Binary(_) => 2,
+            Operator::Negate => 1,
+        }
+    }
+
+    // Binding strength in infix, as in Rust except for `**`, which binds
+    // tighter than `neg` like in Python, so `-2 ** 2` is -4.
+    fn precedence(&self) -> u8 {
+        match self {
+            Operator::Binary(TodoWithNumbers::Or) => 1,
+            Operator::Binary(TodoWithNumbers::Xor) => 2,
+            Operator::Binary(TodoWithNumbers::And) => 3,
+            Operator::Binary(TodoWithNumbers::ShiftLeft | TodoWithNumbers::ShiftRight) => 4,
+            Operator::Binary(TodoWithNumbers::Add | TodoWithNumbers::Subtract) => 5,
+            Operator::Binary(
+                TodoWithNumbers::Multiply | TodoWithNumbers::Divide | TodoWithNumbers::Remainder,
+            ) => 6,
+            Operator::Negate => 7,
+            Operator::Binary(TodoWithNumbers::Power) => 8,
+        }
+    }
+
+    fn right_associative(&self) -> bool {
+        matches!(self, Operator::Binary(TodoWithNumbers::Power) | Operator::Negate)
+    }
+}
+
+// `-`, which negates wherever an operand is expected.
+const MINUS: Operator = Operator::Binary(TodoWithNumbers::Subtract);
+
+impl From<TodoWithNumbers> for Operator {
+    fn from(op: TodoWithNumbers) -> Operator {
+        Operator::Binary(op)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    Overflow(Operator),
+    DivisionByZero,
+    NegativeExponent,
+    // Shifting by this many bits, outside `0..32`.
+    ShiftOutOfRange(i32),
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::Overflow(op) => write!(f, "`{op}` overflowed"),
+            MathError::DivisionByZero => write!(f, "division by zero"),
+            MathError::NegativeExponent => write!(f, "negative exponent"),
+            MathError::ShiftOutOfRange(n) => write!(f, "cannot shift by {n} bits"),
+        }
+    }
+}
+
+impl error::Error for MathError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcErrorKind {
+    // Nothing but whitespace.
+    Empty,
+    UnknownToken(String),
+    // Digits that don't fit an `i32`.
+    InvalidNumber(String),
+    // An RPN operator with too few values on the stack.
+    MissingOperand(Operator),
+    // Values left on the stack at the end, besides the result.
+    UnusedOperands(usize),
+    // Infix: an operator or `)` where a number or `(` should be.
+    ExpectedOperand,
+    // Infix: a number or `(` right after an operand.
+    ExpectedOperator,
+    UnmatchedOpen,
+    UnmatchedClose,
+    Math(MathError),
+}
+
+// Where evaluation stopped. Columns count characters from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalcError {
+    pub column: usize,
+    pub kind: CalcErrorKind,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match &self.kind {
+            CalcErrorKind::Empty => write!(f, "expected an expression"),
+            CalcErrorKind::UnknownToken(token) => write!(f, "unknown token `{token}`"),
+            CalcErrorKind::InvalidNumber(digits) => write!(f, "`{digits}` does not fit an i32"),
+            CalcErrorKind::MissingOperand(op) => write!(f, "not enough operands for `{op}`"),
+            CalcErrorKind::UnusedOperands(1) => write!(f, "1 operand left unused"),
+            CalcErrorKind::UnusedOperands(n) => write!(f, "{n} operands left unused"),
+            CalcErrorKind::ExpectedOperand => write!(f, "expected a number or `(`"),
+            CalcErrorKind::ExpectedOperator => write!(f, "expected an operator"),
+            CalcErrorKind::UnmatchedOpen => write!(f, "`(` is never closed"),
+            CalcErrorKind::UnmatchedClose => write!(f, "`)` without a matching `(`"),
+            CalcErrorKind::Math(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl error::Error for CalcError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Number(i32),
+    Operator(Operator),
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{n}"),
+            Token::Operator(op) => write!(f, "{op}"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+        }
+    }
+}
+
+// A token and the column it starts at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned {
+    pub column: usize,
+    pub token: Token,
+}
+
+// Splits an expression into tokens. Whitespace is only needed between two
+// numbers or words, so `(3+4)*2` and `3 4 + 2 *` both work. A `-` is always
+// `Subtract` here; `infix_to_rpn` decides which ones negate.
+pub fn tokenize(text: &str) -> Result<Vec<Spanned>, CalcError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut column = 0;
+    while let Some((start, c)) = chars.next() {
+        column += 1;
+        let first = column;
+        if c.is_whitespace() {
+            continue;
+        }
+        // Extends the token while `more` accepts the next character.
+        let mut take = |more: &mut dyn FnMut(char) -> bool| {
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, next)) = chars.peek() {
+                if !more(next) {
+                    break;
+                }
+                chars.next();
+                column += 1;
+                end = i + next.len_utf8();
+            }
+            &text[start..end]
+        };
+        let token = if c.is_ascii_digit() {
+            let digits = take(&mut |c| c.is_ascii_digit());
+            let n = digits.parse().map_err(|_| CalcError {
+                column: first,
+                kind: CalcErrorKind::InvalidNumber(digits.to_string()),
+            })?;
+            Token::Number(n)
+        } else if c == '(' {
+            Token::Open
+        } else if c == ')' {
+            Token::Close
+        } else {
+            let word = if c.is_alphabetic() {
+                take(&mut |c| c.is_alphanumeric())
+            } else if matches!(c, '*' | '<' | '>') {
+                // `**`, `<<` and `>>`, or a lone `*`.
+                let mut doubled = false;
+                take(&mut |next| next == c && !std::mem::replace(&mut doubled, true))
+            } else {
+                take(&mut |_| false)
+            };
+            match Operator::from_symbol(word) {
+                Some(op) => Token::Operator(op),
+                None => {
+                    return Err(CalcError {
+                        column: first,
+                        kind: CalcErrorKind::UnknownToken(word.to_string()),
+                    })
+                }
+            }
+        };
+        tokens.push(Spanned { column: first, token });
+    }
+    Ok(tokens)
+}
+
+// Evaluates tokens in RPN order with a stack.
+pub fn eval_tokens(tokens: &[Spanned]) -> Result<i32, CalcError> {
+    let mut stack: Vec<i32> = Vec::new();
+    for spanned in tokens {
+        let error = |kind| CalcError { column: spanned.column, kind };
+        match spanned.token {
+            Token::Number(n) => stack.push(n),
+            Token::Operator(op) => {
+                let Some(start) = stack.len().checked_sub(op.arity()) else {
+                    return Err(error(CalcErrorKind::MissingOperand(op)));
+                };
+                let result = match op {
+                    Operator::Binary(op) => op.run(stack[start], stack[start + 1]),
+                    Operator::Negate => negate(stack[start]),
+                };
+                let result = result.map_err(|err| error(CalcErrorKind::Math(err)))?;
+                stack.truncate(start);
+                stack.push(result);
+            }
+            Token::Open | Token::Close => {
+                return Err(error(CalcErrorKind::UnknownToken(spanned.token.to_string())));
+            }
+        }
+    }
+    match stack[..] {
+        [result] => Ok(result),
+        [] => Err(CalcError { column: 1, kind: CalcErrorKind::Empty }),
+        _ => {
+            let column = tokens.last().map_or(1, |last| last.column);
+            Err(CalcError { column, kind: CalcErrorKind::UnusedOperands(stack.len() - 1) })
+        }
+    }
+}
+
+pub fn eval_rpn(text: &str) -> Result<i32, CalcError> {
+    eval_tokens(&tokenize(text)?)
+}
+
+// Reorders infix tokens into RPN with the shunting-yard algorithm. A `-` or
+// `neg` where an operand is expected negates.
+pub fn infix_to_rpn(tokens: &[Spanned]) -> Result<Vec<Spanned>, CalcError> {
+    let mut output = Vec::new();
+    // Operators and open parentheses waiting for their right-hand side.
+    let mut pending: Vec<Spanned> = Vec::new();
+    let mut expect_operand = true;
+    for &spanned in tokens {
+        let error = |kind| CalcError { column: spanned.column, kind };
+        match (spanned.token, expect_operand) {
+            (Token::Number(_), true) => {
+                output.push(spanned);
+                expect_operand = false;
+            }
+            (Token::Open, true) => pending.push(spanned),
+            (Token::Operator(Operator::Negate | MINUS), true) => {
+                let token = Token::Operator(Operator::Negate);
+                pending.push(Spanned { token, ..spanned });
+            }
+            (Token::Operator(op), false) if op.arity() == 2 => {
+                while let Some(&top) = pending.last() {
+                    let Token::Operator(other) = top.token else {
+                        break;
+                    };
+                    let binds_tighter = other.precedence() > op.precedence()
+                        || (other.precedence() == op.precedence() && !op.right_associative());
+                    if !binds_tighter {
+                        break;
+                    }
+                    output.push(top);
+                    pending.pop();
+                }
+                pending.push(spanned);
+                expect_operand = true;
+            }
+            (Token::Close, false) => loop {
+                match pending.pop() {
+                    Some(Spanned { token: Token::Open, .. }) => break,
+                    Some(top) => output.push(top),
+                    None => return Err(error(CalcErrorKind::UnmatchedClose)),
+                }
+            },
+            (Token::Number(_) | Token::Open | Token::Operator(_), false) => {
+                return Err(error(CalcErrorKind::ExpectedOperator));
+            }
+            (Token::Operator(_) | Token::Close, true) => {
+                return Err(error(CalcErrorKind::ExpectedOperand));
+            }
+        }
+    }
+    if expect_operand {
+        let column = match tokens.last() {
+            Some(last) => last.column + last.token.to_string().chars().count(),
+            None => 1,
+        };
+        let kind = if tokens.is_empty() { CalcErrorKind::Empty } else { CalcErrorKind::ExpectedOperand };
+        return Err(CalcError { column, kind });
+    }
+    while let Some(top) = pending.pop() {
+        if top.token == Token::Open {
+            return Err(CalcError { column: top.column, kind: CalcErrorKind::UnmatchedOpen });
+        }
+        output.push(top);
+    }
+    Ok(output)
+}
+
+pub fn eval_infix(text: &str) -> Result<i32, CalcError> {
+    eval_tokens(&infix_to_rpn(&tokenize(text)?)?)
+}
+
+// The RPN form of an infix expression, as text `eval_rpn` accepts.
+pub fn to_rpn(text: &str) -> Result<String, CalcError> {
+    let rpn = infix_to_rpn(&tokenize(text)?)?;
+    Ok(rpn.iter().map(|s| s.token.to_string()).collect::<Vec<_>>().join(" "))
+}
@@ -0,0 +1,444 @@
+// The `as` rules from `casting`, worked out for a given value.
+//
+// `explain_cast::<i32, u8>(1000)` returns what `1000 as u8` gives and why:
+//
+//     1000_i32 as u8 = 232 (truncated): 1000 is outside u8's range 0..=255;
+//     keeping the low 8 of 32 bits turns 0x000003e8 into 0xe8
+//
+// `cast_table` runs every pair of primitive number types over a set of
+// awkward values and records what can happen, as a quick reference for
+// whether an `as` needs a closer look.
+
+use std::fmt;
+
+// What a cast did to the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CastKind {
+    // Same value on both sides.
+    Lossless,
+    // Same bits read differently, or sign-extended into an unsigned type.
+    Wrapped,
+    // High bits dropped, or a float's fractional part.
+    Truncated,
+    // A float outside the integer type's range, clamped to its bounds.
+    Saturated,
+    NanToZero,
+    // Rounded to the nearest value of a float type, possibly to infinity.
+    PrecisionLost,
+}
+
+impl CastKind {
+    pub const ALL: [CastKind; 6] = [
+        CastKind::Lossless,
+        CastKind::Wrapped,
+        CastKind::Truncated,
+        CastKind::Saturated,
+        CastKind::NanToZero,
+        CastKind::PrecisionLost,
+    ];
+
+    // One letter, for `CastTable`.
+    pub fn code(&self) -> char {
+        match self {
+            CastKind::Lossless => '=',
+            CastKind::Wrapped => 'W',
+            CastKind::Truncated => 'T',
+            CastKind::Saturated => 'S',
+            CastKind::NanToZero => 'N',
+            CastKind::PrecisionLost => 'P',
+        }
+    }
+}
+
+impl fmt::Display for CastKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            CastKind::Lossless => "lossless",
+            CastKind::Wrapped => "wrapped",
+            CastKind::Truncated => "truncated",
+            CastKind::Saturated => "saturated",
+            CastKind::NanToZero => "NaN to zero",
+            CastKind::PrecisionLost => "precision lost",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+    Unsigned,
+    Signed,
+    Float,
+}
+
+// The primitive number types, described well enough to reason about casts
+// between them.
+pub trait Primitive: Copy + fmt::Debug + 'static {
+    const NAME: &'static str;
+    const BITS: u32;
+    const KIND: NumberKind;
+    const MIN: Self;
+    const MAX: Self;
+
+    // The raw bits, zero-extended.
+    fn to_bits(self) -> u128;
+    // Sign and magnitude of an integer, or of a float that is a whole number
+    // within ±2^128. `-0.0` counts as 0.
+    fn whole(self) -> Option<(bool, u128)>;
+    // The value as an `f64`, exactly for `f32`, `f64` and small integers.
+    fn to_f64(self) -> f64;
+    // Values that between them show everything `as` can do.
+    fn samples() -> Vec<Self>;
+}
+
+// `as`, for generic code.
+pub trait CastTo<T>: Primitive {
+    fn cast(self) -> T;
+}
+
+macro_rules! int_primitive {
+    (@whole Unsigned, $value:expr) => {
+        (false, $value as u128)
+    };
+    (@whole Signed, $value:expr) => {
+        ($value < 0, ($value as i128).unsigned_abs())
+    };
+    ($($t:ty: $kind:ident),*) => {
+        $(
+            impl Primitive for $t {
+                const NAME: &'static str = stringify!($t);
+                const BITS: u32 = <$t>::BITS;
+                const KIND: NumberKind = NumberKind::$kind;
+                const MIN: $t = <$t>::MIN;
+                const MAX: $t = <$t>::MAX;
+
+                fn to_bits(self) -> u128 {
+                    // Negative numbers sign-extend into the `u128`; mask that
+                    // off again.
+                    (self as u128) & (u128::MAX >> (128 - Self::BITS))
+                }
+
+                fn whole(self) -> Option<(bool, u128)> {
+                    Some(int_primitive!(@whole $kind, self))
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn samples() -> Vec<$t> {
+                    vec![0, 1, 100, <$t>::MAX, <$t>::MIN, (0 as $t).wrapping_sub(1)]
+                }
+            }
+        )*
+    };
+}
+
+int_primitive!(
+    u8: Unsigned, u16: Unsigned, u32: Unsigned, u64: Unsigned, u128: Unsigned, usize: Unsigned,
+    i8: Signed, i16: Signed, i32: Signed, i64: Signed, i128: Signed, isize: Signed
+);
+
+// 2^128, the first magnitude `whole` can't represent.
+const TWO_128: f64 = 340282366920938463463374607431768211456.0;
+
+fn whole_float(value: f64) -> Option<(bool, u128)> {
+    (value.is_finite() && value.fract() == 0.0 && value.abs() < TWO_128)
+        .then(|| (value < 0.0, value.abs() as u128))
+}
+
+macro_rules! float_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Primitive for $t {
+                const NAME: &'static str = stringify!($t);
+                const BITS: u32 = std::mem::size_of::<$t>() as u32 * 8;
+                const KIND: NumberKind = NumberKind::Float;
+                const MIN: $t = <$t>::MIN;
+                const MAX: $t = <$t>::MAX;
+
+                fn to_bits(self) -> u128 {
+                    <$t>::to_bits(self) as u128
+                }
+
+                fn whole(self) -> Option<(bool, u128)> {
+                    whole_float(self as f64)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn samples() -> Vec<$t> {
+                    vec![
+                        0.0, -0.0, 1.0, -1.0, 0.5, -2.5, 0.1, 1000.0, 16777217.0, 1e20, -1e20,
+                        <$t>::MAX, <$t>::MIN, <$t>::MIN_POSITIVE, <$t>::INFINITY,
+                        <$t>::NEG_INFINITY, <$t>::NAN,
+                    ]
+                }
+            }
+        )*
+    };
+}
+
+float_primitive!(f32, f64);
+
+macro_rules! cast_to {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            impl CastTo<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! cast_all {
+    ($($from:ty),*) => {
+        $(
+            cast_to!($from => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        )*
+    };
+}
+
+cast_all!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// What `value as To` gives, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastExplanation<From, To> {
+    pub value: From,
+    pub result: To,
+    pub kind: CastKind,
+    // The reasoning, down to the bits where they matter.
+    pub reason: String,
+}
+
+impl<From: Primitive, To: Primitive> fmt::Display for CastExplanation<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?}_{} as {} = {:?} ({}): {}",
+            self.value,
+            From::NAME,
+            To::NAME,
+            self.result,
+            self.kind,
+            self.reason
+        )
+    }
+}
+
+// `bits` as hex, zero-padded to a type `width` bits wide.
+fn hex(bits: u128, width: u32) -> String {
+    format!("{:#0w$x}", bits, w = width as usize / 4 + 2)
+}
+
+// Bits of precision in each float type, the implicit leading bit included.
+fn precision(bits: u32) -> u32 {
+    if bits == 32 {
+        24
+    } else {
+        53
+    }
+}
+
+pub fn explain_cast<From, To>(value: From) -> CastExplanation<From, To>
+where
+    From: CastTo<To>,
+    To: Primitive,
+{
+    let result: To = value.cast();
+    let range = format!("{:?}..={:?}", To::MIN, To::MAX);
+    let to = To::NAME;
+    let (kind, reason) = match (From::KIND, To::KIND) {
+        (NumberKind::Float, NumberKind::Float) => explain_float_cast(value, result),
+        (NumberKind::Float, _) => {
+            let float = value.to_f64();
+            if float.is_nan() {
+                (CastKind::NanToZero, "NaN has no integer value, so `as` gives 0".to_string())
+            } else if whole_float(float.trunc()) != result.whole() {
+                let side = if float > 0.0 { "above" } else { "below" };
+                let reason =
+                    format!("{value:?} is {side} {to}'s range {range}, so the cast saturates to {result:?}");
+                (CastKind::Saturated, reason)
+            } else if float.fract() != 0.0 {
+                let reason = format!("{value:?} has a fractional part, which `as` drops, rounding toward zero");
+                (CastKind::Truncated, reason)
+            } else {
+                let reason = format!("{value:?} is a whole number within {to}'s range {range}");
+                (CastKind::Lossless, reason)
+            }
+        }
+        (_, NumberKind::Float) => {
+            let (_, magnitude) = value.whole().unwrap();
+            let significant = match magnitude {
+                0 => 0,
+                m => 128 - m.leading_zeros() - m.trailing_zeros(),
+            };
+            let digits = precision(To::BITS);
+            let needs = format!("{value:?} needs {significant} significant bits and {to} has {digits}");
+            if value.whole() == result.whole() {
+                (CastKind::Lossless, format!("{needs}, so it converts exactly"))
+            } else if result.to_f64().is_infinite() {
+                let reason = format!("{value:?} is beyond {to}::MAX and rounds to infinity");
+                (CastKind::PrecisionLost, reason)
+            } else {
+                let reason = format!("{needs}, so it rounds to the nearest {to}");
+                (CastKind::PrecisionLost, reason)
+            }
+        }
+        _ => {
+            let (before, after) = (hex(value.to_bits(), From::BITS), hex(result.to_bits(), To::BITS));
+            let lossless = value.whole() == result.whole();
+            let (fb, tb) = (From::BITS, To::BITS);
+            if tb < fb {
+                if lossless {
+                    let reason = format!(
+                        "{value:?} is within {to}'s range {range}, so keeping the low {tb} of {fb} bits ({before} -> {after}) keeps the value"
+                    );
+                    (CastKind::Lossless, reason)
+                } else {
+                    let reason = format!(
+                        "{value:?} is outside {to}'s range {range}; keeping the low {tb} of {fb} bits turns {before} into {after}"
+                    );
+                    (CastKind::Truncated, reason)
+                }
+            } else if tb == fb {
+                if lossless {
+                    let reason = format!("the {tb} bits {before} stay the same and mean {value:?} in both types");
+                    (CastKind::Lossless, reason)
+                } else {
+                    // Only the meaning of the top bit differs.
+                    let weight = match To::KIND {
+                        NumberKind::Signed => "-",
+                        _ => "+",
+                    };
+                    let reason = format!(
+                        "the {tb} bits {before} stay the same, but as {to} the top bit counts {weight}2^{}",
+                        tb - 1
+                    );
+                    (CastKind::Wrapped, reason)
+                }
+            } else {
+                let extend = match From::KIND {
+                    NumberKind::Signed => "sign-extends",
+                    _ => "zero-extends",
+                };
+                if lossless {
+                    let reason = format!("{extend} {before} to {after}, which keeps the value");
+                    (CastKind::Lossless, reason)
+                } else {
+                    let reason =
+                        format!("{extend} {before} to {after}, whose top bit counts +2^{} in {to}", tb - 1);
+                    (CastKind::Wrapped, reason)
+                }
+            }
+        }
+    };
+    CastExplanation { value, result, kind, reason }
+}
+
+fn explain_float_cast<From: Primitive, To: Primitive>(value: From, result: To) -> (CastKind, String) {
+    let (before, after) = (hex(value.to_bits(), From::BITS), hex(result.to_bits(), To::BITS));
+    let (float, rounded) = (value.to_f64(), result.to_f64());
+    if From::BITS == To::BITS {
+        return (CastKind::Lossless, format!("same type, same bits {before}"));
+    }
+    if From::BITS < To::BITS {
+        let reason = format!(
+            "every {} fits in {} exactly ({} of {} significant bits): {before} -> {after}",
+            From::NAME,
+            To::NAME,
+            precision(From::BITS),
+            precision(To::BITS)
+        );
+        return (CastKind::Lossless, reason);
+    }
+    if float == rounded || float.is_nan() && rounded.is_nan() {
+        let reason = format!("{value:?} is exactly representable in {}: {before} -> {after}", To::NAME);
+        (CastKind::Lossless, reason)
+    } else if rounded.is_infinite() {
+        let reason = format!("{value:?} is beyond {}::MAX and rounds to infinity ({after})", To::NAME);
+        (CastKind::PrecisionLost, reason)
+    } else {
+        let reason = format!(
+            "{value:?} ({before}) doesn't fit in {} significant bits and rounds to the nearest {}, {after}, which is exactly {rounded:?}",
+            precision(To::BITS),
+            To::NAME
+        );
+        (CastKind::PrecisionLost, reason)
+    }
+}
+
+// What casting between each pair of number types did to the sample values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastTable {
+    // In row and column order.
+    pub types: Vec<&'static str>,
+    // `kinds[from][to]`, sorted.
+    kinds: Vec<Vec<Vec<CastKind>>>,
+}
+
+impl CastTable {
+    pub fn kinds(&self, from: &str, to: &str) -> Option<&[CastKind]> {
+        let from = self.types.iter().position(|t| *t == from)?;
+        let to = self.types.iter().position(|t| *t == to)?;
+        Some(&self.kinds[from][to])
+    }
+}
+
+fn kinds<From: CastTo<To>, To: Primitive>() -> Vec<CastKind> {
+    let mut kinds: Vec<CastKind> =
+        From::samples().into_iter().map(|value| explain_cast::<From, To>(value).kind).collect();
+    kinds.sort();
+    kinds.dedup();
+    kinds
+}
+
+macro_rules! table_row {
+    ($from:ty => $($to:ty),*) => {
+        vec![$(kinds::<$from, $to>()),*]
+    };
+}
+
+macro_rules! table {
+    ($($t:ty),*) => {
+        CastTable {
+            types: vec![$(<$t as Primitive>::NAME),*],
+            kinds: vec![$(
+                table_row!($t => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64)
+            ),*],
+        }
+    };
+}
+
+pub fn cast_table() -> CastTable {
+    table!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64)
+}
+
+impl fmt::Display for CastTable {
+    // Rows are the source types, columns the targets. A cell lists what
+    // happened besides lossless casts, or `=` if every cast was lossless.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const WIDTH: usize = 6;
+        write!(f, "{:>WIDTH$}", "as")?;
+        for to in &self.types {
+            write!(f, "{to:>WIDTH$}")?;
+        }
+        for (from, row) in self.types.iter().zip(&self.kinds) {
+            write!(f, "\n{from:>WIDTH$}")?;
+            for kinds in row {
+                let cell: String = match kinds[..] {
+                    [CastKind::Lossless] => "=".to_string(),
+                    _ => kinds.iter().filter(|k| **k != CastKind::Lossless).map(CastKind::code).collect(),
+                };
+                write!(f, "{cell:>WIDTH$}")?;
+            }
+        }
+        for kind in &CastKind::ALL[1..] {
+            write!(f, "\n{}: {}", kind.code(), kind)?;
+        }
+        Ok(())
+    }
+}
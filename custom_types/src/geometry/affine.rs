@@ -0,0 +1,167 @@
+use std::fmt;
+use std::ops::Mul;
+
+use super::{GeometryError, Point, Polygon, Rectangle};
+
+// A 2D affine transform: a linear map followed by a translation. It is the
+// matrix
+//
+//     | a c e |
+//     | b d f |
+//     | 0 0 1 |
+//
+// with the letters in the order SVG's `matrix(a b c d e f)` uses.
+//
+// `outer * inner` applies `inner` first, as with matrices; `first.then(second)`
+// reads in the order things happen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2 {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+impl Affine2 {
+    pub const IDENTITY: Affine2 = Affine2::new([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    pub const fn new([a, b, c, d, e, f]: [f64; 6]) -> Affine2 {
+        Affine2 { a, b, c, d, e, f }
+    }
+
+    pub fn to_array(self) -> [f64; 6] {
+        [self.a, self.b, self.c, self.d, self.e, self.f]
+    }
+
+    pub fn translate(x: f64, y: f64) -> Affine2 {
+        Affine2::new([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    pub fn scale(x: f64, y: f64) -> Affine2 {
+        Affine2::new([x, 0.0, 0.0, y, 0.0, 0.0])
+    }
+
+    // Anticlockwise by `radians` about the origin, for a y axis that points
+    // up.
+    pub fn rotate(radians: f64) -> Affine2 {
+        let (sin, cos) = radians.sin_cos();
+        Affine2::new([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    pub fn rotate_about(center: Point, radians: f64) -> Affine2 {
+        Affine2::translate(center.x, center.y)
+            * Affine2::rotate(radians)
+            * Affine2::translate(-center.x, -center.y)
+    }
+
+    // Moves x by `x` times y, and y by `y` times x.
+    pub fn shear(x: f64, y: f64) -> Affine2 {
+        Affine2::new([1.0, y, x, 1.0, 0.0, 0.0])
+    }
+
+    // `self`, and then `next`.
+    pub fn then(self, next: Affine2) -> Affine2 {
+        next * self
+    }
+
+    // How much areas are scaled by; negative if the transform mirrors.
+    pub fn determinant(self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    // `None` if the transform squashes the plane onto a line or a point.
+    pub fn inverse(self) -> Option<Affine2> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+        Some(Affine2 {
+            a,
+            b,
+            c,
+            d,
+            e: -(a * self.e + c * self.f),
+            f: -(b * self.e + d * self.f),
+        })
+    }
+
+    pub fn apply(self, point: Point) -> Point {
+        Point::new(
+            self.a * point.x + self.c * point.y + self.e,
+            self.b * point.x + self.d * point.y + self.f,
+        )
+    }
+
+    // Applies only the linear part, for an offset rather than a position.
+    pub fn apply_vector(self, offset: Point) -> Point {
+        Point::new(self.a * offset.x + self.c * offset.y, self.b * offset.x + self.d * offset.y)
+    }
+
+    // Whether axis-aligned rectangles stay axis-aligned: no rotation or
+    // shear, other than by quarter turns.
+    pub fn keeps_axes(self) -> bool {
+        (self.b == 0.0 && self.c == 0.0) || (self.a == 0.0 && self.d == 0.0)
+    }
+
+    // The rectangle's corners moved, as a quad in the same order; see
+    // `apply_polygon` for the error.
+    pub fn apply_rectangle(self, rectangle: &Rectangle) -> Result<Polygon, GeometryError> {
+        self.apply_polygon(&Polygon::from(*rectangle))
+    }
+
+    // The smallest axis-aligned rectangle holding the moved rectangle, which
+    // is exactly it when `keeps_axes`. Fails like `apply_polygon`.
+    pub fn apply_bounds(self, rectangle: &Rectangle) -> Result<Rectangle, GeometryError> {
+        let [a, b, c, d] = rectangle.corners().map(|p| self.apply(p));
+        let diagonal = Rectangle::try_from_corners(a, c)?;
+        Ok(diagonal.union(&Rectangle::try_from_corners(b, d)?))
+    }
+
+    // Fails with `NotFinite` when a moved vertex overflows, which a large
+    // enough scale does to any polygon.
+    pub fn apply_polygon(self, polygon: &Polygon) -> Result<Polygon, GeometryError> {
+        let vertices = polygon.vertices().iter().map(|&p| self.apply(p)).collect();
+        Polygon::new(vertices)
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Affine2 {
+        Affine2::IDENTITY
+    }
+}
+
+impl Mul for Affine2 {
+    type Output = Affine2;
+
+    // `self` after `rhs`.
+    fn mul(self, rhs: Affine2) -> Affine2 {
+        Affine2 {
+            a: self.a * rhs.a + self.c * rhs.b,
+            b: self.b * rhs.a + self.d * rhs.b,
+            c: self.a * rhs.c + self.c * rhs.d,
+            d: self.b * rhs.c + self.d * rhs.d,
+            e: self.a * rhs.e + self.c * rhs.f + self.e,
+            f: self.b * rhs.e + self.d * rhs.f + self.f,
+        }
+    }
+}
+
+impl Mul<Point> for Affine2 {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Point {
+        self.apply(rhs)
+    }
+}
+
+impl fmt::Display for Affine2 {
+    // `matrix(1 0 0 1 0 0)`, as SVG's `transform` attribute takes it.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.to_array();
+        write!(f, "matrix({a} {b} {c} {d} {e} {g})")
+    }
+}
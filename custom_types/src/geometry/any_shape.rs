@@ -0,0 +1,72 @@
+use super::{Circle, HasArea, Point, Polygon, Rectangle, Shape, Triangle};
+
+// Any of the shapes, for lists that mix them. A lone `Point` counts as a
+// shape with no size.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyShape {
+    Point(Point),
+    Circle(Circle),
+    Rectangle(Rectangle),
+    Triangle(Triangle),
+    Polygon(Polygon),
+}
+
+impl AnyShape {
+    // The shape as a trait object, or `None` for a point.
+    fn shape(&self) -> Option<&dyn Shape> {
+        match self {
+            AnyShape::Point(_) => None,
+            AnyShape::Circle(c) => Some(c),
+            AnyShape::Rectangle(r) => Some(r),
+            AnyShape::Triangle(t) => Some(t),
+            AnyShape::Polygon(p) => Some(p),
+        }
+    }
+}
+
+impl HasArea for AnyShape {
+    fn area(&self) -> f64 {
+        self.shape().map_or(0.0, |s| s.area())
+    }
+}
+
+impl Shape for AnyShape {
+    fn perimeter(&self) -> f64 {
+        self.shape().map_or(0.0, |s| s.perimeter())
+    }
+
+    fn centroid(&self) -> Point {
+        match self {
+            AnyShape::Point(p) => *p,
+            _ => self.shape().unwrap().centroid(),
+        }
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        match self {
+            AnyShape::Point(p) => Rectangle::from_corners(*p, *p),
+            _ => self.shape().unwrap().bounding_box(),
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        match self {
+            AnyShape::Point(p) => *p == point,
+            _ => self.shape().unwrap().contains(point),
+        }
+    }
+}
+
+macro_rules! any_shape_from {
+    ($($variant:ident),*) => {
+        $(
+            impl From<$variant> for AnyShape {
+                fn from(shape: $variant) -> AnyShape {
+                    AnyShape::$variant(shape)
+                }
+            }
+        )*
+    };
+}
+
+any_shape_from!(Point, Circle, Rectangle, Triangle, Polygon);
@@ -0,0 +1,54 @@
+use std::f64::consts::PI;
+
+use super::{GeometryError, HasArea, Point, Rectangle, Shape};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    center: Point,
+    radius: f64,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: f64) -> Result<Circle, GeometryError> {
+        if !center.is_finite() || !radius.is_finite() {
+            return Err(GeometryError::NotFinite);
+        }
+        if radius < 0.0 {
+            return Err(GeometryError::NegativeRadius(radius));
+        }
+        Ok(Circle { center, radius })
+    }
+
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl HasArea for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Circle {
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn centroid(&self) -> Point {
+        self.center
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        let corner = Point::new(self.radius, self.radius);
+        Rectangle::from_corners(self.center - corner, self.center + corner)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.center.distance(point) <= self.radius
+    }
+}
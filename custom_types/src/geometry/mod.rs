@@ -0,0 +1,77 @@
+// The `Point`, `Rectangle`, `Trangle` and `Circle` that `structures`,
+// `functions::methods`, `generics::bounds` and `to_from_string` each define
+// for themselves, as one set of shapes with the measurements they share,
+// plus `Polygon` for everything else.
+//
+// Coordinates are `f64`s on plain x and y axes; nothing here assumes the
+// y axis points up or down. Shapes are closed: a point on the edge is inside.
+
+mod affine;
+mod any_shape;
+mod circle;
+mod point;
+mod polygon;
+mod quadtree;
+mod rectangle;
+mod svg;
+mod text;
+mod triangle;
+
+pub use affine::Affine2;
+pub use any_shape::AnyShape;
+pub use circle::Circle;
+pub use point::Point;
+pub use polygon::{convex_hull, Polygon};
+pub use quadtree::{ItemId, Quadtree};
+pub use rectangle::Rectangle;
+pub use svg::{Style, Svg};
+pub use text::{format_shapes, parse_shapes, ParseShapeError, ParseShapeErrorKind};
+pub use triangle::Triangle;
+
+use std::error;
+use std::fmt;
+
+pub trait HasArea {
+    fn area(&self) -> f64;
+}
+
+pub trait Shape: HasArea {
+    fn perimeter(&self) -> f64;
+    // The centre of mass of the shape's area.
+    fn centroid(&self) -> Point;
+    // The smallest axis-aligned rectangle holding the shape.
+    fn bounding_box(&self) -> Rectangle;
+    fn contains(&self, point: Point) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeometryError {
+    // A coordinate or length that is NaN or infinite.
+    NotFinite,
+    NegativeRadius(f64),
+    // A polygon needs at least three.
+    TooFewVertices(usize),
+    // Side lengths that break the triangle inequality, which Heron's
+    // formula would turn into `NaN`.
+    ImpossibleSides { a: f64, b: f64, c: f64 },
+    // Three vertices on one line.
+    Collinear,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeometryError::NotFinite => write!(f, "coordinates and lengths must be finite"),
+            GeometryError::NegativeRadius(r) => write!(f, "radius {r} is negative"),
+            GeometryError::TooFewVertices(n) => {
+                write!(f, "a polygon needs at least 3 vertices, not {n}")
+            }
+            GeometryError::ImpossibleSides { a, b, c } => {
+                write!(f, "sides {a}, {b} and {c} don't make a triangle")
+            }
+            GeometryError::Collinear => write!(f, "the vertices are on one line"),
+        }
+    }
+}
+
+impl error::Error for GeometryError {}
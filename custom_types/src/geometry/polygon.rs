@@ -0,0 +1,213 @@
+use super::{GeometryError, HasArea, Point, Rectangle, Shape, Triangle};
+
+// A closed polygon through its vertices in order; the last one joins back
+// to the first. It may be concave, and even cross itself: `contains` goes by
+// the winding number, so loops that wind the same way twice count once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Result<Polygon, GeometryError> {
+        if vertices.len() < 3 {
+            return Err(GeometryError::TooFewVertices(vertices.len()));
+        }
+        if !vertices.iter().all(|v| v.is_finite()) {
+            return Err(GeometryError::NotFinite);
+        }
+        Ok(Polygon { vertices })
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    // Each edge as its two ends, the closing edge last.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    // The shoelace formula: positive if the vertices go anticlockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|(a, b)| a.cross(b)).sum::<f64>() / 2.0
+    }
+
+    pub fn is_counterclockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    pub fn reversed(&self) -> Polygon {
+        Polygon { vertices: self.vertices.iter().rev().copied().collect() }
+    }
+
+    // Pairs of edges, by the index of their first vertex, that meet other
+    // than where neighbours share a vertex.
+    pub fn self_intersections(&self) -> Vec<(usize, usize)> {
+        let edges: Vec<(Point, Point)> = self.edges().collect();
+        let n = edges.len();
+        let mut found = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                let (a, b) = edges[i];
+                let (c, d) = edges[j];
+                let neighbours = j == i + 1 || (i == 0 && j == n - 1);
+                let meet = if neighbours {
+                    // Only their shared vertex, unless they fold back
+                    // along each other.
+                    let (shared, other) = if j == i + 1 { (b, d) } else { (a, c) };
+                    let far = if j == i + 1 { a } else { b };
+                    (other - shared).cross(far - shared) == 0.0
+                        && (other - shared).dot(far - shared) > 0.0
+                } else {
+                    segments_intersect(a, b, c, d)
+                };
+                if meet {
+                    found.push((i, j));
+                }
+            }
+        }
+        found
+    }
+
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    // Simple, and turning the same way at every vertex. Straight-through
+    // vertices are allowed.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut turn = 0.0;
+        for i in 0..n {
+            let [a, b, c] = [0, 1, 2].map(|k| self.vertices[(i + k) % n]);
+            let cross = (b - a).cross(c - b);
+            if cross * turn < 0.0 {
+                return false;
+            }
+            if cross != 0.0 {
+                turn = cross;
+            }
+        }
+        turn != 0.0 && self.is_simple()
+    }
+
+    // How many times the boundary goes anticlockwise around `point`, less
+    // the times it goes clockwise. Points on the boundary give whatever
+    // side they round to.
+    pub fn winding_number(&self, point: Point) -> i32 {
+        let mut winding = 0;
+        for (a, b) in self.edges() {
+            let side = (b - a).cross(point - a);
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    pub fn on_boundary(&self, point: Point) -> bool {
+        self.edges().any(|(a, b)| on_segment(a, b, point))
+    }
+}
+
+// The smallest convex polygon holding all of `points`, anticlockwise and
+// without vertices in the middle of an edge, by Andrew's monotone chain.
+// `None` if the points are all on one line.
+pub fn convex_hull(points: &[Point]) -> Option<Polygon> {
+    let mut points: Vec<Point> = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return None;
+    }
+    // Builds one side of the hull, keeping only left turns.
+    fn chain<'a>(points: impl Iterator<Item = &'a Point>) -> Vec<Point> {
+        let mut chain: Vec<Point> = Vec::new();
+        for &p in points {
+            while let [.., a, b] = chain[..] {
+                if (b - a).cross(p - b) > 0.0 {
+                    break;
+                }
+                chain.pop();
+            }
+            chain.push(p);
+        }
+        // The last point starts the other chain.
+        chain.pop();
+        chain
+    }
+    let mut hull = chain(points.iter());
+    hull.extend(chain(points.iter().rev()));
+    Polygon::new(hull).ok()
+}
+
+// Whether `point` is on the segment from `a` to `b`, ends included.
+fn on_segment(a: Point, b: Point, point: Point) -> bool {
+    (b - a).cross(point - a) == 0.0
+        && point.x >= a.x.min(b.x)
+        && point.x <= a.x.max(b.x)
+        && point.y >= a.y.min(b.y)
+        && point.y <= a.y.max(b.y)
+}
+
+// Whether segments `ab` and `cd` share a point.
+fn segments_intersect(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let side = |from: Point, to: Point, p: Point| (to - from).cross(p - from).signum();
+    let (c_side, d_side) = (side(a, b, c), side(a, b, d));
+    let (a_side, b_side) = (side(c, d, a), side(c, d, b));
+    (c_side * d_side < 0.0 && a_side * b_side < 0.0)
+        || on_segment(a, b, c)
+        || on_segment(a, b, d)
+        || on_segment(c, d, a)
+        || on_segment(c, d, b)
+}
+
+impl HasArea for Polygon {
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+}
+
+impl Shape for Polygon {
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance(b)).sum()
+    }
+
+    // The centroid of the area; for a polygon without any, the average of
+    // its vertices.
+    fn centroid(&self) -> Point {
+        let area = self.signed_area();
+        if area == 0.0 {
+            let sum = self.vertices.iter().fold(Point::origin(), |sum, &v| sum + v);
+            return sum * (1.0 / self.vertices.len() as f64);
+        }
+        let sum = self.edges().fold(Point::origin(), |sum, (a, b)| sum + (a + b) * a.cross(b));
+        sum * (1.0 / (6.0 * area))
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::around(self.vertices.iter().copied()).expect("a polygon has vertices")
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.winding_number(point) != 0 || self.on_boundary(point)
+    }
+}
+
+impl From<Rectangle> for Polygon {
+    fn from(rectangle: Rectangle) -> Polygon {
+        Polygon { vertices: rectangle.corners().to_vec() }
+    }
+}
+
+impl From<Triangle> for Polygon {
+    fn from(triangle: Triangle) -> Polygon {
+        Polygon { vertices: triangle.vertices().to_vec() }
+    }
+}
@@ -0,0 +1,360 @@
+// A quadtree over things with a bounding rectangle, for finding the ones in
+// an area or near a point without looking at all of them.
+//
+// Each node covers a rectangle and splits into four equal quarters once it
+// holds more than `capacity` items. An item lives in the deepest node whose
+// rectangle holds all of its own, so items straddling a split line stay
+// higher up. Items outside the root's rectangle are kept in the root, which
+// keeps queries correct but makes them slow if there are many: size the root
+// to the data, or use `bulk_load`, which does.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::{Point, Rectangle};
+
+// Identifies an inserted item. The slot it names is reused after a remove,
+// but under a new generation, so a stale id finds nothing rather than the
+// item that took its place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemId {
+    index: usize,
+    generation: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    // Bumped each time the slot is emptied.
+    generation: u32,
+    item: Option<(Rectangle, T)>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rectangle,
+    depth: usize,
+    items: Vec<ItemId>,
+    // Items in this node and all below it.
+    count: usize,
+    children: Option<Box<[Node; 4]>>,
+}
+
+impl Node {
+    fn new(bounds: Rectangle, depth: usize) -> Node {
+        Node { bounds, depth, items: Vec::new(), count: 0, children: None }
+    }
+
+    fn quarters(&self) -> [Rectangle; 4] {
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let mid = min.midpoint(max);
+        [
+            Rectangle::from_corners(min, mid),
+            Rectangle::from_corners(Point::new(mid.x, min.y), Point::new(max.x, mid.y)),
+            Rectangle::from_corners(Point::new(min.x, mid.y), Point::new(mid.x, max.y)),
+            Rectangle::from_corners(mid, max),
+        ]
+    }
+
+    // The quarter that holds all of `bounds`, if one does.
+    fn child_for(&self, bounds: &Rectangle) -> Option<usize> {
+        let children = self.children.as_ref()?;
+        children.iter().position(|child| child.bounds.contains_rectangle(bounds))
+    }
+
+    fn collect(&self, into: &mut Vec<ItemId>) {
+        into.extend(&self.items);
+        if let Some(children) = &self.children {
+            children.iter().for_each(|child| child.collect(into));
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Quadtree<T> {
+    root: Node,
+    // Indexed by `ItemId::index`.
+    slots: Vec<Slot<T>>,
+    // Indices of the empty slots.
+    free: Vec<usize>,
+    capacity: usize,
+    max_depth: usize,
+}
+
+impl<T> Quadtree<T> {
+    pub const DEFAULT_CAPACITY: usize = 8;
+    pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+    pub fn new(bounds: Rectangle) -> Quadtree<T> {
+        Quadtree::with_capacity(bounds, Quadtree::<T>::DEFAULT_CAPACITY, Quadtree::<T>::DEFAULT_MAX_DEPTH)
+    }
+
+    // `capacity` items per node before it splits, and at most `max_depth`
+    // splits below the root.
+    pub fn with_capacity(bounds: Rectangle, capacity: usize, max_depth: usize) -> Quadtree<T> {
+        Quadtree {
+            root: Node::new(bounds, 0),
+            slots: Vec::new(),
+            free: Vec::new(),
+            capacity: capacity.max(1),
+            max_depth,
+        }
+    }
+
+    // A tree over exactly the items' bounds, built top down by splitting
+    // the whole set rather than inserting one at a time. Ids count up from
+    // zero in the items' order.
+    pub fn bulk_load(items: Vec<(Rectangle, T)>) -> Quadtree<T> {
+        let bounds = Rectangle::around(items.iter().flat_map(|(r, _)| [r.min(), r.max()]))
+            .unwrap_or(Rectangle::from_corners(Point::origin(), Point::origin()));
+        let mut tree = Quadtree::new(bounds);
+        let entries: Vec<(ItemId, Rectangle)> = items
+            .iter()
+            .enumerate()
+            .map(|(index, (bounds, _))| (ItemId { index, generation: 0 }, *bounds))
+            .collect();
+        tree.slots =
+            items.into_iter().map(|item| Slot { generation: 0, item: Some(item) }).collect();
+        let mut root = Node::new(bounds, 0);
+        tree.build(&mut root, entries);
+        tree.root = root;
+        tree
+    }
+
+    fn build(&self, node: &mut Node, entries: Vec<(ItemId, Rectangle)>) {
+        node.count = entries.len();
+        if entries.len() <= self.capacity || node.depth >= self.max_depth {
+            node.items = entries.into_iter().map(|(id, _)| id).collect();
+            return;
+        }
+        let quarters = node.quarters();
+        let mut parts: [Vec<(ItemId, Rectangle)>; 4] = Default::default();
+        for (id, bounds) in entries {
+            match quarters.iter().position(|q| q.contains_rectangle(&bounds)) {
+                Some(i) => parts[i].push((id, bounds)),
+                None => node.items.push(id),
+            }
+        }
+        let mut children = quarters.map(|q| Node::new(q, node.depth + 1));
+        for (child, part) in children.iter_mut().zip(parts) {
+            self.build(child, part);
+        }
+        node.children = Some(Box::new(children));
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn bounds(&self) -> Rectangle {
+        self.root.bounds
+    }
+
+    pub fn get(&self, id: ItemId) -> Option<(&Rectangle, &T)> {
+        let slot = self.slots.get(id.index).filter(|slot| slot.generation == id.generation)?;
+        slot.item.as_ref().map(|(bounds, value)| (bounds, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, &Rectangle, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let id = ItemId { index, generation: slot.generation };
+            slot.item.as_ref().map(|(bounds, value)| (id, bounds, value))
+        })
+    }
+
+    fn bounds_of(&self, id: ItemId) -> Rectangle {
+        self.slots[id.index].item.as_ref().expect("live item").0
+    }
+
+    pub fn insert(&mut self, bounds: Rectangle, value: T) -> ItemId {
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slots[index].item = Some((bounds, value));
+                index
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, item: Some((bounds, value)) });
+                self.slots.len() - 1
+            }
+        };
+        let id = ItemId { index, generation: self.slots[index].generation };
+        let mut root = std::mem::replace(&mut self.root, Node::new(bounds, 0));
+        self.insert_into(&mut root, id, &bounds);
+        self.root = root;
+        id
+    }
+
+    pub fn insert_point(&mut self, point: Point, value: T) -> ItemId {
+        self.insert(Rectangle::from_corners(point, point), value)
+    }
+
+    fn insert_into(&self, node: &mut Node, id: ItemId, bounds: &Rectangle) {
+        node.count += 1;
+        if let Some(i) = node.child_for(bounds) {
+            let child = &mut node.children.as_mut().unwrap()[i];
+            return self.insert_into(child, id, bounds);
+        }
+        node.items.push(id);
+        if node.children.is_none() && node.items.len() > self.capacity && node.depth < self.max_depth {
+            let mut children = node.quarters().map(|q| Node::new(q, node.depth + 1));
+            let items = std::mem::take(&mut node.items);
+            for item in items {
+                let item_bounds = self.bounds_of(item);
+                match children.iter().position(|c| c.bounds.contains_rectangle(&item_bounds)) {
+                    Some(i) => {
+                        children[i].items.push(item);
+                        children[i].count += 1;
+                    }
+                    None => node.items.push(item),
+                }
+            }
+            // A child can be over capacity if everything went into it; it
+            // splits when the next item arrives.
+            node.children = Some(Box::new(children));
+        }
+    }
+
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let bounds = self.get(id)?.0.to_owned();
+        let capacity = self.capacity;
+        remove_from(&mut self.root, id, &bounds, capacity);
+        let slot = &mut self.slots[id.index];
+        let (_, value) = slot.item.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.index);
+        Some(value)
+    }
+
+    // Items whose bounds share any point with `area`.
+    pub fn query(&self, area: &Rectangle) -> Vec<ItemId> {
+        let mut found = Vec::new();
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            found.extend(node.items.iter().filter(|&&id| self.bounds_of(id).intersects(area)));
+            if let Some(children) = &node.children {
+                stack.extend(children.iter().filter(|child| child.bounds.intersects(area)));
+            }
+        }
+        found.sort();
+        found
+    }
+
+    // Items whose bounds hold `point`: what a click at `point` hits.
+    pub fn query_point(&self, point: Point) -> Vec<ItemId> {
+        self.query(&Rectangle::from_corners(point, point))
+    }
+
+    // The `k` items nearest `point`, by the distance to their bounds, closest
+    // first. Ties go to the lower id.
+    pub fn nearest(&self, point: Point, k: usize) -> Vec<(ItemId, f64)> {
+        // Best first: the heap holds nodes by how close their rectangle is
+        // and items by how close they are, so an item comes off it only when
+        // nothing unexplored can be closer.
+        let mut heap = BinaryHeap::new();
+        let mut found = Vec::new();
+        if k == 0 {
+            return found;
+        }
+        heap.push(Candidate { distance: 0.0, entry: Entry::Node(&self.root) });
+        while let Some(Candidate { distance, entry }) = heap.pop() {
+            match entry {
+                Entry::Item(id) => {
+                    found.push((id, distance));
+                    if found.len() == k {
+                        break;
+                    }
+                }
+                Entry::Node(node) => {
+                    for &id in &node.items {
+                        let distance = self.bounds_of(id).distance_to(point);
+                        heap.push(Candidate { distance, entry: Entry::Item(id) });
+                    }
+                    for child in node.children.iter().flat_map(|c| c.iter()) {
+                        let distance = child.bounds.distance_to(point);
+                        heap.push(Candidate { distance, entry: Entry::Node(child) });
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    // How many nodes deep the tree goes, 0 for a lone root.
+    pub fn depth(&self) -> usize {
+        fn depth(node: &Node) -> usize {
+            node.children.as_ref().map_or(0, |c| 1 + c.iter().map(depth).max().unwrap_or(0))
+        }
+        depth(&self.root)
+    }
+}
+
+// Takes `id` out of the node that holds it, folding children back into
+// their parent once they all fit in it again.
+fn remove_from(node: &mut Node, id: ItemId, bounds: &Rectangle, capacity: usize) -> bool {
+    let removed = match node.child_for(bounds) {
+        Some(i) => remove_from(&mut node.children.as_mut().unwrap()[i], id, bounds, capacity),
+        None => match node.items.iter().position(|&item| item == id) {
+            Some(i) => {
+                node.items.swap_remove(i);
+                true
+            }
+            None => false,
+        },
+    };
+    if !removed {
+        return false;
+    }
+    node.count -= 1;
+    if node.children.is_some() && node.count <= capacity {
+        let mut items = std::mem::take(&mut node.items);
+        node.collect(&mut items);
+        node.items = items;
+        node.children = None;
+    }
+    true
+}
+
+enum Entry<'a> {
+    Node(&'a Node),
+    Item(ItemId),
+}
+
+// Orders `BinaryHeap`, a max-heap, nearest first. At the same distance nodes
+// go first, so every item at that distance is in the heap before any is
+// taken, and then lower ids.
+struct Candidate<'a> {
+    distance: f64,
+    entry: Entry<'a>,
+}
+
+impl Candidate<'_> {
+    fn rank(&self) -> (u8, usize) {
+        match self.entry {
+            Entry::Node(_) => (0, 0),
+            Entry::Item(id) => (1, id.index),
+        }
+    }
+}
+
+impl Ord for Candidate<'_> {
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        other.distance.total_cmp(&self.distance).then_with(|| other.rank().cmp(&self.rank()))
+    }
+}
+
+impl PartialOrd for Candidate<'_> {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate<'_> {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate<'_> {}
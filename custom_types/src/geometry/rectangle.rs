@@ -0,0 +1,142 @@
+use super::{GeometryError, HasArea, Point, Shape};
+
+// An axis-aligned rectangle, kept as its lowest and highest corners, so it
+// doesn't matter which pair of opposite corners it was built from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    min: Point,
+    max: Point,
+}
+
+impl Rectangle {
+    // Any two opposite corners, like `structures`' `top_left` and
+    // `right_bottom`. The corners aren't checked: a `NaN` coordinate gives
+    // way to the other corner's and infinities are kept, so use
+    // `try_from_corners` for input that may not be finite.
+    pub fn from_corners(a: Point, b: Point) -> Rectangle {
+        Rectangle {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn try_from_corners(a: Point, b: Point) -> Result<Rectangle, GeometryError> {
+        if !a.is_finite() || !b.is_finite() {
+            return Err(GeometryError::NotFinite);
+        }
+        Ok(Rectangle::from_corners(a, b))
+    }
+
+    // Unchecked like `from_corners`; see `try_from_size`.
+    pub fn from_size(corner: Point, width: f64, height: f64) -> Rectangle {
+        Rectangle::from_corners(corner, corner + Point::new(width, height))
+    }
+
+    pub fn try_from_size(
+        corner: Point,
+        width: f64,
+        height: f64,
+    ) -> Result<Rectangle, GeometryError> {
+        Rectangle::try_from_corners(corner, corner + Point::new(width, height))
+    }
+
+    // The smallest rectangle holding all of `points`, or `None` if there
+    // are none.
+    pub fn around<I: IntoIterator<Item = Point>>(points: I) -> Option<Rectangle> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rectangle::from_corners(first, first), |r, p| {
+            r.union(&Rectangle::from_corners(p, p))
+        }))
+    }
+
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    // Counterclockwise from `min`, for a y axis that points up.
+    pub fn corners(&self) -> [Point; 4] {
+        [self.min, Point::new(self.max.x, self.min.y), self.max, Point::new(self.min.x, self.max.y)]
+    }
+
+    pub fn translate(&mut self, x: f64, y: f64) {
+        let offset = Point::new(x, y);
+        self.min = self.min + offset;
+        self.max = self.max + offset;
+    }
+
+    // Whether the two share any point, edges included.
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    // The area the two share. Rectangles that only touch share a line,
+    // which is a rectangle with no width or height.
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Rectangle {
+            min: Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        })
+    }
+
+    // The smallest rectangle holding both.
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        Rectangle {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    pub fn contains_rectangle(&self, other: &Rectangle) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    // How far `point` is from the rectangle; 0 inside it.
+    pub fn distance_to(&self, point: Point) -> f64 {
+        let dx = (self.min.x - point.x).max(point.x - self.max.x).max(0.0);
+        let dy = (self.min.y - point.y).max(point.y - self.max.y).max(0.0);
+        dx.hypot(dy)
+    }
+}
+
+impl HasArea for Rectangle {
+    fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+}
+
+impl Shape for Rectangle {
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width() + self.height())
+    }
+
+    fn centroid(&self) -> Point {
+        self.min.midpoint(self.max)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        *self
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
@@ -0,0 +1,327 @@
+// Draws shapes as a standalone SVG document, for looking at geometry
+// instead of sketching it:
+//
+//     let mut svg = Svg::new();
+//     svg.add(room, Style::new().fill("#eef").label("kitchen"));
+//     svg.add(Point::new(1.0, 4.0), Style::new().stroke("red"));
+//     fs::write("plan.svg", svg.to_string())?;
+//
+// The view fits the shapes plus a margin. By default y points up, as in the
+// maths the shapes come from, so a positive rotation turns anticlockwise on
+// screen too; `y_down` draws in SVG's own orientation instead.
+// Stroke widths and font sizes are in pixels, whatever the scale.
+
+use std::fmt;
+use std::fmt::Write;
+
+use super::{AnyShape, Point, Rectangle, Shape};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    stroke: Option<String>,
+    stroke_width: f64,
+    dash: Option<f64>,
+    fill: Option<String>,
+    opacity: f64,
+    label: Option<String>,
+    label_color: String,
+    font_size: f64,
+}
+
+impl Style {
+    // A thin black outline with no fill or label.
+    pub fn new() -> Style {
+        Style {
+            stroke: Some("black".to_string()),
+            stroke_width: 1.0,
+            dash: None,
+            fill: None,
+            opacity: 1.0,
+            label: None,
+            label_color: "black".to_string(),
+            font_size: 12.0,
+        }
+    }
+
+    pub fn stroke(mut self, color: &str) -> Style {
+        self.stroke = Some(color.to_string());
+        self
+    }
+
+    pub fn no_stroke(mut self) -> Style {
+        self.stroke = None;
+        self
+    }
+
+    pub fn stroke_width(mut self, pixels: f64) -> Style {
+        self.stroke_width = pixels;
+        self
+    }
+
+    // Dashes and gaps of `pixels` each.
+    pub fn dashed(mut self, pixels: f64) -> Style {
+        self.dash = Some(pixels);
+        self
+    }
+
+    pub fn fill(mut self, color: &str) -> Style {
+        self.fill = Some(color.to_string());
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Style {
+        self.opacity = opacity;
+        self
+    }
+
+    // Text drawn at the shape's centroid.
+    pub fn label(mut self, text: &str) -> Style {
+        self.label = Some(text.to_string());
+        self
+    }
+
+    pub fn label_color(mut self, color: &str) -> Style {
+        self.label_color = color.to_string();
+        self
+    }
+
+    pub fn font_size(mut self, pixels: f64) -> Style {
+        self.font_size = pixels;
+        self
+    }
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    items: Vec<(AnyShape, Style)>,
+    title: Option<String>,
+    size: f64,
+    margin: f64,
+    point_radius: f64,
+    y_up: bool,
+}
+
+impl Svg {
+    pub fn new() -> Svg {
+        Svg { items: Vec::new(), title: None, size: 400.0, margin: 1.0, point_radius: 3.0, y_up: true }
+    }
+
+    pub fn add<S: Into<AnyShape>>(&mut self, shape: S, style: Style) -> &mut Svg {
+        self.items.push((shape.into(), style));
+        self
+    }
+
+    pub fn title(&mut self, title: &str) -> &mut Svg {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    // The length of the longer side of the picture, in pixels.
+    pub fn size(&mut self, pixels: f64) -> &mut Svg {
+        self.size = pixels;
+        self
+    }
+
+    // Space around the shapes, in the shapes' units.
+    pub fn margin(&mut self, margin: f64) -> &mut Svg {
+        self.margin = margin;
+        self
+    }
+
+    // The radius `Point`s are drawn with, in pixels.
+    pub fn point_radius(&mut self, pixels: f64) -> &mut Svg {
+        self.point_radius = pixels;
+        self
+    }
+
+    pub fn y_down(&mut self) -> &mut Svg {
+        self.y_up = false;
+        self
+    }
+
+    // The area shown, in the shapes' units.
+    pub fn view(&self) -> Rectangle {
+        let bounds = self
+            .items
+            .iter()
+            .map(|(shape, _)| shape.bounding_box())
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or(Rectangle::from_corners(Point::origin(), Point::origin()));
+        let margin = Point::new(self.margin, self.margin);
+        let view = Rectangle::from_corners(bounds.min() - margin, bounds.max() + margin);
+        // Never empty, so the scale stays finite.
+        if view.width() > 0.0 && view.height() > 0.0 {
+            view
+        } else {
+            Rectangle::from_size(view.min(), view.width().max(1.0), view.height().max(1.0))
+        }
+    }
+
+    // Where a point lands in SVG coordinates.
+    fn place(&self, point: Point) -> Point {
+        if self.y_up {
+            Point::new(point.x, -point.y)
+        } else {
+            point
+        }
+    }
+
+    fn write_shape(&self, out: &mut String, shape: &AnyShape, style: &Style, unit: f64) {
+        let places = decimals(unit);
+        let num = |value| round(value, places);
+        let attributes = attributes(style);
+        match shape {
+            AnyShape::Point(p) => {
+                let c = self.place(*p);
+                let r = num(self.point_radius * unit);
+                let _ = write!(out, r#"<circle cx="{}" cy="{}" r="{r}"{attributes}/>"#, num(c.x), num(c.y));
+            }
+            AnyShape::Circle(circle) => {
+                let c = self.place(circle.center());
+                let r = num(circle.radius());
+                let _ = write!(out, r#"<circle cx="{}" cy="{}" r="{r}"{attributes}/>"#, num(c.x), num(c.y));
+            }
+            AnyShape::Rectangle(r) => {
+                let (a, b) = (self.place(r.min()), self.place(r.max()));
+                let _ = write!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}"{attributes}/>"#,
+                    num(a.x.min(b.x)),
+                    num(a.y.min(b.y)),
+                    num(r.width()),
+                    num(r.height())
+                );
+            }
+            AnyShape::Triangle(t) => self.write_polygon(out, &t.vertices(), &attributes, places),
+            AnyShape::Polygon(p) => self.write_polygon(out, p.vertices(), &attributes, places),
+        }
+        if let Some(label) = &style.label {
+            let at = self.place(shape.centroid());
+            let _ = write!(
+                out,
+                "\n  <text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\" \
+                 text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>",
+                num(at.x),
+                num(at.y),
+                num(style.font_size * unit),
+                escape(&style.label_color),
+                escape(label)
+            );
+        }
+    }
+
+    fn write_polygon(&self, out: &mut String, vertices: &[Point], attributes: &str, places: usize) {
+        let points: Vec<String> = vertices
+            .iter()
+            .map(|&v| {
+                let v = self.place(v);
+                format!("{},{}", round(v.x, places), round(v.y, places))
+            })
+            .collect();
+        let _ = write!(out, r#"<polygon points="{}"{attributes}/>"#, points.join(" "));
+    }
+}
+
+impl Default for Svg {
+    fn default() -> Svg {
+        Svg::new()
+    }
+}
+
+// The presentation attributes for a style, each with a leading space.
+fn attributes(style: &Style) -> String {
+    let mut out = String::new();
+    match &style.fill {
+        Some(fill) => out += &format!(r#" fill="{}""#, escape(fill)),
+        None => out += r#" fill="none""#,
+    }
+    match &style.stroke {
+        Some(stroke) => {
+            out += &format!(
+                r#" stroke="{}" stroke-width="{}" vector-effect="non-scaling-stroke""#,
+                escape(stroke),
+                round(style.stroke_width, 3)
+            );
+            if let Some(dash) = style.dash {
+                out += &format!(r#" stroke-dasharray="{}""#, round(dash, 3));
+            }
+        }
+        None => out += r#" stroke="none""#,
+    }
+    if style.opacity != 1.0 {
+        out += &format!(r#" opacity="{}""#, round(style.opacity, 3));
+    }
+    out
+}
+
+// Decimal places that pin a coordinate down to a hundredth of a pixel, when
+// a pixel is `unit` long.
+fn decimals(unit: f64) -> usize {
+    (2.0 - unit.log10()).ceil().max(0.0) as usize
+}
+
+// A number to `places` decimal places, without trailing zeros or `-0`.
+fn round(value: f64, places: usize) -> String {
+    let text = format!("{:.*}", places, value);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    match text {
+        "-0" => "0".to_string(),
+        _ => text.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out += "&amp;",
+            '<' => out += "&lt;",
+            '>' => out += "&gt;",
+            '"' => out += "&quot;",
+            '\'' => out += "&apos;",
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let view = self.view();
+        let (a, b) = (self.place(view.min()), self.place(view.max()));
+        let corner = Point::new(a.x.min(b.x), a.y.min(b.y));
+        // Shape units per pixel.
+        let unit = view.width().max(view.height()) / self.size;
+        let num = |value| round(value, decimals(unit));
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            round(view.width() / unit, 3),
+            round(view.height() / unit, 3),
+            num(corner.x),
+            num(corner.y),
+            num(view.width()),
+            num(view.height())
+        )?;
+        if let Some(title) = &self.title {
+            writeln!(f, "  <title>{}</title>", escape(title))?;
+        }
+        for (shape, style) in &self.items {
+            let mut element = String::new();
+            self.write_shape(&mut element, shape, style, unit);
+            writeln!(f, "  {element}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
@@ -0,0 +1,372 @@
+// A one-line text format for the shapes, so a list of them can be kept in a
+// file and read back:
+//
+//     point (1,2)
+//     circle r=100 at (0,0)
+//     rect (0,0)-(3,4)
+//     triangle (0,0) (4,0) (0,3)
+//     triangle 4 5 6
+//     polygon (0,0) (6,0) (6,2.5) (0,2.5)
+//
+// A circle without `at` is centred on the origin. `triangle` takes either
+// three vertices or three side lengths, which are laid out the way
+// `Triangle::from_sides` does; either way it is written back as vertices, as
+// the sides of a placed triangle aren't exact. Whitespace is optional around
+// brackets, commas and the `-` of a `rect`.
+//
+// Numbers are written the way `f64`'s `Display` writes them, which is the
+// shortest text that parses back to the same number, so formatting a shape
+// and parsing it back gives the same shape.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{AnyShape, Circle, GeometryError, Point, Polygon, Rectangle, Triangle};
+
+impl AnyShape {
+    // The word that starts the shape's line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnyShape::Point(_) => "point",
+            AnyShape::Circle(_) => "circle",
+            AnyShape::Rectangle(_) => "rect",
+            AnyShape::Triangle(_) => "triangle",
+            AnyShape::Polygon(_) => "polygon",
+        }
+    }
+
+    // Parses one line, numbering errors with `line`.
+    pub fn parse_line(text: &str, line: usize) -> Result<AnyShape, ParseShapeError> {
+        let mut cursor = Cursor { text, pos: 0, line };
+        cursor.skip_space();
+        let column = cursor.column();
+        let name = cursor.word();
+        if name.is_empty() {
+            return Err(cursor.error(column, ParseShapeErrorKind::Empty));
+        }
+        let invalid =
+            |cursor: &Cursor, error| cursor.error(column, ParseShapeErrorKind::Invalid(error));
+        let shape = match name {
+            "point" => AnyShape::Point(cursor.point()?),
+            "circle" => {
+                cursor.expect("r=")?;
+                let radius_column = cursor.column_after_space();
+                let radius = cursor.number()?;
+                cursor.skip_space();
+                let center = if cursor.rest().starts_with("at") {
+                    cursor.expect("at")?;
+                    cursor.point()?
+                } else {
+                    Point::origin()
+                };
+                let circle = Circle::new(center, radius).map_err(|error| {
+                    cursor.error(radius_column, ParseShapeErrorKind::Invalid(error))
+                })?;
+                AnyShape::Circle(circle)
+            }
+            "rect" => {
+                let a = cursor.point()?;
+                cursor.expect("-")?;
+                let b = cursor.point()?;
+                AnyShape::Rectangle(Rectangle::from_corners(a, b))
+            }
+            "triangle" => {
+                cursor.skip_space();
+                let triangle = if cursor.rest().starts_with('(') {
+                    let (a, b, c) = (cursor.point()?, cursor.point()?, cursor.point()?);
+                    Triangle::new(a, b, c)
+                } else {
+                    let (a, b, c) = (cursor.number()?, cursor.number()?, cursor.number()?);
+                    Triangle::from_sides(a, b, c)
+                };
+                AnyShape::Triangle(triangle.map_err(|error| invalid(&cursor, error))?)
+            }
+            "polygon" => {
+                let mut vertices = Vec::new();
+                loop {
+                    cursor.skip_space();
+                    if !cursor.rest().starts_with('(') {
+                        break;
+                    }
+                    vertices.push(cursor.point()?);
+                }
+                AnyShape::Polygon(Polygon::new(vertices).map_err(|error| invalid(&cursor, error))?)
+            }
+            _ => {
+                let kind = ParseShapeErrorKind::UnknownShape(name.to_string());
+                return Err(cursor.error(column, kind));
+            }
+        };
+        cursor.skip_space();
+        if cursor.pos < text.len() {
+            return Err(cursor.error(cursor.column(), ParseShapeErrorKind::UnexpectedInput));
+        }
+        Ok(shape)
+    }
+}
+
+// Parses a whole document, one shape per line. Blank lines and lines
+// starting with `#` are skipped.
+pub fn parse_shapes(text: &str) -> Result<Vec<AnyShape>, ParseShapeError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim_start();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(i, line)| AnyShape::parse_line(line, i + 1))
+        .collect()
+}
+
+// The shapes one per line, each line ending in `\n`.
+pub fn format_shapes<'a>(shapes: impl IntoIterator<Item = &'a AnyShape>) -> String {
+    shapes.into_iter().map(|shape| format!("{shape}\n")).collect()
+}
+
+impl FromStr for AnyShape {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<AnyShape, ParseShapeError> {
+        AnyShape::parse_line(s, 1)
+    }
+}
+
+// `FromStr` for one kind of shape: the line has to be that kind.
+macro_rules! shape_from_str {
+    ($($variant:ident $name:literal),*) => {
+        $(
+            impl FromStr for $variant {
+                type Err = ParseShapeError;
+
+                fn from_str(s: &str) -> Result<$variant, ParseShapeError> {
+                    match s.parse()? {
+                        AnyShape::$variant(shape) => Ok(shape),
+                        other => {
+                            let offset = s.len() - s.trim_start_matches(is_space).len();
+                            let column = s[..offset].chars().count() + 1;
+                            let found = other.name();
+                            let kind = ParseShapeErrorKind::WrongShape { expected: $name, found };
+                            Err(ParseShapeError { line: 1, column, kind })
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+shape_from_str!(Circle "circle", Rectangle "rect", Triangle "triangle", Polygon "polygon");
+
+// A bare point, as `Point`'s `Display` writes it: `(1.5, -2)`.
+impl FromStr for Point {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Point, ParseShapeError> {
+        let mut cursor = Cursor { text: s, pos: 0, line: 1 };
+        let point = cursor.point()?;
+        cursor.skip_space();
+        if cursor.pos < s.len() {
+            return Err(cursor.error(cursor.column(), ParseShapeErrorKind::UnexpectedInput));
+        }
+        Ok(point)
+    }
+}
+
+// A point without the space `Point`'s `Display` puts after the comma.
+struct Compact(Point);
+
+impl fmt::Display for Compact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.0.x, self.0.y)
+    }
+}
+
+fn write_points(f: &mut fmt::Formatter, points: &[Point]) -> fmt::Result {
+    for &point in points {
+        write!(f, " {}", Compact(point))?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "circle r={} at {}", self.radius(), Compact(self.center()))
+    }
+}
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rect {}-{}", Compact(self.min()), Compact(self.max()))
+    }
+}
+
+impl fmt::Display for Triangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "triangle")?;
+        write_points(f, &self.vertices())
+    }
+}
+
+impl fmt::Display for Polygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "polygon")?;
+        write_points(f, self.vertices())
+    }
+}
+
+impl fmt::Display for AnyShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyShape::Point(p) => write!(f, "point {}", Compact(*p)),
+            AnyShape::Circle(c) => write!(f, "{c}"),
+            AnyShape::Rectangle(r) => write!(f, "{r}"),
+            AnyShape::Triangle(t) => write!(f, "{t}"),
+            AnyShape::Polygon(p) => write!(f, "{p}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseShapeErrorKind {
+    // Nothing but whitespace.
+    Empty,
+    UnknownShape(String),
+    // Parsing a `Circle`, say, from a line holding another shape.
+    WrongShape { expected: &'static str, found: &'static str },
+    // The line ended where more was expected.
+    MissingArgument,
+    // Something after the end of the shape.
+    UnexpectedInput,
+    // Punctuation or a keyword that wasn't there, like the `-` in `rect`.
+    Expected(&'static str),
+    // Anything that doesn't parse as a finite `f64`.
+    InvalidNumber(String),
+    // Numbers that don't make the shape, like a negative radius.
+    Invalid(GeometryError),
+}
+
+// Where parsing stopped. Lines and columns count from 1, columns in
+// characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseShapeError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseShapeErrorKind,
+}
+
+impl fmt::Display for ParseShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseShapeErrorKind::Empty => write!(f, "expected a shape"),
+            ParseShapeErrorKind::UnknownShape(name) => write!(f, "unknown shape `{name}`"),
+            ParseShapeErrorKind::WrongShape { expected, found } => {
+                write!(f, "expected a {expected}, found a {found}")
+            }
+            ParseShapeErrorKind::MissingArgument => write!(f, "missing argument"),
+            ParseShapeErrorKind::UnexpectedInput => write!(f, "unexpected input after the shape"),
+            ParseShapeErrorKind::Expected(token) => write!(f, "expected `{token}`"),
+            ParseShapeErrorKind::InvalidNumber(s) => write!(f, "`{s}` is not a finite number"),
+            ParseShapeErrorKind::Invalid(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl error::Error for ParseShapeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ParseShapeErrorKind::Invalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Whitespace within a line. A line break is not, so a shape can't carry on
+// onto the next line.
+fn is_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\n' | '\r')
+}
+
+// Reads one line left to right, keeping track of the column for errors.
+struct Cursor<'a> {
+    text: &'a str,
+    // Byte offset into `text`.
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    // The column of whatever comes after any whitespace at the cursor.
+    fn column_after_space(&mut self) -> usize {
+        self.skip_space();
+        self.column()
+    }
+
+    fn error(&self, column: usize, kind: ParseShapeErrorKind) -> ParseShapeError {
+        ParseShapeError { line: self.line, column, kind }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_space(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches(is_space).len();
+    }
+
+    // The shape's name: everything up to whitespace or a point.
+    fn word(&mut self) -> &'a str {
+        let rest = self.rest();
+        let end = rest.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), ParseShapeError> {
+        let column = self.column_after_space();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else if self.rest().is_empty() {
+            Err(self.error(column, ParseShapeErrorKind::MissingArgument))
+        } else {
+            Err(self.error(column, ParseShapeErrorKind::Expected(token)))
+        }
+    }
+
+    // A number runs up to whitespace or punctuation.
+    fn number(&mut self) -> Result<f64, ParseShapeError> {
+        let column = self.column_after_space();
+        let rest = self.rest();
+        if rest.is_empty() {
+            return Err(self.error(column, ParseShapeErrorKind::MissingArgument));
+        }
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ','))
+            .unwrap_or(rest.len())
+            // At least one character, so the error shows what was there.
+            .max(rest.chars().next().map_or(0, char::len_utf8));
+        let word = &rest[..end];
+        self.pos += end;
+        word.parse()
+            .ok()
+            .filter(|n: &f64| n.is_finite())
+            .ok_or_else(|| self.error(column, ParseShapeErrorKind::InvalidNumber(word.to_string())))
+    }
+
+    // `(x,y)`, with any whitespace inside.
+    fn point(&mut self) -> Result<Point, ParseShapeError> {
+        self.expect("(")?;
+        let x = self.number()?;
+        self.expect(",")?;
+        let y = self.number()?;
+        self.expect(")")?;
+        Ok(Point::new(x, y))
+    }
+}
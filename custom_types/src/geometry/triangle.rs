@@ -0,0 +1,84 @@
+use super::{GeometryError, HasArea, Point, Rectangle, Shape};
+
+// A triangle by its vertices, which `generics::bounds`' `Trangle` only has
+// the side lengths of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    vertices: [Point; 3],
+}
+
+impl Triangle {
+    pub fn new(a: Point, b: Point, c: Point) -> Result<Triangle, GeometryError> {
+        if !(a.is_finite() && b.is_finite() && c.is_finite()) {
+            return Err(GeometryError::NotFinite);
+        }
+        if (b - a).cross(c - a) == 0.0 {
+            return Err(GeometryError::Collinear);
+        }
+        Ok(Triangle { vertices: [a, b, c] })
+    }
+
+    // The triangle with sides `a`, `b` and `c`, laid out anticlockwise from
+    // the origin with side `c` along the x axis. Sides that can't close,
+    // where one is at least as long as the other two together, are an error
+    // rather than Heron's `NaN`.
+    pub fn from_sides(a: f64, b: f64, c: f64) -> Result<Triangle, GeometryError> {
+        if !(a.is_finite() && b.is_finite() && c.is_finite()) {
+            return Err(GeometryError::NotFinite);
+        }
+        if a <= 0.0 || b <= 0.0 || c <= 0.0 || a >= b + c || b >= a + c || c >= a + b {
+            return Err(GeometryError::ImpossibleSides { a, b, c });
+        }
+        // The law of cosines puts the third vertex `x` along side `c`.
+        let x = (b * b + c * c - a * a) / (2.0 * c);
+        let y = (b * b - x * x).sqrt();
+        Triangle::new(Point::origin(), Point::new(c, 0.0), Point::new(x, y))
+            .map_err(|_| GeometryError::ImpossibleSides { a, b, c })
+    }
+
+    pub fn vertices(&self) -> [Point; 3] {
+        self.vertices
+    }
+
+    // The lengths opposite each vertex, in vertex order.
+    pub fn sides(&self) -> [f64; 3] {
+        let [a, b, c] = self.vertices;
+        [b.distance(c), c.distance(a), a.distance(b)]
+    }
+
+    // Twice the area, positive if the vertices go anticlockwise.
+    fn signed_double_area(&self) -> f64 {
+        let [a, b, c] = self.vertices;
+        (b - a).cross(c - a)
+    }
+}
+
+impl HasArea for Triangle {
+    fn area(&self) -> f64 {
+        self.signed_double_area().abs() / 2.0
+    }
+}
+
+impl Shape for Triangle {
+    fn perimeter(&self) -> f64 {
+        self.sides().iter().sum()
+    }
+
+    fn centroid(&self) -> Point {
+        let [a, b, c] = self.vertices;
+        Point::new((a.x + b.x + c.x) / 3.0, (a.y + b.y + c.y) / 3.0)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::around(self.vertices).expect("a triangle has vertices")
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        // Inside means on the same side of every edge as the triangle is.
+        let orientation = self.signed_double_area().signum();
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)]
+            .iter()
+            .all(|&(from, to)| (to - from).cross(point - from) * orientation >= 0.0)
+    }
+}
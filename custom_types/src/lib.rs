@@ -0,0 +1,660 @@
+#![allow(
+    dead_code,
+    clippy::approx_constant,
+    clippy::cast_nan_to_int,
+    clippy::empty_line_after_outer_attr,
+    clippy::let_unit_value,
+    clippy::to_string_in_format_args,
+    clippy::unnecessary_cast,
+    clippy::vec_init_then_push,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
+pub mod bignum;
+pub mod calc;
+pub mod cast;
+pub mod geometry;
+pub mod list;
+pub mod refined;
+pub mod units;
+pub mod web;
+
+use std::fmt;
+
+pub fn structures() {
+
+    #[derive(Debug)]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+    
+    struct Unit;
+    
+    struct Pair(i32, f32);
+    
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    let point = Point { x: 1.1, y: 2.3 };
+    
+    struct Rectangle {
+        top_left: Point,
+        right_bottom: Point,
+    }
+
+    let name: String = String::from("John");
+    let age: u8 = 30;
+    
+    let Point { x: left_edge, y: top_edge } = point;
+    println!("{} {} === {} {}", left_edge, top_edge, name, age);
+}
+
+pub fn structures_geometry() {
+    use geometry::{
+        convex_hull, Affine2, Circle, HasArea, Point, Polygon, Rectangle, Shape, Style, Svg,
+        Triangle,
+    };
+
+    // `structures` builds a `Rectangle` from `top_left` and `right_bottom`;
+    // `geometry` takes any two opposite corners.
+    let rectangle = Rectangle::from_corners(Point::new(0.0, 4.0), Point::new(3.0, 0.0));
+    let circle = Circle::new(Point::new(3.0, 4.0), 2.0).unwrap();
+    let triangle = Triangle::from_sides(4.0, 5.0, 6.0).unwrap();
+
+    let shapes: [(&str, &dyn Shape); 3] =
+        [("rectangle", &rectangle), ("circle", &circle), ("triangle", &triangle)];
+    for (name, shape) in shapes {
+        let bounds = shape.bounding_box();
+        println!("{:>9}: area {:.3}, perimeter {:.3}, centroid {:.3}, bounds {:.3}-{:.3}",
+                 name, shape.area(), shape.perimeter(), shape.centroid(),
+                 bounds.min(), bounds.max());
+        println!("{:>9}  contains (3, 4)? {}", "", shape.contains(Point::new(3.0, 4.0)));
+    }
+
+    let other = Rectangle::from_size(Point::new(2.0, 1.0), 4.0, 4.0);
+    match rectangle.intersection(&other) {
+        Some(shared) => println!("The rectangles share {}-{}", shared.min(), shared.max()),
+        None => println!("The rectangles don't meet"),
+    }
+    let union = rectangle.union(&other);
+    println!("Both fit in {}-{}", union.min(), union.max());
+
+    // Heron's formula gives `NaN` for sides that can't close; `from_sides`
+    // says so instead.
+    match Triangle::from_sides(1.0, 2.0, 10.0) {
+        Ok(triangle) => println!("Area {}", triangle.area()),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Any other room shape is a `Polygon`, measured with the shoelace
+    // formula.
+    let corners = [(0.0, 0.0), (6.0, 0.0), (6.0, 2.5), (2.5, 2.5), (2.5, 5.0), (0.0, 5.0)];
+    let room = Polygon::new(corners.iter().map(|&(x, y)| Point::new(x, y)).collect()).unwrap();
+    println!("The L-shaped room has {} m² of floor and {} m of wall, convex? {}",
+             room.area(), room.perimeter(), room.is_convex());
+    for point in [Point::new(1.0, 4.0), Point::new(4.0, 4.0)] {
+        println!("{} is {} the room", point,
+                 if room.contains(point) { "in" } else { "outside" });
+    }
+    let hull = convex_hull(room.vertices()).unwrap();
+    println!("Its convex hull has {} corners and {} m²", hull.vertices().len(), hull.area());
+
+    // Beyond `translate`: turn the rectangle a quarter turn about its
+    // centre and stretch it, then undo that.
+    let turn = Affine2::rotate_about(rectangle.centroid(), std::f64::consts::FRAC_PI_2)
+        .then(Affine2::scale(2.0, 1.0));
+    let quad = turn.apply_rectangle(&rectangle).unwrap();
+    let corners: Vec<String> = quad.vertices().iter().map(|p| format!("{:.1}", p)).collect();
+    println!("Turned and stretched: {} with area {:.1}", corners.join(" "), quad.area());
+    let back = turn.inverse().unwrap().apply_polygon(&quad).unwrap();
+    let error = back.vertices().iter().zip(rectangle.corners())
+        .map(|(&p, q)| p.distance(q))
+        .fold(0.0, f64::max);
+    println!("Undone to within 1e-12? {}", error < 1e-12);
+
+    // Drawn, to check by eye rather than by hand.
+    let mut svg = Svg::new();
+    svg.size(200.0)
+        .add(hull, Style::new().stroke("gray").dashed(4.0))
+        .add(room, Style::new().fill("#eeeeee").label("room"));
+    print!("{}", svg);
+}
+
+pub fn enums() {
+    // `WebEvent` moved to `web/event.rs`, where it also learned to read and
+    // write itself as one line of text.
+    use web::WebEvent;
+
+    fn inspect(event: &WebEvent) {
+        match event {
+            WebEvent::PageLoad => println!("Page loaded"),
+            WebEvent::PageUnload => println!("Page unloaded"),
+            WebEvent::KeyPress(key) => println!("Key pressed: {}", key),
+            WebEvent::Paste(text) => println!("Pasted text: {}", text),
+            WebEvent::Click{x, y} => println!("Clicked at ({}, {})", x, y),
+        }
+    }
+    let pressed = WebEvent::KeyPress('x');
+    // `to_owned()` creates an owned `String` from a string slice.
+    let pasted  = WebEvent::Paste("my text".to_owned());
+    let click   = WebEvent::Click { x: 20, y: 80 };
+    let load    = WebEvent::PageLoad;
+    let unload  = WebEvent::PageUnload;
+
+    inspect(&pressed);
+    inspect(&pasted);
+    inspect(&click);
+    inspect(&load);
+    inspect(&unload);
+
+    // The same events as they are written to a session file.
+    let session = web::format_session([&pressed, &pasted, &click, &load, &unload]);
+    print!("{}", session);
+    let replayed = web::parse_session(&session).unwrap();
+    println!("Read back {} events, last one: {:?}", replayed.len(), replayed.last());
+}
+
+// `inspect` from `enums` as handlers on an event bus instead of one `match`.
+pub fn enums_event_bus() {
+    use std::cell::Cell;
+    use web::{clicks_within, Dispatcher, EventKind, WebEvent};
+
+    let clicks = Cell::new(0);
+    let mut bus = Dispatcher::new();
+    bus.on(EventKind::KeyPress, 0, |event, _| println!("Key handler got {}", event));
+    bus.on(EventKind::Click, 0, |_, _| clicks.set(clicks.get() + 1));
+    // Runs before the plain click handler and keeps clicks on the close
+    // button away from it.
+    bus.on_match(clicks_within(0..=10, 0..=10), 10, |_, context| {
+        println!("Close button clicked, unloading");
+        context.emit(WebEvent::PageUnload);
+        context.stop_propagation();
+    });
+    bus.on_any(-1, |event, _| println!("Logged {:?}", event));
+
+    bus.emit(WebEvent::PageLoad);
+    bus.emit(WebEvent::KeyPress('q'));
+    bus.emit(WebEvent::Click { x: 20, y: 80 });
+    bus.emit(WebEvent::Click { x: 5, y: 5 });
+    let deliveries = bus.run();
+    println!("{} events dispatched, {} clicks counted", deliveries.len(), clicks.get());
+    print!("{}", web::format_session(bus.log()));
+}
+
+pub fn enums_click_analytics() {
+    use web::{
+        double_clicks, parse_timed_session, sessions, DoubleClickConfig, Heatmap, HeatmapConfig,
+        SessionStats,
+    };
+
+    let recording = parse_timed_session(
+        "0 load\n\
+         150 click 10 10\n\
+         380 click 12 11\n\
+         900 click 95 45\n\
+         1200 key h\n\
+         1300 key i\n\
+         1450 paste \"hello there\"\n\
+         2000 click 11 9\n\
+         2600 unload\n\
+         3000 load\n\
+         3100 click 50 25\n\
+         3200 click 51 26\n",
+    )
+    .unwrap();
+
+    let config = HeatmapConfig { width: 100, height: 50, columns: 10, rows: 5, ..HeatmapConfig::default() };
+    let mut heatmap = Heatmap::new(config);
+    heatmap.add_events(recording.iter().map(|timed| &timed.event));
+    println!("{} clicks, hottest cell {:?}", heatmap.total(), heatmap.hottest());
+    println!("+{}+", "-".repeat(config.columns));
+    for line in heatmap.render_ascii().lines() {
+        println!("|{}|", line);
+    }
+    println!("+{}+", "-".repeat(config.columns));
+
+    for (i, session) in sessions(&recording).into_iter().enumerate() {
+        println!("Session {}:", i + 1);
+        for click in double_clicks(session, DoubleClickConfig::default()) {
+            println!("  double click at ({}, {}), {} ms in", click.x, click.y, click.at_ms);
+        }
+        println!("{}", SessionStats::of(session, DoubleClickConfig::default()));
+    }
+}
+
+pub fn enums_click_hit_testing() {
+    use geometry::{Point, Quadtree, Rectangle};
+    use web::{parse_session, WebEvent};
+
+    // A toolbar of 200 buttons, 40x20 each, in rows of 20, indexed so a
+    // click only looks at the buttons near it.
+    let mut buttons = Quadtree::new(Rectangle::from_size(Point::origin(), 1000.0, 300.0));
+    for i in 0..200 {
+        let corner = Point::new((i % 20) as f64 * 50.0, (i / 20) as f64 * 30.0);
+        buttons.insert(Rectangle::from_size(corner, 40.0, 20.0), format!("button {}", i));
+    }
+
+    let session = parse_session("click 5 5\nclick 45 5\nkey x\nclick 512 95\nclick 999 299\n").unwrap();
+    for event in &session {
+        if let WebEvent::Click { x, y } = *event {
+            let point = Point::new(x as f64, y as f64);
+            match buttons.query_point(point).first() {
+                Some(&id) => println!("Click at {} hits {}", point, buttons.get(id).unwrap().1),
+                None => {
+                    let (id, distance) = buttons.nearest(point, 1)[0];
+                    println!("Click at {} misses; {} is {:.1} away",
+                             point, buttons.get(id).unwrap().1, distance);
+                }
+            }
+        }
+    }
+}
+
+pub fn enums_impls() {
+    // `Add` and `Subtract` grew into the checked operators in `calc.rs`,
+    // which also has RPN and infix evaluators.
+    use calc::{eval_infix, eval_rpn, negate, to_rpn, TodoWithNumbers};
+
+    let x: i32 = 10;
+    let y: i32 = 5;
+    let add = TodoWithNumbers::Add;
+    let subtract = TodoWithNumbers::Subtract;
+    println!("{}, {}", add.run(x, y).unwrap(), subtract.run(x, y).unwrap());
+
+    for op in TodoWithNumbers::ALL {
+        println!("{} {:>2} {} = {:?}", x, op, y, op.run(x, y));
+    }
+    println!("neg {} = {:?}", x, negate(x));
+    println!("{:?}", TodoWithNumbers::Divide.run(x, 0));
+    println!("{:?}", TodoWithNumbers::Multiply.run(i32::MAX, 2));
+
+    println!("{:?}", eval_rpn("3 4 + 2 *"));
+    let infix = "-2 ** 3 + (7 - 1) % 4 << 2";
+    println!("{} = {} = {:?}", infix, to_rpn(infix).unwrap(), eval_infix(infix));
+    match eval_infix("1 + 2 / (3 - 3)") {
+        Ok(n) => println!("{}", n),
+        Err(err) => println!("Error: {}", err),
+    }
+}
+
+pub fn enums_cast() {
+    // Fieldless enums cast to their discriminant, counting from 0.
+    use calc::TodoWithNumbers;
+
+    let add = TodoWithNumbers::Add;
+    let subtract = TodoWithNumbers::Subtract;
+    println!("{}, {:06X}", add as i32, subtract as i32);
+}
+
+pub fn enums_test_linked_list() {
+    // The `Cons(u32, Box<List>)` enum grew into the generic `List<T>` in
+    // `list.rs`, which appends and drops in loops instead of recursing.
+    use list::{List, SharedList};
+
+    let mut list = List::new();
+
+    // Prepend some elements
+    list = list.prepend(1);
+    list = list.prepend(2);
+    list = list.prepend(3);
+    list = list.append(5);
+
+    // Show the final state of the list
+    println!("linked list has length: {}", list.len());
+    println!("{}", list);
+
+    // Long enough that a recursive `Drop` would overflow the stack.
+    let long: List<u32> = (0..1_000_000).collect();
+    println!("long list has length: {}", long.len());
+
+    // Both lists below share the nodes of `tail` instead of copying them.
+    let tail: SharedList<&str> = ["c", "d"].into_iter().collect();
+    let a = tail.prepend("a");
+    let b = tail.prepend("b");
+    println!("{} and {} share {:?}", a, b, b.tail());
+}
+
+pub fn constants() {
+    const PI:f64 = 3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679;
+    println!("PI is {}", PI);
+
+    // The `f64` keeps about 16 of those digits. A `BigDecimal` parsed from
+    // the same text keeps all of them, and shows exactly what the `f64` is.
+    use bignum::{BigDecimal, Rounding};
+    const PI_DIGITS: &str = "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+    let pi: BigDecimal = PI_DIGITS.parse().unwrap();
+    let pi_f64 = BigDecimal::from_f64(PI).unwrap();
+    println!("PI is {}", pi);
+    println!("PI as an f64 is exactly {}", pi_f64);
+    println!("which is off by {:.60}", &pi - &pi_f64);
+    let r: BigDecimal = "2.5".parse().unwrap();
+    println!("A circle of radius {} has area {:.50}", r, &pi * &r * &r);
+    println!("22/7 is {}", BigDecimal::from(22).div(&BigDecimal::from(7), 40, Rounding::HalfEven).unwrap());
+
+    fn is_big(n: i32) -> bool {
+        // Access constant in some function
+        n > THRESHOLD
+    }
+
+    let n = 16;
+    println!("{} is {}", n, if is_big(n) { "big" } else { "small" });
+
+    static LANGUAGE: &str = "Rust";
+    const THRESHOLD: i32 = 10;
+
+    println!("This is {}", LANGUAGE);
+    println!("The threshold is {}", THRESHOLD);
+    println!("{} is {}", n, if is_big(n) { "big" } else { "small" });
+}
+
+pub fn variable_freezing() {
+    let mut _mutable_integer = 7i32;
+    {
+        // Shadowing by immutable `_mutable_integer`
+        let _mutable_integer: i32 = _mutable_integer;
+
+        // Error! `_mutable_integer` is frozen in this scope
+        // _mutable_integer = 50;
+        println!("{}", _mutable_integer);
+        // FIXME ^ Comment out this line
+
+        // `_mutable_integer` goes out of scope
+    }
+    // Ok! `_mutable_integer` is not frozen in this scope
+    _mutable_integer = 3;
+    println!("{}", _mutable_integer);
+}
+
+// Suppress all warnings from casts which overflow.
+#[allow(overflowing_literals)]
+
+pub fn casting() {
+    let decimal = 65.4321_f32;
+
+    // Error! No implicit conversion
+    // let integer: u8 = decimal;
+    // FIXME ^ Comment out this line
+
+    // Explicit conversion
+    let integer = decimal as u8;
+    let character = integer as char;
+
+    // Error! There are limitations in conversion rules.
+    // A float cannot be directly converted to a char.
+    // let character = decimal as char;
+    // FIXME ^ Comment out this line
+
+    println!("Casting: {} -> {} -> {}", decimal, integer, character);
+
+    // when casting any value to an unsigned type, T,
+    // T::MAX + 1 is added or subtracted until the value
+    // fits into the new type
+
+    // 1000 already fits in a u16
+    println!("1000 as a u16 is: {}", 1000 as u16);
+
+    // 1000 - 256 - 256 - 256 = 232
+    // Under the hood, the first 8 least significant bits (LSB) are kept,
+    // while the rest towards the most significant bit (MSB) get truncated.
+    println!("1000 as a u8 is : {}", 1000 as u8);
+    // -1 + 256 = 255
+    println!("  -1 as a u8 is : {}", (-1i8) as u8);
+
+    // For positive numbers, this is the same as the modulus
+    println!("1000 mod 256 is : {}", 1000 % 256);
+
+    // When casting to a signed type, the (bitwise) result is the same as
+    // first casting to the corresponding unsigned type. If the most significant
+    // bit of that value is 1, then the value is negative.
+
+    // Unless it already fits, of course.
+    println!(" 128 as a i16 is: {}", 128 as i16);
+
+    // In boundary case 128 value in 8-bit two's complement representation is -128
+    println!(" 128 as a i8 is : {}", 128 as i8);
+
+    // repeating the example above
+    // 1000 as u8 -> 232
+    println!("1000 as a u8 is : {}", 1000 as u8);
+    // and the value of 232 in 8-bit two's complement representation is -24
+    println!(" 232 as a i8 is : {}", 232 as i8);
+
+    // Since Rust 1.45, the `as` keyword performs a *saturating cast*
+    // when casting from float to int. If the floating point value exceeds
+    // the upper bound or is less than the lower bound, the returned value
+    // will be equal to the bound crossed.
+
+    // 300.0 as u8 is 255
+    println!(" 300.0 as u8 is : {}", 300.0_f32 as u8);
+    // -100.0 as u8 is 0
+    println!("-100.0 as u8 is : {}", -100.0_f32 as u8);
+    // nan as u8 is 0
+    println!("   nan as u8 is : {}", f32::NAN as u8);
+
+    // This behavior incurs a small runtime cost and can be avoided
+    // with unsafe methods, however the results might overflow and
+    // return **unsound values**. Use these methods wisely:
+    unsafe {
+        // 300.0 as u8 is 44
+        println!(" 300.0 as u8 is : {}", 300.0_f32.to_int_unchecked::<u8>());
+        // -100.0 as u8 is 156
+        println!("-100.0 as u8 is : {}", (-100.0_f32).to_int_unchecked::<u8>());
+        // nan as u8 is 0
+        println!("   nan as u8 is : {}", f32::NAN.to_int_unchecked::<u8>());
+    }
+}
+
+pub fn casting_explained() {
+    // The casts from `casting`, with `cast.rs` working out what happened.
+    use cast::{cast_table, explain_cast};
+
+    println!("{}", explain_cast::<f32, u8>(65.4321));
+    println!("{}", explain_cast::<i32, u16>(1000));
+    println!("{}", explain_cast::<i32, u8>(1000));
+    println!("{}", explain_cast::<i8, u8>(-1));
+    println!("{}", explain_cast::<i8, u32>(-1));
+    println!("{}", explain_cast::<i32, i8>(128));
+    println!("{}", explain_cast::<u8, i8>(232));
+    println!("{}", explain_cast::<f32, u8>(300.0));
+    println!("{}", explain_cast::<f32, u8>(-100.0));
+    println!("{}", explain_cast::<f32, u8>(f32::NAN));
+    println!("{}", explain_cast::<i32, f32>(16_777_217));
+    println!("{}", explain_cast::<f64, f32>(0.1));
+    println!("{}", explain_cast::<f32, f64>(0.1));
+    println!();
+    println!("{}", cast_table());
+}
+
+pub fn literals() {
+    // Suffixed literals, their types are known at initialization
+    let x = 1u8;
+    let y = 2u32;
+    let z = 3f32;
+
+    // Unsuffixed literals, their types depend on how they are used
+    let i = 1;
+    let f = 1.0;
+
+    // `size_of_val` returns the size of a variable in bytes
+    println!("size of `x` in bytes: {}", std::mem::size_of_val(&x));
+    println!("size of `y` in bytes: {}", std::mem::size_of_val(&y));
+    println!("size of `z` in bytes: {}", std::mem::size_of_val(&z));
+    println!("size of `i` in bytes: {}", std::mem::size_of_val(&i));
+    println!("size of `f` in bytes: {}", std::mem::size_of_val(&f));
+}
+
+pub fn inference() {
+    // Because of the annotation, the compiler knows that `elem` has type u8.
+    let elem = 5u8;
+
+    // Create an empty vector (a growable array).
+    let mut vec = Vec::new();
+    // At this point the compiler doesn't know the exact type of `vec`, it
+    // just knows that it's a vector of something (`Vec<_>`).
+
+    // Insert `elem` in the vector.
+    vec.push(elem);
+    // Aha! Now the compiler knows that `vec` is a vector of `u8`s (`Vec<u8>`)
+    // TODO ^ Try commenting out the `vec.push(elem)` line
+
+    println!("{:?}", vec);
+}
+
+pub fn aliasing() {
+    type NanoSecond = u64;
+    type Inch = u64;
+    type U64 = u64;
+    // `NanoSecond` = `Inch` = `U64` = `u64`.
+    let nanoseconds: NanoSecond = 5 as NanoSecond;
+    let inches: Inch = 2 as U64;
+
+    // Note that type aliases *don't* provide any extra type safety, because
+    // aliases are *not* new types
+    println!("{} nanoseconds + {} inches = {} unit?",
+                nanoseconds,
+                inches,
+                nanoseconds + inches);
+
+    // A `Quantity` carries its dimension in its type, so the same mistake
+    // doesn't compile, and switching units is spelled out.
+    use units::{CENTIMETER, INCH, METER, NANOSECOND};
+    let nanoseconds = 5.0 * NANOSECOND;
+    let inches = 2.0 * INCH;
+    // println!("{}", nanoseconds + inches);
+    // TODO ^ Try uncommenting this line (E0308)
+    let twice = nanoseconds + nanoseconds;
+    println!("{} + {} = {}", nanoseconds.display(NANOSECOND), nanoseconds.display(NANOSECOND),
+             twice.display(NANOSECOND));
+    println!("{} = {}", inches.display(INCH), inches.display(CENTIMETER));
+
+    // The foot and the meter `traits::derive` compares, converted here.
+    let foot = 12.0 * INCH;
+    let cmp = if foot < 1.0 * METER { "smaller" } else { "bigger" };
+    println!("{} is {} than {}.", foot.display(INCH), cmp, METER.of(1.0));
+
+    // Parsed quantities pick up their dimension from the unit.
+    for text in ["12in", "30.48cm", "5ns", "2.5 lb", "3 parsecs"] {
+        match text.parse::<units::AnyQuantity>() {
+            Ok(quantity) => println!("{:>9} parses as {}", text, quantity),
+            Err(e) => println!("{:>9} doesn't parse: {}", text, e),
+        }
+    }
+    match "5ns".parse::<units::Length>() {
+        Ok(length) => println!("5ns is {}", length),
+        Err(e) => println!("5ns as a length: {}", e),
+    }
+}
+
+pub fn from_into() {
+    #[derive(Debug)]
+    struct Number {
+        value: i32,
+    }
+
+    impl From<i32> for Number {
+        fn from(item: i32) -> Self {
+            Number { value: item }
+        }
+    }
+
+    // impl Into<Number> for i32 {
+    //     fn into(self) -> Number {
+    //         Number { value: self }
+    //     }
+    // }
+    
+    let num = Number::from(30);
+    println!("My number is: {}", num.value);
+
+    let int = 5;
+    // Try removing the type annotation
+    let num: Number = int.into();
+    println!("My number is: {}", num.value);
+}
+
+pub fn try_from_try_into() {
+    // `refined!` writes `EvenNumber` and its `TryFrom<i32>` from the check
+    // alone, with an error that says what went wrong.
+    crate::refined!(#[derive(Debug, PartialEq)] EvenNumber: i32 where |v| v % 2 == 0);
+
+    assert_eq!(EvenNumber::try_from(8), Ok(EvenNumber(8)));
+    assert_eq!(EvenNumber::try_from(5).map_err(|e| e.value), Err(5));
+
+    // TryInto
+
+    let result: Result<EvenNumber, _> = 8i32.try_into();
+    assert_eq!(result, Ok(EvenNumber(8)));
+    println!("{:?}", result);
+    let result: Result<EvenNumber, _> = 5i32.try_into();
+    assert!(result.is_err());
+    println!("{:?}", result);
+
+    let even = EvenNumber::try_from(8).unwrap();
+    println!("{} is even, and half of it is {}", even, *even / 2);
+    if let Err(e) = EvenNumber::try_from(5) {
+        println!("{}", e);
+    }
+
+    // A range works as the check too.
+    crate::refined!(#[derive(Debug, PartialEq)] Percent: u8 in 0..=100);
+    for value in [42, 100, 101] {
+        match Percent::try_from(value) {
+            Ok(percent) => println!("{}%", percent),
+            Err(e) => println!("{}", e),
+        }
+    }
+}
+
+pub fn to_from_string() {
+    // Converting to String
+    #[derive(Debug)]
+    struct Circle{
+        radius: f64,
+    }
+
+    impl fmt::Display for Circle {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Circle of radius: {}", self.radius)
+        }
+    }
+
+    let circle: Circle = Circle { radius: 100.0 };
+    println!("{:?} - {} - {}", circle, circle, circle.to_string());
+
+    // `geometry`'s shapes print in a form that parses back to the same shape
+    let shape: geometry::Circle = "circle r=100 at (0,0)".parse().unwrap();
+    println!("{} has radius {}", shape, shape.radius());
+    let plan = "rect (0,0)-(3,4)\n\n# sides, placed from the origin\ntriangle 4 5 6";
+    let plan = geometry::parse_shapes(plan).unwrap();
+    print!("{}", geometry::format_shapes(&plan));
+    if let Err(error) = "circle r=-100".parse::<geometry::Circle>() {
+        println!("Error: {}", error);
+    }
+    let parsed: i32 = "5".parse().unwrap();
+    let turbo_parsed = "10".parse::<i32>().unwrap();
+    let sum = parsed + turbo_parsed;
+    println!("Sum: {:?}", sum);
+
+    let x = 5u32;
+
+    let y = {
+        let x_squared = x * x;
+        let x_cube = x_squared * x;
+
+        // This expression will be assigned to `y`
+        x_cube + x_squared + x
+    };
+
+    let z = {
+    };
+
+    println!("x is {:?}", x);
+    println!("y is {:?}", y);
+    println!("z is {:?}", z);
+}
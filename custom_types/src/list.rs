@@ -0,0 +1,431 @@
+// Singly linked lists, grown out of the `Cons(u32, Box<List>)` enum in
+// `enums_test_linked_list`.
+//
+// The enum version recursed in `append`, `len` and in the `Drop` the compiler
+// generates, so a long enough list overflowed the stack. Here every walk is a
+// loop, including `Drop`.
+//
+// `List<T>` owns its nodes. `SharedList<T>` keeps its tails behind `Rc`, so
+// `prepend` leaves the original list intact and clones are O(1).
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    pub fn new() -> List<T> {
+        List { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // Like the original, but O(1) and without recursion.
+    pub fn prepend(mut self, value: T) -> List<T> {
+        self.push_front(value);
+        self
+    }
+
+    // Walks to the end in a loop, so it is O(n) but safe on long lists.
+    pub fn append(mut self, value: T) -> List<T> {
+        self.push_back(value);
+        self
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.extend(Some(value));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head.take()?;
+        self.head = node.next;
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.value)
+    }
+
+    // Reverses the list in place by relinking the nodes.
+    pub fn reverse(&mut self) {
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = self.head.take();
+            self.head = Some(node);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        // `Drop` does the unlinking.
+        *self = List::new();
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), len: self.len }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut(), len: self.len }
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Unlink one node at a time, so that dropping a node never has to
+        // drop the rest of the list recursively.
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> List<T> {
+        List::new()
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> List<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        let mut added = 0;
+        for value in iter {
+            let node = link.insert(Box::new(Node { value, next: None }));
+            link = &mut node.next;
+            added += 1;
+        }
+        self.len += added;
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> List<T> {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.len -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { next: self.next, len: self.len }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = self.next.take()?;
+        self.next = node.next.as_deref_mut();
+        self.len -= 1;
+        Some(&mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// Writes `[a, b, c]` with each element's `Display`, like `stringify` did.
+fn write_elements<'a, T: fmt::Display + 'a>(
+    f: &mut fmt::Formatter,
+    values: impl Iterator<Item = &'a T>,
+) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt::Display::fmt(value, f)?;
+    }
+    write!(f, "]")
+}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_elements(f, self.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+struct SharedNode<T> {
+    value: T,
+    next: Option<Rc<SharedNode<T>>>,
+}
+
+// A persistent list: lists built from the same tail share its nodes.
+pub struct SharedList<T> {
+    head: Option<Rc<SharedNode<T>>>,
+    len: usize,
+}
+
+impl<T> SharedList<T> {
+    pub fn new() -> SharedList<T> {
+        SharedList { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // A new list with `value` in front of this one, which is left as it is.
+    pub fn prepend(&self, value: T) -> SharedList<T> {
+        let node = SharedNode { value, next: self.head.clone() };
+        SharedList { head: Some(Rc::new(node)), len: self.len + 1 }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    // Everything after the front, shared with this list. Empty for an empty
+    // list.
+    pub fn tail(&self) -> SharedList<T> {
+        match &self.head {
+            Some(node) => SharedList { head: node.next.clone(), len: self.len - 1 },
+            None => SharedList::new(),
+        }
+    }
+
+    pub fn iter(&self) -> SharedIter<'_, T> {
+        SharedIter { next: self.head.as_deref(), len: self.len }
+    }
+
+    // Whether both lists start with the very same node, not just equal values.
+    pub fn ptr_eq(&self, other: &SharedList<T>) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> Drop for SharedList<T> {
+    fn drop(&mut self) {
+        // Free nodes for as long as this list was their only owner; the rest
+        // belongs to some other list.
+        let mut link = self.head.take();
+        while let Some(node) = link {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => link = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Default for SharedList<T> {
+    fn default() -> SharedList<T> {
+        SharedList::new()
+    }
+}
+
+// Shares every node, so this is O(1) and needs no `T: Clone`.
+impl<T> Clone for SharedList<T> {
+    fn clone(&self) -> SharedList<T> {
+        SharedList { head: self.head.clone(), len: self.len }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SharedList<T> {
+    fn eq(&self, other: &SharedList<T>) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for SharedList<T> {}
+
+impl<T> FromIterator<T> for SharedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SharedList<T> {
+        // Nodes can only be added in front, so build from the back.
+        let values: Vec<T> = iter.into_iter().collect();
+        let mut list = SharedList::new();
+        for value in values.into_iter().rev() {
+            list = list.prepend(value);
+        }
+        list
+    }
+}
+
+pub struct SharedIter<'a, T> {
+    next: Option<&'a SharedNode<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for SharedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.len -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for SharedIter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a SharedList<T> {
+    type Item = &'a T;
+    type IntoIter = SharedIter<'a, T>;
+
+    fn into_iter(self) -> SharedIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SharedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_elements(f, self.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
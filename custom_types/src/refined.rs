@@ -0,0 +1,129 @@
+// `refined!` declares a newtype that only holds values passing a check, like
+// `EvenNumber` in `try_from_try_into`:
+//
+//     refined!(#[derive(Debug, PartialEq)] pub EvenNumber: i32 where |v| v % 2 == 0);
+//     refined!(pub Percent: u8 in 0..=100);
+//
+//     assert_eq!(*EvenNumber::try_from(8).unwrap(), 8);
+//     let error = Percent::try_from(101).unwrap_err();
+//     assert_eq!(error.to_string(), "101 is not a valid Percent (u8 in 0..=100)");
+//
+// The check is either a closure-like predicate, which gets a reference to the
+// value, or a range the value must be in. The inner type can be any type.
+// The generated type has:
+// - `TryFrom<inner>` and so `TryInto`, failing with a `RefinementError` that
+//   holds the rejected value;
+// - `Deref` to the inner value, `into_inner` and `is_valid`;
+// - `Display` as the inner value, which therefore has to implement it.
+// The field is private to the module the macro is used in, so outside it
+// `try_from` is the only way to get one.
+
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefinementError<T> {
+    pub type_name: &'static str,
+    // The declaration's constraint, like `u8 in 0..=100`.
+    pub constraint: &'static str,
+    pub value: T,
+}
+
+impl<T: fmt::Debug> fmt::Display for RefinementError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} is not a valid {} ({})", self.value, self.type_name, self.constraint)
+    }
+}
+
+impl<T: fmt::Debug> error::Error for RefinementError<T> {}
+
+#[macro_export]
+macro_rules! refined {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident : $inner:ty where |$value:ident| $predicate:expr
+    ) => {
+        $crate::refined!(
+            @define [$(#[$meta])*] $vis $name, $inner,
+            ::std::concat!(
+                ::std::stringify!($inner), " where |", ::std::stringify!($value), "| ",
+                ::std::stringify!($predicate)
+            ),
+            |$value: &$inner| $predicate
+        );
+    };
+    // `in` can't follow a `ty` fragment, so the type's tokens are collected
+    // up to it one at a time.
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident : $($rest:tt)+
+    ) => {
+        $crate::refined!(@range [$(#[$meta])*] $vis $name [] $($rest)+);
+    };
+    (@range [$($meta:tt)*] $vis:vis $name:ident [$($inner:tt)+] in $range:expr) => {
+        $crate::refined!(@in [$($meta)*] $vis $name, $($inner)+, $range);
+    };
+    (@range [$($meta:tt)*] $vis:vis $name:ident [$($inner:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::refined!(@range [$($meta)*] $vis $name [$($inner)* $next] $($rest)*);
+    };
+    (@range [$($meta:tt)*] $vis:vis $name:ident [$($inner:tt)*]) => {
+        ::std::compile_error!("expected `TYPE in RANGE` or `TYPE where |value| CHECK`");
+    };
+    (@in [$($meta:tt)*] $vis:vis $name:ident, $inner:ty, $range:expr) => {
+        $crate::refined!(
+            @define [$($meta)*] $vis $name, $inner,
+            ::std::concat!(::std::stringify!($inner), " in ", ::std::stringify!($range)),
+            |value: &$inner| ($range).contains(value)
+        );
+    };
+    (
+        @define [$($meta:tt)*] $vis:vis $name:ident, $inner:ty,
+        $constraint:expr, $check:expr
+    ) => {
+        $($meta)*
+        $vis struct $name($inner);
+
+        impl $name {
+            pub const CONSTRAINT: &'static str = $constraint;
+
+            pub fn is_valid(value: &$inner) -> bool {
+                let check: fn(&$inner) -> bool = $check;
+                check(value)
+            }
+
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl ::std::convert::TryFrom<$inner> for $name {
+            type Error = $crate::refined::RefinementError<$inner>;
+
+            fn try_from(value: $inner) -> ::std::result::Result<$name, Self::Error> {
+                if $name::is_valid(&value) {
+                    ::std::result::Result::Ok($name(value))
+                } else {
+                    ::std::result::Result::Err($crate::refined::RefinementError {
+                        type_name: ::std::stringify!($name),
+                        constraint: $name::CONSTRAINT,
+                        value,
+                    })
+                }
+            }
+        }
+
+        impl ::std::ops::Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
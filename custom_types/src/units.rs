@@ -0,0 +1,405 @@
+// Quantities that know their dimension, so the `NanoSecond + Inch` from
+// `aliasing` no longer compiles.
+//
+// A `Quantity<D>` is a number of base units of dimension `D`: nanometres for
+// `Length`, nanoseconds for `Time` and nanograms for `Mass`. Those are small
+// enough that every unit below is a whole number of them, so converting
+// `12in` to centimetres gives exactly `30.48`. Quantities of the same
+// dimension add, subtract and compare; different dimensions are different
+// types. Numbers only go in and out through a `Unit`:
+//
+//     let foot = 12.0 * INCH;
+//     assert_eq!(foot.get(CENTIMETER), 30.48);
+//     let timeout: Time = "5ns".parse().unwrap();
+
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+pub trait Dimension: Copy + 'static {
+    const NAME: &'static str;
+    // The unit `Display` uses.
+    const DISPLAY_UNIT: Unit<Self>;
+    // Every unit parsing knows for the dimension.
+    const UNITS: &'static [Unit<Self>];
+}
+
+// The dimensions, as marker types.
+pub mod dim {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Length;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Time;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Mass;
+}
+
+pub type Length = Quantity<dim::Length>;
+pub type Time = Quantity<dim::Time>;
+pub type Mass = Quantity<dim::Mass>;
+
+// A named multiple of a dimension's base unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit<D> {
+    pub symbol: &'static str,
+    // Base units in one of these.
+    factor: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Unit<D> {
+    pub const fn new(symbol: &'static str, factor: f64) -> Unit<D> {
+        Unit { symbol, factor, dimension: PhantomData }
+    }
+
+    pub fn of(self, value: f64) -> Quantity<D> {
+        Quantity::new(value, self)
+    }
+
+    // Whether `text` names the unit; `u` works for `µ`.
+    fn matches(&self, text: &str) -> bool {
+        self.symbol == text || self.symbol.replace('µ', "u") == text
+    }
+}
+
+pub const NANOMETER: Unit<dim::Length> = Unit::new("nm", 1.0);
+pub const MICROMETER: Unit<dim::Length> = Unit::new("µm", 1e3);
+pub const MILLIMETER: Unit<dim::Length> = Unit::new("mm", 1e6);
+pub const CENTIMETER: Unit<dim::Length> = Unit::new("cm", 1e7);
+pub const METER: Unit<dim::Length> = Unit::new("m", 1e9);
+pub const KILOMETER: Unit<dim::Length> = Unit::new("km", 1e12);
+pub const INCH: Unit<dim::Length> = Unit::new("in", 25_400_000.0);
+pub const FOOT: Unit<dim::Length> = Unit::new("ft", 304_800_000.0);
+pub const YARD: Unit<dim::Length> = Unit::new("yd", 914_400_000.0);
+pub const MILE: Unit<dim::Length> = Unit::new("mi", 1_609_344_000_000.0);
+
+pub const NANOSECOND: Unit<dim::Time> = Unit::new("ns", 1.0);
+pub const MICROSECOND: Unit<dim::Time> = Unit::new("µs", 1e3);
+pub const MILLISECOND: Unit<dim::Time> = Unit::new("ms", 1e6);
+pub const SECOND: Unit<dim::Time> = Unit::new("s", 1e9);
+pub const MINUTE: Unit<dim::Time> = Unit::new("min", 60e9);
+pub const HOUR: Unit<dim::Time> = Unit::new("h", 3_600e9);
+pub const DAY: Unit<dim::Time> = Unit::new("d", 86_400e9);
+// 365 days, as in `new_type_idom`.
+pub const YEAR: Unit<dim::Time> = Unit::new("y", 365.0 * 86_400e9);
+
+pub const MILLIGRAM: Unit<dim::Mass> = Unit::new("mg", 1e6);
+pub const GRAM: Unit<dim::Mass> = Unit::new("g", 1e9);
+pub const KILOGRAM: Unit<dim::Mass> = Unit::new("kg", 1e12);
+pub const TONNE: Unit<dim::Mass> = Unit::new("t", 1e15);
+pub const OUNCE: Unit<dim::Mass> = Unit::new("oz", 28_349_523_125.0);
+pub const POUND: Unit<dim::Mass> = Unit::new("lb", 453_592_370_000.0);
+
+impl Dimension for dim::Length {
+    const NAME: &'static str = "length";
+    const DISPLAY_UNIT: Unit<Self> = METER;
+    const UNITS: &'static [Unit<Self>] =
+        &[NANOMETER, MICROMETER, MILLIMETER, CENTIMETER, METER, KILOMETER, INCH, FOOT, YARD, MILE];
+}
+
+impl Dimension for dim::Time {
+    const NAME: &'static str = "time";
+    const DISPLAY_UNIT: Unit<Self> = SECOND;
+    const UNITS: &'static [Unit<Self>] =
+        &[NANOSECOND, MICROSECOND, MILLISECOND, SECOND, MINUTE, HOUR, DAY, YEAR];
+}
+
+impl Dimension for dim::Mass {
+    const NAME: &'static str = "mass";
+    const DISPLAY_UNIT: Unit<Self> = KILOGRAM;
+    const UNITS: &'static [Unit<Self>] = &[MILLIGRAM, GRAM, KILOGRAM, TONNE, OUNCE, POUND];
+}
+
+pub struct Quantity<D> {
+    // In base units.
+    base: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Quantity<D> {
+    pub fn new(value: f64, unit: Unit<D>) -> Quantity<D> {
+        Quantity::from_base(value * unit.factor)
+    }
+
+    fn from_base(base: f64) -> Quantity<D> {
+        Quantity { base, dimension: PhantomData }
+    }
+
+    pub fn zero() -> Quantity<D> {
+        Quantity::from_base(0.0)
+    }
+
+    // The quantity as a number of `unit`s.
+    pub fn get(self, unit: Unit<D>) -> f64 {
+        self.base / unit.factor
+    }
+
+    // Displays as a number of `unit`s: `length.display(INCH)`.
+    pub fn display(self, unit: Unit<D>) -> InUnit<D> {
+        InUnit { quantity: self, unit }
+    }
+
+    pub fn abs(self) -> Quantity<D> {
+        Quantity::from_base(self.base.abs())
+    }
+
+    pub fn min(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::from_base(self.base.min(other.base))
+    }
+
+    pub fn max(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::from_base(self.base.max(other.base))
+    }
+}
+
+// Written by hand so that they don't depend on what the marker derives.
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Quantity<D> {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Quantity<D>) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<D> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Quantity<D>) -> Option<Ordering> {
+        self.base.partial_cmp(&other.base)
+    }
+}
+
+impl<D: Dimension> Default for Quantity<D> {
+    fn default() -> Quantity<D> {
+        Quantity::zero()
+    }
+}
+
+impl<D: Dimension> Add for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn add(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::from_base(self.base + rhs.base)
+    }
+}
+
+impl<D: Dimension> Sub for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn sub(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::from_base(self.base - rhs.base)
+    }
+}
+
+impl<D: Dimension> AddAssign for Quantity<D> {
+    fn add_assign(&mut self, rhs: Quantity<D>) {
+        self.base += rhs.base;
+    }
+}
+
+impl<D: Dimension> SubAssign for Quantity<D> {
+    fn sub_assign(&mut self, rhs: Quantity<D>) {
+        self.base -= rhs.base;
+    }
+}
+
+impl<D: Dimension> Neg for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn neg(self) -> Quantity<D> {
+        Quantity::from_base(-self.base)
+    }
+}
+
+// Scaling by a plain number keeps the dimension.
+impl<D: Dimension> Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn mul(self, rhs: f64) -> Quantity<D> {
+        Quantity::from_base(self.base * rhs)
+    }
+}
+
+impl<D: Dimension> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, rhs: Quantity<D>) -> Quantity<D> {
+        rhs * self
+    }
+}
+
+impl<D: Dimension> Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn div(self, rhs: f64) -> Quantity<D> {
+        Quantity::from_base(self.base / rhs)
+    }
+}
+
+// The ratio of two quantities of the same dimension is a plain number.
+impl<D: Dimension> Div for Quantity<D> {
+    type Output = f64;
+
+    fn div(self, rhs: Quantity<D>) -> f64 {
+        self.base / rhs.base
+    }
+}
+
+// `12.0 * INCH`
+impl<D: Dimension> Mul<Unit<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, unit: Unit<D>) -> Quantity<D> {
+        Quantity::new(self, unit)
+    }
+}
+
+impl<D: Dimension> Sum for Quantity<D> {
+    fn sum<I: Iterator<Item = Quantity<D>>>(iter: I) -> Quantity<D> {
+        iter.fold(Quantity::zero(), Add::add)
+    }
+}
+
+// A quantity shown in a chosen unit, from `Quantity::display`.
+pub struct InUnit<D> {
+    quantity: Quantity<D>,
+    unit: Unit<D>,
+}
+
+impl<D: Dimension> fmt::Display for InUnit<D> {
+    // `30.48 cm`; a precision applies to the number.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.quantity.get(self.unit);
+        match f.precision() {
+            Some(places) => write!(f, "{:.*} {}", places, value, self.unit.symbol),
+            None => write!(f, "{} {}", value, self.unit.symbol),
+        }
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display(D::DISPLAY_UNIT), f)
+    }
+}
+
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", D::NAME, self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseQuantityError {
+    Empty,
+    // A number without a unit after it.
+    MissingUnit,
+    InvalidNumber(String),
+    UnknownUnit(String),
+    // A unit of another dimension, like `5ns` for a length.
+    WrongDimension { unit: String, expected: &'static str, found: &'static str },
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseQuantityError::Empty => write!(f, "expected a quantity"),
+            ParseQuantityError::MissingUnit => write!(f, "missing unit"),
+            ParseQuantityError::InvalidNumber(s) => write!(f, "`{s}` is not a number"),
+            ParseQuantityError::UnknownUnit(s) => write!(f, "unknown unit `{s}`"),
+            ParseQuantityError::WrongDimension { unit, expected, found } => {
+                write!(f, "`{unit}` is a unit of {found}, expected {expected}")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseQuantityError {}
+
+// Splits `12in` or `2.5 min` into the number and the unit symbol.
+fn split_quantity(s: &str) -> Result<(f64, &str), ParseQuantityError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseQuantityError::Empty);
+    }
+    let number_end = s.trim_end_matches(|c: char| c.is_alphabetic()).len();
+    let (number, unit) = s.split_at(number_end);
+    if unit.is_empty() {
+        return Err(ParseQuantityError::MissingUnit);
+    }
+    let number = number.trim_end();
+    let value = number
+        .parse()
+        .map_err(|_| ParseQuantityError::InvalidNumber(number.to_string()))?;
+    Ok((value, unit))
+}
+
+// The dimension a unit symbol belongs to, for errors.
+fn dimension_of(symbol: &str) -> Option<&'static str> {
+    fn has<D: Dimension>(symbol: &str) -> Option<&'static str> {
+        D::UNITS.iter().any(|u| u.matches(symbol)).then_some(D::NAME)
+    }
+    has::<dim::Length>(symbol).or_else(|| has::<dim::Time>(symbol)).or_else(|| has::<dim::Mass>(symbol))
+}
+
+impl<D: Dimension> FromStr for Quantity<D> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Quantity<D>, ParseQuantityError> {
+        let (value, symbol) = split_quantity(s)?;
+        match D::UNITS.iter().find(|u| u.matches(symbol)) {
+            Some(&unit) => Ok(Quantity::new(value, unit)),
+            None => Err(match dimension_of(symbol) {
+                Some(found) => ParseQuantityError::WrongDimension {
+                    unit: symbol.to_string(),
+                    expected: D::NAME,
+                    found,
+                },
+                None => ParseQuantityError::UnknownUnit(symbol.to_string()),
+            }),
+        }
+    }
+}
+
+// A quantity of whichever dimension its unit says, for input that can be
+// any of them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyQuantity {
+    Length(Length),
+    Time(Time),
+    Mass(Mass),
+}
+
+impl FromStr for AnyQuantity {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<AnyQuantity, ParseQuantityError> {
+        let (_, symbol) = split_quantity(s)?;
+        match dimension_of(symbol) {
+            Some("length") => s.parse().map(AnyQuantity::Length),
+            Some("time") => s.parse().map(AnyQuantity::Time),
+            Some(_) => s.parse().map(AnyQuantity::Mass),
+            None => Err(ParseQuantityError::UnknownUnit(symbol.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AnyQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyQuantity::Length(q) => fmt::Display::fmt(q, f),
+            AnyQuantity::Time(q) => fmt::Display::fmt(q, f),
+            AnyQuantity::Mass(q) => fmt::Display::fmt(q, f),
+        }
+    }
+}
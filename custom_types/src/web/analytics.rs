@@ -0,0 +1,357 @@
+// Click and session analytics over recorded `WebEvent`s.
+//
+// Timing matters for double clicks, so the analytics read events with a
+// timestamp in front, in milliseconds since the recording started:
+//
+//     0 load
+//     120 click 20 80
+//     310 click 21 80
+//     900 key x
+//
+// The rest of each line is the usual `WebEvent` format.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{ParseError, ParseErrorKind, WebEvent};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timed {
+    pub at_ms: u64,
+    pub event: WebEvent,
+}
+
+impl Timed {
+    pub fn new(at_ms: u64, event: WebEvent) -> Timed {
+        Timed { at_ms, event }
+    }
+
+    // Parses one `<ms> <event>` line, numbering errors with `line`.
+    pub fn parse_line(text: &str, line: usize) -> Result<Timed, ParseError> {
+        let start = text.len() - text.trim_start().len();
+        let rest = &text[start..];
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (stamp, event) = rest.split_at(end);
+        let column = text[..start].chars().count() + 1;
+        if stamp.is_empty() {
+            return Err(ParseError { line, column, kind: ParseErrorKind::Empty });
+        }
+        let at_ms = stamp.parse().map_err(|_| ParseError {
+            line,
+            column,
+            kind: ParseErrorKind::InvalidNumber(stamp.to_string()),
+        })?;
+        // Columns in the event count from the start of the whole line.
+        let offset = column - 1 + stamp.chars().count();
+        let event = WebEvent::parse_line(event, line)
+            .map_err(|err| ParseError { column: err.column + offset, ..err })?;
+        Ok(Timed { at_ms, event })
+    }
+}
+
+impl FromStr for Timed {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Timed, ParseError> {
+        Timed::parse_line(s, 1)
+    }
+}
+
+impl fmt::Display for Timed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.at_ms, self.event)
+    }
+}
+
+// Like `parse_session`, for timestamped lines.
+pub fn parse_timed_session(text: &str) -> Result<Vec<Timed>, ParseError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim_start();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(i, line)| Timed::parse_line(line, i + 1))
+        .collect()
+}
+
+// Splits a recording into sessions, each starting at a `load`. Events before
+// the first `load` form a session of their own.
+pub fn sessions(events: &[Timed]) -> Vec<&[Timed]> {
+    let mut sessions = Vec::new();
+    let mut start = 0;
+    for (i, timed) in events.iter().enumerate() {
+        if timed.event == WebEvent::PageLoad && i > start {
+            sessions.push(&events[start..i]);
+            start = i;
+        }
+    }
+    if start < events.len() {
+        sessions.push(&events[start..]);
+    }
+    sessions
+}
+
+// The page area a heatmap covers and how finely it is divided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatmapConfig {
+    pub left: i64,
+    pub top: i64,
+    pub width: i64,
+    pub height: i64,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl Default for HeatmapConfig {
+    // A 1920x1080 page in 16x9 cells of 120 pixels.
+    fn default() -> HeatmapConfig {
+        HeatmapConfig { left: 0, top: 0, width: 1920, height: 1080, columns: 16, rows: 9 }
+    }
+}
+
+// Click counts per cell of a grid laid over the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    config: HeatmapConfig,
+    counts: Vec<u64>,
+    // Clicks that fell outside the covered area.
+    outside: u64,
+}
+
+// From cold to hot.
+const ASCII_RAMP: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+const ANSI_RAMP: &[u8] = &[17, 19, 21, 27, 33, 39, 45, 51, 49, 47, 46, 82, 118, 154, 190, 226, 220, 214, 208, 202, 196];
+
+impl Heatmap {
+    // Panics unless the area and the number of cells are positive.
+    pub fn new(config: HeatmapConfig) -> Heatmap {
+        assert!(config.width > 0 && config.height > 0, "heatmap area must not be empty");
+        assert!(config.columns > 0 && config.rows > 0, "heatmap needs at least one cell");
+        Heatmap { config, counts: vec![0; config.columns * config.rows], outside: 0 }
+    }
+
+    pub fn config(&self) -> HeatmapConfig {
+        self.config
+    }
+
+    // The `(column, row)` of the cell containing a point, if any.
+    pub fn cell_of(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let c = &self.config;
+        let bin = |v: i64, start: i64, size: i64, cells: usize| {
+            let offset = v as i128 - start as i128;
+            (0..size as i128)
+                .contains(&offset)
+                .then(|| (offset * cells as i128 / size as i128) as usize)
+        };
+        Some((bin(x, c.left, c.width, c.columns)?, bin(y, c.top, c.height, c.rows)?))
+    }
+
+    // Counts a click, returning `false` if it fell outside the area.
+    pub fn add(&mut self, x: i64, y: i64) -> bool {
+        match self.cell_of(x, y) {
+            Some((column, row)) => {
+                self.counts[row * self.config.columns + column] += 1;
+                true
+            }
+            None => {
+                self.outside += 1;
+                false
+            }
+        }
+    }
+
+    // Counts every click among `events`.
+    pub fn add_events<'a>(&mut self, events: impl IntoIterator<Item = &'a WebEvent>) {
+        for event in events {
+            if let WebEvent::Click { x, y } = *event {
+                self.add(x, y);
+            }
+        }
+    }
+
+    pub fn count(&self, column: usize, row: usize) -> Option<u64> {
+        (column < self.config.columns && row < self.config.rows)
+            .then(|| self.counts[row * self.config.columns + column])
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    pub fn outside(&self) -> u64 {
+        self.outside
+    }
+
+    pub fn max(&self) -> u64 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    // The `(column, row, count)` of the busiest cell, the first one row by
+    // row on a tie. `None` without clicks.
+    pub fn hottest(&self) -> Option<(usize, usize, u64)> {
+        let max = self.max();
+        let i = self.counts.iter().position(|&n| n == max && n > 0)?;
+        Some((i % self.config.columns, i / self.config.columns, max))
+    }
+
+    // Where `count` falls on a ramp of `steps` steps: step 0 for no clicks,
+    // the last step for the busiest cell and the ones in between in
+    // proportion.
+    fn level(&self, count: u64, steps: usize) -> usize {
+        let max = self.max();
+        if count == 0 || max == 0 {
+            return 0;
+        }
+        let top = steps as u64 - 1;
+        (count * top).div_ceil(max) as usize
+    }
+
+    // One character per cell, from ` ` for no clicks to `@` for the most.
+    pub fn render_ascii(&self) -> String {
+        self.render(|count| ASCII_RAMP[self.level(count, ASCII_RAMP.len())].to_string())
+    }
+
+    // Two coloured spaces per cell on a 256-colour terminal, blue for few
+    // clicks to red for many. Empty cells keep the terminal's background.
+    pub fn render_ansi(&self) -> String {
+        self.render(|count| match count {
+            0 => String::from("  "),
+            _ => {
+                let color = ANSI_RAMP[self.level(count, ANSI_RAMP.len() + 1) - 1];
+                format!("\x1b[48;5;{color}m  \x1b[0m")
+            }
+        })
+    }
+
+    fn render(&self, mut cell: impl FnMut(u64) -> String) -> String {
+        let mut text = String::new();
+        for (row, counts) in self.counts.chunks(self.config.columns).enumerate() {
+            if row > 0 {
+                text.push('\n');
+            }
+            for &count in counts {
+                text.push_str(&cell(count));
+            }
+        }
+        text
+    }
+}
+
+// How close two clicks must be to count as a double click.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleClickConfig {
+    pub max_gap_ms: u64,
+    // In pixels along either axis.
+    pub max_distance: u64,
+}
+
+impl Default for DoubleClickConfig {
+    fn default() -> DoubleClickConfig {
+        DoubleClickConfig { max_gap_ms: 500, max_distance: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleClick {
+    // When the second click happened, and where the first one was.
+    pub at_ms: u64,
+    pub x: i64,
+    pub y: i64,
+}
+
+// Pairs of consecutive clicks close enough in time and space. A click can
+// only be part of one pair, so a triple click is one double click followed
+// by a single one, and any other event in between breaks a pair.
+pub fn double_clicks(events: &[Timed], config: DoubleClickConfig) -> Vec<DoubleClick> {
+    let mut found = Vec::new();
+    let mut pending: Option<(u64, i64, i64)> = None;
+    for timed in events {
+        let WebEvent::Click { x, y } = timed.event else {
+            pending = None;
+            continue;
+        };
+        pending = match pending {
+            Some((at, px, py))
+                if timed.at_ms.saturating_sub(at) <= config.max_gap_ms
+                    && x.abs_diff(px) <= config.max_distance
+                    && y.abs_diff(py) <= config.max_distance =>
+            {
+                found.push(DoubleClick { at_ms: timed.at_ms, x: px, y: py });
+                None
+            }
+            _ => Some((timed.at_ms, x, y)),
+        };
+    }
+    found
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionStats {
+    pub events: usize,
+    // From the first event to the last.
+    pub duration_ms: u64,
+    pub clicks: usize,
+    pub double_clicks: usize,
+    pub keys_pressed: usize,
+    // How often each key was pressed.
+    pub keys: BTreeMap<char, usize>,
+    pub pastes: usize,
+    // Characters pasted, over all pastes.
+    pub pasted_chars: usize,
+    pub longest_paste: usize,
+}
+
+impl SessionStats {
+    pub fn of(events: &[Timed], double_click: DoubleClickConfig) -> SessionStats {
+        let mut stats = SessionStats {
+            events: events.len(),
+            double_clicks: double_clicks(events, double_click).len(),
+            ..SessionStats::default()
+        };
+        if let (Some(first), Some(last)) = (events.first(), events.last()) {
+            stats.duration_ms = last.at_ms.saturating_sub(first.at_ms);
+        }
+        for timed in events {
+            match &timed.event {
+                WebEvent::Click { .. } => stats.clicks += 1,
+                WebEvent::KeyPress(key) => {
+                    stats.keys_pressed += 1;
+                    *stats.keys.entry(*key).or_default() += 1;
+                }
+                WebEvent::Paste(text) => {
+                    let len = text.chars().count();
+                    stats.pastes += 1;
+                    stats.pasted_chars += len;
+                    stats.longest_paste = stats.longest_paste.max(len);
+                }
+                WebEvent::PageLoad | WebEvent::PageUnload => {}
+            }
+        }
+        stats
+    }
+
+    // The most pressed key, the smallest one on a tie.
+    pub fn top_key(&self) -> Option<(char, usize)> {
+        let max = self.keys.values().copied().max()?;
+        self.keys.iter().find(|&(_, &n)| n == max).map(|(&key, &n)| (key, n))
+    }
+}
+
+impl fmt::Display for SessionStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "events:        {} over {} ms", self.events, self.duration_ms)?;
+        writeln!(f, "clicks:        {} ({} double)", self.clicks, self.double_clicks)?;
+        write!(f, "keys pressed:  {}", self.keys_pressed)?;
+        if let Some((key, n)) = self.top_key() {
+            write!(f, " (most often {key:?}: {n})")?;
+        }
+        writeln!(f)?;
+        write!(
+            f,
+            "pastes:        {} ({} chars, longest {})",
+            self.pastes, self.pasted_chars, self.longest_paste
+        )
+    }
+}
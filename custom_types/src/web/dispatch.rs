@@ -0,0 +1,247 @@
+// An event bus for `WebEvent`s, replacing the single `match` in `inspect`.
+//
+// Handlers subscribe to one variant, to any event matching a predicate, or to
+// everything. Each event goes to the matching handlers from the highest
+// priority down, in registration order for equal priorities, until one of
+// them stops propagation.
+//
+// Nothing runs until the queue is drained with `step` or `run`, always in
+// FIFO order, so a test can emit events and then check exactly what
+// happened. Events emitted by handlers join the back of the queue.
+//
+// The log records only the events emitted from outside, in dispatch order:
+// the ones handlers emit follow from those, so replaying the log into the
+// same handlers reproduces the whole session.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::WebEvent;
+
+// The variants of `WebEvent` without their data, for subscribing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    PageLoad,
+    PageUnload,
+    KeyPress,
+    Paste,
+    Click,
+}
+
+impl WebEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            WebEvent::PageLoad => EventKind::PageLoad,
+            WebEvent::PageUnload => EventKind::PageUnload,
+            WebEvent::KeyPress(_) => EventKind::KeyPress,
+            WebEvent::Paste(_) => EventKind::Paste,
+            WebEvent::Click { .. } => EventKind::Click,
+        }
+    }
+}
+
+// A predicate for clicks inside a rectangle, edges included.
+pub fn clicks_within(
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>,
+) -> impl Fn(&WebEvent) -> bool {
+    move |event| match event {
+        WebEvent::Click { x: cx, y: cy } => x.contains(cx) && y.contains(cy),
+        _ => false,
+    }
+}
+
+// Identifies a handler, for `Dispatcher::remove`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandlerId(usize);
+
+// What a handler can do besides looking at the event.
+#[derive(Debug, Default)]
+pub struct Context {
+    emitted: Vec<WebEvent>,
+    stopped: bool,
+}
+
+impl Context {
+    // Queues another event, to be dispatched after everything already queued.
+    pub fn emit(&mut self, event: WebEvent) {
+        self.emitted.push(event);
+    }
+
+    // Keeps the event from lower priority handlers.
+    pub fn stop_propagation(&mut self) {
+        self.stopped = true;
+    }
+}
+
+enum Filter<'a> {
+    Kind(EventKind),
+    Predicate(Box<dyn Fn(&WebEvent) -> bool + 'a>),
+    Any,
+}
+
+impl Filter<'_> {
+    fn matches(&self, event: &WebEvent) -> bool {
+        match self {
+            Filter::Kind(kind) => event.kind() == *kind,
+            Filter::Predicate(predicate) => predicate(event),
+            Filter::Any => true,
+        }
+    }
+}
+
+type Callback<'a> = Box<dyn FnMut(&WebEvent, &mut Context) + 'a>;
+
+struct Handler<'a> {
+    id: HandlerId,
+    priority: i32,
+    filter: Filter<'a>,
+    callback: Callback<'a>,
+}
+
+// What happened to one dispatched event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delivery {
+    pub event: WebEvent,
+    // The handlers that ran, in the order they ran.
+    pub handlers: Vec<HandlerId>,
+    // Whether one of them stopped propagation.
+    pub stopped: bool,
+}
+
+#[derive(Default)]
+pub struct Dispatcher<'a> {
+    // Sorted by descending priority, then by registration.
+    handlers: Vec<Handler<'a>>,
+    // Each event with whether it came from outside, through `emit`.
+    queue: VecDeque<(WebEvent, bool)>,
+    log: Vec<WebEvent>,
+    next_id: usize,
+}
+
+impl<'a> Dispatcher<'a> {
+    pub fn new() -> Dispatcher<'a> {
+        Dispatcher::default()
+    }
+
+    // Handles every event of one variant.
+    pub fn on(
+        &mut self,
+        kind: EventKind,
+        priority: i32,
+        callback: impl FnMut(&WebEvent, &mut Context) + 'a,
+    ) -> HandlerId {
+        self.add(Filter::Kind(kind), priority, Box::new(callback))
+    }
+
+    // Handles the events `predicate` accepts.
+    pub fn on_match(
+        &mut self,
+        predicate: impl Fn(&WebEvent) -> bool + 'a,
+        priority: i32,
+        callback: impl FnMut(&WebEvent, &mut Context) + 'a,
+    ) -> HandlerId {
+        self.add(Filter::Predicate(Box::new(predicate)), priority, Box::new(callback))
+    }
+
+    // Handles every event.
+    pub fn on_any(
+        &mut self,
+        priority: i32,
+        callback: impl FnMut(&WebEvent, &mut Context) + 'a,
+    ) -> HandlerId {
+        self.add(Filter::Any, priority, Box::new(callback))
+    }
+
+    fn add(
+        &mut self,
+        filter: Filter<'a>,
+        priority: i32,
+        callback: Callback<'a>,
+    ) -> HandlerId {
+        let id = HandlerId(self.next_id);
+        self.next_id += 1;
+        // After every handler of the same or higher priority.
+        let index = self.handlers.partition_point(|h| h.priority >= priority);
+        self.handlers.insert(index, Handler { id, priority, filter, callback });
+        id
+    }
+
+    // Returns whether the handler was still registered.
+    pub fn remove(&mut self, id: HandlerId) -> bool {
+        let before = self.handlers.len();
+        self.handlers.retain(|h| h.id != id);
+        self.handlers.len() != before
+    }
+
+    pub fn emit(&mut self, event: WebEvent) {
+        self.queue.push_back((event, true));
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    // Dispatches the next queued event, if there is one.
+    pub fn step(&mut self) -> Option<Delivery> {
+        let (event, external) = self.queue.pop_front()?;
+        let mut context = Context::default();
+        let mut handlers = Vec::new();
+        for handler in &mut self.handlers {
+            if !handler.filter.matches(&event) {
+                continue;
+            }
+            (handler.callback)(&event, &mut context);
+            handlers.push(handler.id);
+            if context.stopped {
+                break;
+            }
+        }
+        self.queue.extend(context.emitted.into_iter().map(|event| (event, false)));
+        if external {
+            self.log.push(event.clone());
+        }
+        Some(Delivery { event, handlers, stopped: context.stopped })
+    }
+
+    // Dispatches until the queue is empty, including events emitted along the
+    // way, and returns what happened to each.
+    pub fn run(&mut self) -> Vec<Delivery> {
+        let mut deliveries = Vec::new();
+        while let Some(delivery) = self.step() {
+            deliveries.push(delivery);
+        }
+        deliveries
+    }
+
+    // Every dispatched event that was emitted from outside, in dispatch order.
+    // Write it out with `format_session` and feed it to `replay` to run the
+    // session again.
+    pub fn log(&self) -> &[WebEvent] {
+        &self.log
+    }
+
+    pub fn take_log(&mut self) -> Vec<WebEvent> {
+        std::mem::take(&mut self.log)
+    }
+
+    // Queues recorded events and runs them. The handlers emit whatever they
+    // emitted the first time, so the deliveries come out the same.
+    pub fn replay(&mut self, events: impl IntoIterator<Item = WebEvent>) -> Vec<Delivery> {
+        for event in events {
+            self.emit(event);
+        }
+        self.run()
+    }
+}
+
+impl fmt::Debug for Dispatcher<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("handlers", &self.handlers.iter().map(|h| (h.id, h.priority)).collect::<Vec<_>>())
+            .field("queue", &self.queue.iter().map(|(event, _)| event).collect::<Vec<_>>())
+            .field("log", &self.log)
+            .finish()
+    }
+}
@@ -0,0 +1,320 @@
+// The `WebEvent` from `enums`, with a one-line text format so sessions can be
+// written to a file and read back:
+//
+//     load
+//     key x
+//     paste "my text"
+//     click 20 80
+//     unload
+//
+// `paste` always quotes its text, `key` only quotes characters that wouldn't
+// survive as a bare word (whitespace, quotes, backslashes, control
+// characters). Inside quotes `\"`, `\\`, `\n`, `\r`, `\t` and `\u{...}` are
+// escapes. Formatting an event and parsing it back gives the same event.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WebEvent {
+    PageLoad,
+    PageUnload,
+    KeyPress(char),
+    Paste(String),
+    Click { x: i64, y: i64 },
+}
+
+impl WebEvent {
+    // The word that starts the event's line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebEvent::PageLoad => "load",
+            WebEvent::PageUnload => "unload",
+            WebEvent::KeyPress(_) => "key",
+            WebEvent::Paste(_) => "paste",
+            WebEvent::Click { .. } => "click",
+        }
+    }
+
+    // Parses one line, numbering errors with `line`.
+    pub fn parse_line(text: &str, line: usize) -> Result<WebEvent, ParseError> {
+        let mut cursor = Cursor { text, pos: 0, line };
+        cursor.skip_space();
+        let column = cursor.column();
+        let name = cursor.word();
+        if name.is_empty() {
+            return Err(cursor.error(column, ParseErrorKind::Empty));
+        }
+        let event = match name {
+            "load" => WebEvent::PageLoad,
+            "unload" => WebEvent::PageUnload,
+            "key" => WebEvent::KeyPress(cursor.key()?),
+            "paste" => WebEvent::Paste(cursor.quoted()?),
+            "click" => {
+                let x = cursor.number()?;
+                let y = cursor.number()?;
+                WebEvent::Click { x, y }
+            }
+            _ => return Err(cursor.error(column, ParseErrorKind::UnknownEvent(name.to_string()))),
+        };
+        cursor.skip_space();
+        if cursor.pos < text.len() {
+            return Err(cursor.error(cursor.column(), ParseErrorKind::UnexpectedInput));
+        }
+        Ok(event)
+    }
+}
+
+// Parses a whole session, one event per line. Blank lines and lines starting
+// with `#` are skipped.
+pub fn parse_session(text: &str) -> Result<Vec<WebEvent>, ParseError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim_start();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(i, line)| WebEvent::parse_line(line, i + 1))
+        .collect()
+}
+
+// The events one per line, each line ending in `\n`.
+pub fn format_session<'a>(events: impl IntoIterator<Item = &'a WebEvent>) -> String {
+    events.into_iter().map(|event| format!("{event}\n")).collect()
+}
+
+impl FromStr for WebEvent {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<WebEvent, ParseError> {
+        WebEvent::parse_line(s, 1)
+    }
+}
+
+// Characters `key` can write without quotes.
+fn is_bare(c: char) -> bool {
+    !c.is_whitespace() && !c.is_control() && c != '"' && c != '\\'
+}
+
+fn write_quoted(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{{{:x}}}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for WebEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())?;
+        match self {
+            WebEvent::PageLoad | WebEvent::PageUnload => Ok(()),
+            WebEvent::KeyPress(c) if is_bare(*c) => write!(f, " {c}"),
+            WebEvent::KeyPress(c) => {
+                write!(f, " ")?;
+                write_quoted(f, c.encode_utf8(&mut [0; 4]))
+            }
+            WebEvent::Paste(text) => {
+                write!(f, " ")?;
+                write_quoted(f, text)
+            }
+            WebEvent::Click { x, y } => write!(f, " {x} {y}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    // Nothing but whitespace.
+    Empty,
+    UnknownEvent(String),
+    // The line ended where an argument was expected.
+    MissingArgument,
+    // Something after the last argument.
+    UnexpectedInput,
+    InvalidNumber(String),
+    // A `key` argument that isn't exactly one character.
+    InvalidKey(String),
+    // `paste` text without its opening quote.
+    ExpectedQuote,
+    UnterminatedString,
+    InvalidEscape(String),
+}
+
+// Where parsing stopped. Lines and columns count from 1, columns in
+// characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::Empty => write!(f, "expected an event"),
+            ParseErrorKind::UnknownEvent(name) => write!(f, "unknown event `{name}`"),
+            ParseErrorKind::MissingArgument => write!(f, "missing argument"),
+            ParseErrorKind::UnexpectedInput => write!(f, "unexpected input after the event"),
+            ParseErrorKind::InvalidNumber(s) => write!(f, "`{s}` is not a number"),
+            ParseErrorKind::InvalidKey(s) => write!(f, "`{s}` is not a single key"),
+            ParseErrorKind::ExpectedQuote => write!(f, "expected a quoted string"),
+            ParseErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            ParseErrorKind::InvalidEscape(s) => write!(f, "invalid escape `{s}`"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+// Reads one line left to right, keeping track of the column for errors.
+struct Cursor<'a> {
+    text: &'a str,
+    // Byte offset into `text`.
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    fn column_at(&self, pos: usize) -> usize {
+        self.text[..pos].chars().count() + 1
+    }
+
+    fn error(&self, column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line: self.line, column, kind }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_space(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // Everything up to the next whitespace.
+    fn word(&mut self) -> &'a str {
+        let rest = self.rest();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    // Moves to the next argument, which must be there.
+    fn argument(&mut self) -> Result<usize, ParseError> {
+        let before = self.pos;
+        self.skip_space();
+        if self.pos == self.text.len() {
+            return Err(self.error(self.column(), ParseErrorKind::MissingArgument));
+        }
+        if self.pos == before {
+            // Arguments are separated by whitespace, e.g. `key"x"`.
+            return Err(self.error(self.column(), ParseErrorKind::UnexpectedInput));
+        }
+        Ok(self.column())
+    }
+
+    fn number(&mut self) -> Result<i64, ParseError> {
+        let column = self.argument()?;
+        let word = self.word();
+        word.parse()
+            .map_err(|_| self.error(column, ParseErrorKind::InvalidNumber(word.to_string())))
+    }
+
+    fn key(&mut self) -> Result<char, ParseError> {
+        let column = self.argument()?;
+        let (text, raw) = if self.rest().starts_with('"') {
+            let start = self.pos;
+            let text = self.quoted_here()?;
+            (text, &self.text[start..self.pos])
+        } else {
+            let word = self.word();
+            (word.to_string(), word)
+        };
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(self.error(column, ParseErrorKind::InvalidKey(raw.to_string()))),
+        }
+    }
+
+    fn quoted(&mut self) -> Result<String, ParseError> {
+        let column = self.argument()?;
+        if !self.rest().starts_with('"') {
+            return Err(self.error(column, ParseErrorKind::ExpectedQuote));
+        }
+        self.quoted_here()
+    }
+
+    // A string starting with the `"` at the cursor.
+    fn quoted_here(&mut self) -> Result<String, ParseError> {
+        let open = self.pos;
+        let mut text = String::new();
+        let mut chars = self.text[open + 1..].char_indices().map(|(i, c)| (open + 1 + i, c));
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos = i + 1;
+                    return Ok(text);
+                }
+                '\\' => {
+                    let bad = |end: usize| {
+                        let escape = self.text[i..end].to_string();
+                        self.error(self.column_at(i), ParseErrorKind::InvalidEscape(escape))
+                    };
+                    let Some((j, escape)) = chars.next() else {
+                        return Err(bad(self.text.len()));
+                    };
+                    let end = j + escape.len_utf8();
+                    text.push(match escape {
+                        '"' => '"',
+                        '\\' => '\\',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            // `\u{...}` with 1 to 6 hex digits.
+                            let rest = &self.text[end..];
+                            let close = rest.find('}').filter(|_| rest.starts_with('{'));
+                            let Some(close) = close else {
+                                return Err(bad(end));
+                            };
+                            let end = end + close + 1;
+                            let digits = &rest[1..close];
+                            // `from_str_radix` alone would take a leading `+`.
+                            let hex = (1..=6).contains(&digits.len())
+                                && digits.chars().all(|c| c.is_ascii_hexdigit());
+                            let c = hex
+                                .then(|| u32::from_str_radix(digits, 16).ok())
+                                .flatten()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| bad(end))?;
+                            // Skip over the braces and digits.
+                            while chars.next().is_some_and(|(k, _)| k + 1 < end) {}
+                            c
+                        }
+                        _ => return Err(bad(end)),
+                    });
+                }
+                c => text.push(c),
+            }
+        }
+        Err(self.error(self.column_at(open), ParseErrorKind::UnterminatedString))
+    }
+}
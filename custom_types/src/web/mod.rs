@@ -0,0 +1,13 @@
+// The `WebEvent` from `enums`, grown into something a UI session can be
+// recorded, dispatched, replayed and analysed with.
+
+mod analytics;
+mod dispatch;
+mod event;
+
+pub use analytics::{
+    double_clicks, parse_timed_session, sessions, DoubleClick, DoubleClickConfig, Heatmap,
+    HeatmapConfig, SessionStats, Timed,
+};
+pub use dispatch::{clicks_within, Context, Delivery, Dispatcher, EventKind, HandlerId};
+pub use event::{format_session, parse_session, ParseError, ParseErrorKind, WebEvent};
@@ -0,0 +1,157 @@
+mod common;
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use common::{p, random_point};
+use custom_types::geometry::{Affine2, GeometryError, HasArea, Point, Polygon, Rectangle, Shape};
+use testutil::Rng;
+
+const TOLERANCE: f64 = 1e-9;
+
+fn near(a: Point, b: Point) -> bool {
+    a.distance(b) <= TOLERANCE * a.length().max(b.length()).max(1.0)
+}
+
+fn near_transform(a: Affine2, b: Affine2) -> bool {
+    a.to_array().iter().zip(b.to_array()).all(|(x, y)| (x - y).abs() <= TOLERANCE)
+}
+
+// Uniform in `-range..range`.
+fn number(rng: &mut Rng, range: f64) -> f64 {
+    rng.unit() * 2.0 * range - range
+}
+
+// A product of a few random basic transforms, none of them singular.
+fn random_transform(rng: &mut Rng) -> Affine2 {
+    (0..1 + rng.below(4)).fold(Affine2::IDENTITY, |t, _| {
+        let step = match rng.below(4) {
+            0 => Affine2::translate(number(rng, 100.0), number(rng, 100.0)),
+            1 => Affine2::scale(rng.unit() * 3.0 + 0.1, -(rng.unit() * 3.0 + 0.1)),
+            2 => Affine2::rotate(number(rng, PI)),
+            _ => Affine2::shear(number(rng, 2.0), 0.0),
+        };
+        t.then(step)
+    })
+}
+
+#[test]
+fn basic_transforms() {
+    assert_eq!(Affine2::translate(1.0, 2.0) * p(3.0, 4.0), p(4.0, 6.0));
+    assert_eq!(Affine2::scale(2.0, -1.0).apply(p(3.0, 4.0)), p(6.0, -4.0));
+    assert!(near(Affine2::rotate(FRAC_PI_2) * p(1.0, 0.0), p(0.0, 1.0)));
+    assert!(near(Affine2::rotate_about(p(1.0, 1.0), PI) * p(2.0, 1.0), p(0.0, 1.0)));
+    assert_eq!(Affine2::shear(2.0, 0.0) * p(1.0, 3.0), p(7.0, 3.0));
+    assert_eq!(Affine2::shear(0.0, 2.0) * p(1.0, 3.0), p(1.0, 5.0));
+    assert_eq!(Affine2::default(), Affine2::IDENTITY);
+    // Offsets aren't translated.
+    assert_eq!(Affine2::translate(5.0, 5.0).apply_vector(p(1.0, 2.0)), p(1.0, 2.0));
+    assert_eq!(Affine2::translate(1.5, -2.0).to_string(), "matrix(1 0 0 1 1.5 -2)");
+}
+
+#[test]
+fn composition_order() {
+    let move_then_double = Affine2::translate(1.0, 0.0).then(Affine2::scale(2.0, 2.0));
+    assert_eq!(move_then_double * p(1.0, 1.0), p(4.0, 2.0));
+    assert_eq!(move_then_double, Affine2::scale(2.0, 2.0) * Affine2::translate(1.0, 0.0));
+    let double_then_move = Affine2::scale(2.0, 2.0).then(Affine2::translate(1.0, 0.0));
+    assert_eq!(double_then_move * p(1.0, 1.0), p(3.0, 2.0));
+
+    let mut rng = Rng(0xDEAD_BEEF_CAFE_F00D);
+    for _ in 0..500 {
+        let [t, u, v] = [(); 3].map(|_| random_transform(&mut rng));
+        let q = random_point(&mut rng, 10);
+        assert!(near((t * u) * q, t * (u * q)));
+        assert!(near_transform((t * u) * v, t * (u * v)));
+    }
+}
+
+#[test]
+fn determinants_and_inverses() {
+    assert_eq!(Affine2::scale(2.0, 3.0).determinant(), 6.0);
+    assert_eq!(Affine2::scale(-1.0, 1.0).determinant(), -1.0);
+    assert!((Affine2::rotate(1.0).determinant() - 1.0).abs() < TOLERANCE);
+    assert_eq!(Affine2::shear(5.0, 0.0).determinant(), 1.0);
+    assert_eq!(Affine2::scale(0.0, 1.0).inverse(), None);
+    assert_eq!(Affine2::new([1.0, 2.0, 2.0, 4.0, 0.0, 0.0]).inverse(), None);
+    assert_eq!(
+        Affine2::translate(3.0, -4.0).inverse(),
+        Some(Affine2::translate(-3.0, 4.0))
+    );
+    assert_eq!(Affine2::scale(2.0, 4.0).inverse(), Some(Affine2::scale(0.5, 0.25)));
+}
+
+#[test]
+fn transform_then_inverse_gives_the_original() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..1000 {
+        let t = random_transform(&mut rng);
+        let inverse = t.inverse().expect("not singular");
+        assert!(near_transform(t * inverse, Affine2::IDENTITY), "{t}");
+        assert!(near_transform(inverse * t, Affine2::IDENTITY), "{t}");
+
+        let q = random_point(&mut rng, 50);
+        assert!(near(inverse * (t * q), q));
+
+        let (a, b) = (random_point(&mut rng, 50), random_point(&mut rng, 50));
+        let rectangle = Rectangle::from_corners(a, b);
+        let quad = t.apply_rectangle(&rectangle).unwrap();
+        let back = inverse.apply_polygon(&quad).unwrap();
+        for (&v, corner) in back.vertices().iter().zip(rectangle.corners()) {
+            assert!(near(v, corner), "{v} != {corner}");
+        }
+
+        let polygon = Polygon::new((0..5).map(|_| random_point(&mut rng, 50)).collect()).unwrap();
+        let back = inverse.apply_polygon(&t.apply_polygon(&polygon).unwrap()).unwrap();
+        assert!(back.vertices().iter().zip(polygon.vertices()).all(|(&a, &b)| near(a, b)));
+    }
+}
+
+#[test]
+fn areas_scale_by_the_determinant() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..500 {
+        let t = random_transform(&mut rng);
+        let polygon = Polygon::new((0..6).map(|_| random_point(&mut rng, 20)).collect()).unwrap();
+        let moved = t.apply_polygon(&polygon).unwrap();
+        let expected = polygon.signed_area() * t.determinant();
+        assert!((moved.signed_area() - expected).abs() <= TOLERANCE * expected.abs().max(1.0));
+    }
+}
+
+#[test]
+fn rectangles_become_quads_or_bounds() {
+    let rectangle = Rectangle::from_corners(p(0.0, 0.0), p(4.0, 2.0));
+
+    let stretched = Affine2::scale(2.0, 3.0).then(Affine2::translate(1.0, 1.0));
+    assert!(stretched.keeps_axes());
+    assert_eq!(
+        stretched.apply_bounds(&rectangle),
+        Ok(Rectangle::from_corners(p(1.0, 1.0), p(9.0, 7.0)))
+    );
+    assert_eq!(stretched.apply_rectangle(&rectangle).unwrap().area(), 48.0);
+    assert!(Affine2::scale(-1.0, 1.0).keeps_axes());
+    assert!(Affine2::new([0.0, 1.0, -1.0, 0.0, 0.0, 0.0]).keeps_axes());
+
+    let tilted = Affine2::rotate_about(rectangle.centroid(), PI / 4.0);
+    assert!(!tilted.keeps_axes());
+    let quad = tilted.apply_rectangle(&rectangle).unwrap();
+    assert!((quad.area() - 8.0).abs() < TOLERANCE);
+    assert!(near(quad.centroid(), p(2.0, 1.0)));
+    assert!(quad.is_convex());
+    // The bounds of a 45 degree turn are a square with the diagonal sides.
+    let bounds = tilted.apply_bounds(&rectangle).unwrap();
+    let side = 6.0 / 2f64.sqrt();
+    assert!((bounds.width() - side).abs() < TOLERANCE);
+    assert!((bounds.height() - side).abs() < TOLERANCE);
+    assert!(quad.vertices().iter().all(|&v| bounds.contains(v)));
+}
+
+#[test]
+fn overflowing_vertices_are_an_error() {
+    let polygon = Polygon::new(vec![p(0.0, 0.0), p(1e300, 0.0), p(0.0, 1e300)]).unwrap();
+    let huge = Affine2::scale(1e10, 1e10);
+    assert_eq!(huge.apply_polygon(&polygon), Err(GeometryError::NotFinite));
+    let rectangle = Rectangle::from_corners(p(0.0, 0.0), p(1e300, 1e300));
+    assert_eq!(huge.apply_rectangle(&rectangle), Err(GeometryError::NotFinite));
+    assert_eq!(huge.apply_bounds(&rectangle), Err(GeometryError::NotFinite));
+}
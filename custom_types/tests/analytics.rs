@@ -0,0 +1,154 @@
+use custom_types::web::{
+    double_clicks, parse_timed_session, sessions, DoubleClick, DoubleClickConfig, Heatmap,
+    HeatmapConfig, ParseError, ParseErrorKind, SessionStats, Timed, WebEvent,
+};
+
+fn timed(text: &str) -> Vec<Timed> {
+    parse_timed_session(text).unwrap()
+}
+
+fn small() -> HeatmapConfig {
+    HeatmapConfig { left: -10, top: 0, width: 40, height: 20, columns: 4, rows: 2 }
+}
+
+#[test]
+fn timed_lines_round_trip() {
+    let event: Timed = "  120 click 20 -80".parse().unwrap();
+    assert_eq!(event, Timed::new(120, WebEvent::Click { x: 20, y: -80 }));
+    assert_eq!(event.to_string(), "120 click 20 -80");
+    let paste = Timed::new(7, WebEvent::Paste("a \"b\"".into()));
+    assert_eq!(paste.to_string().parse(), Ok(paste));
+}
+
+#[test]
+fn timed_errors_count_columns_from_the_line_start() {
+    let parse = |s: &str| s.parse::<Timed>().unwrap_err();
+    let error = |column, kind| ParseError { line: 1, column, kind };
+    assert_eq!(parse("   "), error(4, ParseErrorKind::Empty));
+    assert_eq!(parse(" soon load"), error(2, ParseErrorKind::InvalidNumber("soon".into())));
+    assert_eq!(parse("-5 load"), error(1, ParseErrorKind::InvalidNumber("-5".into())));
+    assert_eq!(parse("10"), error(3, ParseErrorKind::Empty));
+    assert_eq!(parse("10 click 1 x"), error(12, ParseErrorKind::InvalidNumber("x".into())));
+    assert_eq!(parse("  10  load now"), error(12, ParseErrorKind::UnexpectedInput));
+
+    let err = parse_timed_session("# clicks\n0 load\n\n5 scroll\n").unwrap_err();
+    assert_eq!(err, ParseError { line: 4, column: 3, kind: ParseErrorKind::UnknownEvent("scroll".into()) });
+}
+
+#[test]
+fn recordings_split_at_each_load() {
+    let events = timed("0 key a\n1 load\n2 click 1 1\n3 unload\n4 load\n5 load\n");
+    let split: Vec<usize> = sessions(&events).iter().map(|s| s.len()).collect();
+    assert_eq!(split, [1, 3, 1, 1]);
+    assert_eq!(sessions(&events)[1][0].at_ms, 1);
+    assert!(sessions(&[]).is_empty());
+}
+
+#[test]
+fn clicks_land_in_their_cells() {
+    let mut heatmap = Heatmap::new(small());
+    assert_eq!(heatmap.cell_of(-10, 0), Some((0, 0)));
+    assert_eq!(heatmap.cell_of(-1, 9), Some((0, 0)));
+    assert_eq!(heatmap.cell_of(0, 10), Some((1, 1)));
+    assert_eq!(heatmap.cell_of(29, 19), Some((3, 1)));
+    assert_eq!(heatmap.cell_of(30, 5), None);
+    assert_eq!(heatmap.cell_of(-11, 5), None);
+    assert_eq!(heatmap.cell_of(i64::MIN, i64::MAX), None);
+
+    assert!(heatmap.add(5, 5));
+    assert!(heatmap.add(6, 7));
+    assert!(!heatmap.add(5, 20));
+    heatmap.add_events(&[WebEvent::Click { x: 25, y: 15 }, WebEvent::KeyPress('c')]);
+    assert_eq!(heatmap.count(1, 0), Some(2));
+    assert_eq!(heatmap.count(3, 1), Some(1));
+    assert_eq!(heatmap.count(4, 0), None);
+    assert_eq!((heatmap.total(), heatmap.outside(), heatmap.max()), (3, 1, 2));
+    assert_eq!(heatmap.hottest(), Some((1, 0, 2)));
+    assert_eq!(Heatmap::new(small()).hottest(), None);
+}
+
+#[test]
+#[should_panic(expected = "at least one cell")]
+fn heatmaps_need_cells() {
+    Heatmap::new(HeatmapConfig { columns: 0, ..HeatmapConfig::default() });
+}
+
+#[test]
+fn renders_ascii_and_ansi() {
+    let config = HeatmapConfig { left: 0, top: 0, width: 3, height: 2, columns: 3, rows: 2 };
+    let mut heatmap = Heatmap::new(config);
+    assert_eq!(heatmap.render_ascii(), "   \n   ");
+    for _ in 0..9 {
+        heatmap.add(0, 0);
+    }
+    heatmap.add(1, 0);
+    for _ in 0..5 {
+        heatmap.add(2, 1);
+    }
+    assert_eq!(heatmap.render_ascii(), "@. \n  +");
+    assert_eq!(
+        heatmap.render_ansi(),
+        "\x1b[48;5;196m  \x1b[0m\x1b[48;5;21m  \x1b[0m  \n    \x1b[48;5;82m  \x1b[0m"
+    );
+}
+
+#[test]
+fn double_clicks_need_to_be_quick_and_close() {
+    let config = DoubleClickConfig::default();
+    let events = timed(
+        "0 click 10 10\n400 click 14 6\n\
+         1000 click 50 50\n1600 click 50 50\n\
+         2000 click 0 0\n2100 click 5 0\n\
+         3000 click 1 1\n3100 key x\n3200 click 1 1\n",
+    );
+    assert_eq!(double_clicks(&events, config), [DoubleClick { at_ms: 400, x: 10, y: 10 }]);
+    let slow = DoubleClickConfig { max_gap_ms: 600, max_distance: 5 };
+    assert_eq!(double_clicks(&events, slow).len(), 3);
+}
+
+#[test]
+fn clicks_at_the_far_edges_dont_overflow() {
+    let events = timed(&format!(
+        "0 click {min} {min}\n100 click {max} {max}\n200 click {max} {max}\n",
+        min = i64::MIN,
+        max = i64::MAX
+    ));
+    let found = double_clicks(&events, DoubleClickConfig::default());
+    assert_eq!(found, [DoubleClick { at_ms: 200, x: i64::MAX, y: i64::MAX }]);
+    let almost = DoubleClickConfig { max_gap_ms: 500, max_distance: u64::MAX - 1 };
+    assert_eq!(double_clicks(&events[..2], almost).len(), 0);
+    let anywhere = DoubleClickConfig { max_gap_ms: 500, max_distance: u64::MAX };
+    assert_eq!(double_clicks(&events[..2], anywhere).len(), 1);
+}
+
+#[test]
+fn a_triple_click_is_one_double_click() {
+    let events = timed("0 click 1 1\n100 click 1 1\n200 click 1 1\n300 click 1 1\n400 click 1 1\n");
+    let found = double_clicks(&events, DoubleClickConfig::default());
+    let at: Vec<u64> = found.iter().map(|d| d.at_ms).collect();
+    assert_eq!(at, [100, 300]);
+    assert_eq!(double_clicks(&events[..3], DoubleClickConfig::default()).len(), 1);
+}
+
+#[test]
+fn session_statistics() {
+    let events = timed(
+        "100 load\n200 key b\n250 key a\n300 key b\n400 paste \"héllo\"\n\
+         500 paste \"\"\n600 click 1 1\n700 click 1 1\n900 unload\n",
+    );
+    let stats = SessionStats::of(&events, DoubleClickConfig::default());
+    assert_eq!(stats.events, 9);
+    assert_eq!(stats.duration_ms, 800);
+    assert_eq!((stats.clicks, stats.double_clicks), (2, 1));
+    assert_eq!(stats.keys_pressed, 3);
+    assert_eq!(stats.top_key(), Some(('b', 2)));
+    assert_eq!((stats.pastes, stats.pasted_chars, stats.longest_paste), (2, 5, 5));
+    assert_eq!(
+        stats.to_string(),
+        "events:        9 over 800 ms\n\
+         clicks:        2 (1 double)\n\
+         keys pressed:  3 (most often 'b': 2)\n\
+         pastes:        2 (5 chars, longest 5)"
+    );
+    assert_eq!(SessionStats::of(&[], DoubleClickConfig::default()), SessionStats::default());
+}
@@ -0,0 +1,218 @@
+use custom_types::bignum::{BigDecimal, BigUint, DivisionByZero, ParseNumberError, Rounding};
+use testutil::Rng;
+
+fn big(s: &str) -> BigUint {
+    s.parse().unwrap()
+}
+
+fn dec(s: &str) -> BigDecimal {
+    s.parse().unwrap()
+}
+
+// Up to `max` bits, often with long runs of zero or one bits.
+fn random_u128(rng: &mut Rng, max: u32) -> u128 {
+    let n = (rng.next() as u128) << 64 | rng.next() as u128;
+    let n = match rng.next() % 4 {
+        0 => n | 0xffff_ffff << 32,
+        1 => n & !(0xffff_ffff << 64),
+        _ => n,
+    };
+    n >> (128 - rng.next() as u32 % (max + 1)).min(127)
+}
+
+// A number of about `limbs` 32-bit limbs.
+fn random_big(rng: &mut Rng, limbs: usize) -> BigUint {
+    let digit = |rng: &mut Rng| char::from(b'0' + rng.below(10) as u8);
+    let digits: String = (0..limbs * 10).map(|_| digit(rng)).collect();
+    digits.parse().unwrap()
+}
+
+#[test]
+fn parses_and_prints_integers() {
+    assert_eq!(big("0"), BigUint::zero());
+    assert_eq!(big("000123"), BigUint::from(123u32));
+    assert_eq!(big("1_000_000").to_string(), "1000000");
+    assert_eq!(big(&u128::MAX.to_string()), BigUint::from(u128::MAX));
+    let long = "123456789012345678901234567890123456789012345678901234567890";
+    assert_eq!(big(long).to_string(), long);
+    assert_eq!(format!("{:>8}|{:08}", big("42"), big("42")), "      42|00000042");
+    assert_eq!("".parse::<BigUint>(), Err(ParseNumberError::Empty));
+    assert_eq!("12a".parse::<BigUint>(), Err(ParseNumberError::InvalidDigit { index: 2, found: 'a' }));
+    assert_eq!("-1".parse::<BigUint>(), Err(ParseNumberError::InvalidDigit { index: 0, found: '-' }));
+    assert_eq!("_1".parse::<BigUint>(), Err(ParseNumberError::InvalidDigit { index: 0, found: '_' }));
+    assert_eq!("1_".parse::<BigUint>(), Err(ParseNumberError::InvalidDigit { index: 1, found: '_' }));
+}
+
+#[test]
+fn integer_arithmetic_matches_u128() {
+    let mut rng = Rng(0x853C_49E6_748F_EA9B);
+    for _ in 0..5000 {
+        let (a, b) = (random_u128(&mut rng, 127), random_u128(&mut rng, 127));
+        let (x, y) = (BigUint::from(a), BigUint::from(b));
+        assert_eq!((&x + &y).to_u128(), Some(a + b), "{a} + {b}");
+        assert_eq!(x.checked_sub(&y).map(|d| d.to_u128().unwrap()), a.checked_sub(b), "{a} - {b}");
+        let (c, d) = (random_u128(&mut rng, 64), random_u128(&mut rng, 63));
+        assert_eq!((BigUint::from(c) * BigUint::from(d)).to_u128(), Some(c * d), "{c} * {d}");
+        if let (Some(quotient), Some(remainder)) = (a.checked_div(b), a.checked_rem(b)) {
+            let (q, r) = x.div_rem(&y);
+            assert_eq!((q.to_u128(), r.to_u128()), (Some(quotient), Some(remainder)), "{a} / {b}");
+        }
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(x.bits(), 128 - a.leading_zeros() as u64);
+    }
+}
+
+#[test]
+fn karatsuba_agrees_with_schoolbook() {
+    let mut rng = Rng(0xDA94_2042_E4DD_58B5);
+    for (a, b) in [(40, 40), (31, 200), (64, 33), (100, 100), (250, 7), (300, 280)] {
+        let (x, y) = (random_big(&mut rng, a), random_big(&mut rng, b));
+        let product = x.mul_schoolbook(&y);
+        assert_eq!(x.mul_karatsuba(&y), product, "{a} x {b} limbs");
+        assert_eq!(&x * &y, product);
+        assert_eq!(product.div_rem(&y), (x.clone(), BigUint::zero()));
+    }
+    let ones = BigUint::from(2u32).pow(4000) - BigUint::one();
+    assert_eq!(ones.mul_karatsuba(&ones), ones.mul_schoolbook(&ones));
+}
+
+#[test]
+fn long_division_reconstructs_the_dividend() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..300 {
+        let (n_limbs, d_limbs) = (1 + rng.next() as usize % 40, 1 + rng.next() as usize % 20);
+        let n = random_big(&mut rng, n_limbs);
+        let d = random_big(&mut rng, d_limbs) + BigUint::one();
+        let (q, r) = n.div_rem(&d);
+        assert!(r < d);
+        assert_eq!(&q * &d + &r, n);
+    }
+    // From Hacker's Delight: the estimated quotient limb is one too large
+    // even after the correction step, so the divisor has to be added back.
+    let n = BigUint::from(0x7fff_ffff_8000_0000_0000_0000_0000_0000u128);
+    let d = BigUint::from(0x8000_0000_0000_0000_0000_0001u128);
+    let (q, r) = n.div_rem(&d);
+    assert_eq!(q.to_u128(), Some(0xffff_fffe));
+    assert_eq!(r.to_u128(), Some(0x7fff_ffff_ffff_ffff_0000_0002));
+}
+
+#[test]
+fn known_values() {
+    let factorial = (1..=30u32).fold(BigUint::one(), |n, k| n * BigUint::from(k));
+    assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+    assert_eq!(BigUint::from(2u32).pow(200).to_string(), "1606938044258990275541962092341162602522202993782792835301376");
+    assert_eq!(BigUint::from(7u32).pow(0), BigUint::one());
+    assert!(BigUint::from(10u32).is_even() && BigUint::zero().is_even());
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn integer_division_by_zero_panics() {
+    big("5").div_rem(&BigUint::zero());
+}
+
+#[test]
+fn parses_decimals() {
+    assert_eq!(dec("-12.50").to_string(), "-12.50");
+    assert_eq!(dec("+3").to_string(), "3");
+    assert_eq!(dec(".5").to_string(), "0.5");
+    assert_eq!(dec("6.").to_string(), "6");
+    assert_eq!(dec("1.5e-3").to_string(), "0.0015");
+    assert_eq!(dec("1.5E3").to_string(), "1500");
+    assert_eq!(dec("-0.00").to_string(), "0.00");
+    assert_eq!(dec("1_000.000_1").to_string(), "1000.0001");
+    assert_eq!(dec("1.50").scale(), 2);
+    assert_eq!("-".parse::<BigDecimal>(), Err(ParseNumberError::Empty));
+    assert_eq!("1.2.3".parse::<BigDecimal>(), Err(ParseNumberError::InvalidDigit { index: 3, found: '.' }));
+    assert_eq!("-1x".parse::<BigDecimal>(), Err(ParseNumberError::InvalidDigit { index: 2, found: 'x' }));
+    assert_eq!("1._5".parse::<BigDecimal>(), Err(ParseNumberError::InvalidDigit { index: 2, found: '_' }));
+    assert_eq!("-1_.5".parse::<BigDecimal>(), Err(ParseNumberError::InvalidDigit { index: 2, found: '_' }));
+    assert_eq!("._5".parse::<BigDecimal>(), Err(ParseNumberError::InvalidDigit { index: 1, found: '_' }));
+    assert_eq!("1e".parse::<BigDecimal>(), Err(ParseNumberError::InvalidExponent { index: 1 }));
+    assert_eq!("1e999999".parse::<BigDecimal>(), Err(ParseNumberError::InvalidExponent { index: 1 }));
+}
+
+#[test]
+fn decimal_arithmetic_is_exact() {
+    assert_eq!((dec("0.1") + dec("0.2")).to_string(), "0.3");
+    assert_eq!((dec("1.10") - dec("2.025")).to_string(), "-0.925");
+    assert_eq!((dec("-1.5") + dec("1.5")).to_string(), "0.0");
+    assert_eq!((dec("-1.25") * dec("-0.4")).to_string(), "0.500");
+    assert_eq!((dec("19.99") * BigDecimal::from(3)).to_string(), "59.97");
+    assert_eq!((-dec("2")).to_string(), "-2");
+    assert_eq!(-dec("0"), dec("0"));
+
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..1000 {
+        let a = rng.next() as i64 >> 20;
+        let b = rng.next() as i64 >> 20;
+        let x = BigDecimal::new(a < 0, BigUint::from(a.unsigned_abs()), 3);
+        let y = BigDecimal::new(b < 0, BigUint::from(b.unsigned_abs()), 1);
+        // The same sums in thousandths.
+        let (ai, bi) = (a as i128, b as i128 * 100);
+        assert_eq!(&x + &y, BigDecimal::new(ai + bi < 0, BigUint::from((ai + bi).unsigned_abs()), 3));
+        assert_eq!(&x - &y, BigDecimal::new(ai - bi < 0, BigUint::from((ai - bi).unsigned_abs()), 3));
+        assert_eq!(x.cmp(&y), ai.cmp(&bi));
+    }
+}
+
+#[test]
+fn divides_to_a_chosen_precision() {
+    let third = BigDecimal::from(1).div(&BigDecimal::from(3), 20, Rounding::HalfEven).unwrap();
+    assert_eq!(third.to_string(), "0.33333333333333333333");
+    let two_thirds = BigDecimal::from(-2).div(&BigDecimal::from(3), 5, Rounding::HalfUp).unwrap();
+    assert_eq!(two_thirds.to_string(), "-0.66667");
+    let down = BigDecimal::from(-2).div(&BigDecimal::from(3), 5, Rounding::Down).unwrap();
+    assert_eq!(down.to_string(), "-0.66666");
+    assert_eq!(dec("1.000").div(&dec("0.08"), 0, Rounding::HalfEven).unwrap().to_string(), "12");
+    assert_eq!(dec("1.000").div(&dec("0.08"), 0, Rounding::HalfUp).unwrap().to_string(), "13");
+    assert_eq!(dec("123.456").div(&dec("1000"), 1, Rounding::HalfEven).unwrap().to_string(), "0.1");
+    assert_eq!(dec("1").div(&dec("0.000"), 2, Rounding::Down), Err(DivisionByZero));
+}
+
+#[test]
+fn rounds_and_normalizes() {
+    let cases = [
+        ("2.345", Rounding::HalfEven, "2.34"),
+        ("2.355", Rounding::HalfEven, "2.36"),
+        ("2.345", Rounding::HalfUp, "2.35"),
+        ("-2.345", Rounding::HalfUp, "-2.35"),
+        ("2.349", Rounding::Down, "2.34"),
+        ("-0.004", Rounding::HalfEven, "0.00"),
+    ];
+    for (value, rounding, expected) in cases {
+        assert_eq!(dec(value).with_scale(2, rounding).to_string(), expected, "{value} {rounding:?}");
+    }
+    assert_eq!(dec("7").with_scale(3, Rounding::Down).to_string(), "7.000");
+    assert_eq!(dec("1.2300").normalized().to_string(), "1.23");
+    assert_eq!(dec("100").normalized().to_string(), "100");
+    assert_eq!(dec("0.000").normalized().to_string(), "0");
+    assert_eq!(format!("{:.3}", dec("3.14159")), "3.142");
+    assert_eq!(format!("{:+.1}", dec("0.25")), "+0.2");
+    assert_eq!(format!("{:>8.2}", dec("-1.005")), "   -1.00");
+}
+
+#[test]
+fn compares_by_value() {
+    assert_eq!(dec("1.5"), dec("1.50000"));
+    assert!(dec("-2") < dec("-1.999"));
+    assert!(dec("0.1") > dec("0.09"));
+    assert!(dec("-0.0") == dec("0"));
+    let mut values = [dec("3"), dec("-1.5"), dec("0.25"), dec("-10")];
+    values.sort();
+    let sorted: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    assert_eq!(sorted, ["-10", "-1.5", "0.25", "3"]);
+}
+
+#[test]
+fn floats_convert_exactly() {
+    assert_eq!(BigDecimal::from_f64(0.1).unwrap().to_string(), "0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(BigDecimal::from_f64(-2.5).unwrap().to_string(), "-2.5");
+    assert_eq!(BigDecimal::from_f64(1e20).unwrap().to_string(), "100000000000000000000");
+    assert_eq!(BigDecimal::from_f64(0.0).unwrap(), BigDecimal::zero());
+    assert_eq!(BigDecimal::from_f64(f64::NAN), None);
+    for x in [0.1, -7.25e-300, 5e-324, f64::MAX, 1.0 / 3.0] {
+        assert_eq!(BigDecimal::from_f64(x).unwrap().to_f64(), x);
+    }
+}
@@ -0,0 +1,208 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use custom_types::calc::{
+    eval_infix, eval_rpn, negate, to_rpn, tokenize, CalcError, CalcErrorKind, MathError, Operator,
+    Spanned, Token, TodoWithNumbers,
+};
+use testutil::Rng;
+
+use TodoWithNumbers::*;
+
+fn error(column: usize, kind: CalcErrorKind) -> CalcError {
+    CalcError { column, kind }
+}
+
+#[test]
+fn operators_are_checked() {
+    assert_eq!(Add.run(10, 5), Ok(15));
+    assert_eq!(Subtract.run(10, 5), Ok(5));
+    assert_eq!(Multiply.run(-4, 5), Ok(-20));
+    assert_eq!(Divide.run(-7, 2), Ok(-3));
+    assert_eq!(Remainder.run(-7, 2), Ok(-1));
+    assert_eq!(Power.run(-3, 3), Ok(-27));
+    assert_eq!(Power.run(0, 0), Ok(1));
+    assert_eq!(And.run(0b1100, 0b1010), Ok(0b1000));
+    assert_eq!(Or.run(0b1100, 0b1010), Ok(0b1110));
+    assert_eq!(Xor.run(0b1100, 0b1010), Ok(0b0110));
+    assert_eq!(ShiftLeft.run(-3, 4), Ok(-48));
+    assert_eq!(ShiftRight.run(-48, 4), Ok(-3));
+    assert_eq!(negate(7), Ok(-7));
+
+    assert_eq!(Add.run(i32::MAX, 1), Err(MathError::Overflow(Add.into())));
+    assert_eq!(Subtract.run(i32::MIN, 1), Err(MathError::Overflow(Subtract.into())));
+    assert_eq!(Multiply.run(1 << 16, 1 << 15), Err(MathError::Overflow(Multiply.into())));
+    assert_eq!(Divide.run(i32::MIN, -1), Err(MathError::Overflow(Divide.into())));
+    assert_eq!(Remainder.run(i32::MIN, -1), Err(MathError::Overflow(Remainder.into())));
+    assert_eq!(Divide.run(1, 0), Err(MathError::DivisionByZero));
+    assert_eq!(Remainder.run(1, 0), Err(MathError::DivisionByZero));
+    assert_eq!(Power.run(2, 31), Err(MathError::Overflow(Power.into())));
+    assert_eq!(Power.run(2, -1), Err(MathError::NegativeExponent));
+    assert_eq!(ShiftLeft.run(1, 31), Err(MathError::Overflow(ShiftLeft.into())));
+    assert_eq!(ShiftLeft.run(-1, 31), Ok(i32::MIN));
+    assert_eq!(ShiftLeft.run(1, 32), Err(MathError::ShiftOutOfRange(32)));
+    assert_eq!(ShiftRight.run(1, -1), Err(MathError::ShiftOutOfRange(-1)));
+    assert_eq!(negate(i32::MIN), Err(MathError::Overflow(Operator::Negate)));
+    assert_eq!(negate(i32::MIN).unwrap_err().to_string(), "`neg` overflowed");
+}
+
+#[test]
+fn symbols_name_every_operator_once() {
+    for op in TodoWithNumbers::ALL {
+        assert_eq!(Operator::from_symbol(op.symbol()), Some(op.into()));
+        assert_eq!(op.to_string(), op.symbol());
+    }
+    assert_eq!(Operator::from_symbol("neg"), Some(Operator::Negate));
+    assert_eq!(Operator::from_symbol("//"), None);
+    assert_eq!(Add as i32, 0);
+    assert_eq!(ShiftRight as i32, 10);
+}
+
+#[test]
+fn tokens_need_no_spaces() {
+    let tokens: Vec<Spanned> = tokenize(" (12**-3)<<neg").unwrap();
+    let expected = [
+        (2, Token::Open),
+        (3, Token::Number(12)),
+        (5, Token::Operator(Power.into())),
+        (7, Token::Operator(Subtract.into())),
+        (8, Token::Number(3)),
+        (9, Token::Close),
+        (10, Token::Operator(ShiftLeft.into())),
+        (12, Token::Operator(Operator::Negate)),
+    ];
+    let expected: Vec<Spanned> =
+        expected.into_iter().map(|(column, token)| Spanned { column, token }).collect();
+    assert_eq!(tokens, expected);
+    let tokens: Vec<Token> = tokenize("1 *** 2").unwrap().iter().map(|s| s.token).collect();
+    assert_eq!(tokens[1..3], [Token::Operator(Power.into()), Token::Operator(Multiply.into())]);
+    assert_eq!(tokenize("é ~").unwrap_err(), error(1, CalcErrorKind::UnknownToken("é".into())));
+    assert_eq!(tokenize("1 ~ 2").unwrap_err(), error(3, CalcErrorKind::UnknownToken("~".into())));
+    assert_eq!(tokenize("1 <= 2").unwrap_err(), error(3, CalcErrorKind::UnknownToken("<".into())));
+    assert_eq!(
+        tokenize("2147483648").unwrap_err(),
+        error(1, CalcErrorKind::InvalidNumber("2147483648".into()))
+    );
+}
+
+#[test]
+fn evaluates_rpn() {
+    assert_eq!(eval_rpn("3 4 + 2 *"), Ok(14));
+    assert_eq!(eval_rpn("2 3 ** neg"), Ok(-8));
+    assert_eq!(eval_rpn("10 3 % 1 2 << |"), Ok(5));
+    assert_eq!(eval_rpn("7"), Ok(7));
+    assert_eq!(eval_rpn("  "), Err(error(1, CalcErrorKind::Empty)));
+    assert_eq!(eval_rpn("1 +"), Err(error(3, CalcErrorKind::MissingOperand(Add.into()))));
+    assert_eq!(eval_rpn("neg"), Err(error(1, CalcErrorKind::MissingOperand(Operator::Negate))));
+    assert_eq!(eval_rpn("1 2 3 +"), Err(error(7, CalcErrorKind::UnusedOperands(1))));
+    assert_eq!(eval_rpn("1 0 /"), Err(error(5, CalcErrorKind::Math(MathError::DivisionByZero))));
+    assert_eq!(eval_rpn("( 1 )"), Err(error(1, CalcErrorKind::UnknownToken("(".into()))));
+    assert_eq!(
+        eval_rpn("1 2 3").unwrap_err().to_string(),
+        "column 5: 2 operands left unused"
+    );
+}
+
+#[test]
+fn infix_follows_precedence_and_associativity() {
+    assert_eq!(eval_infix("1 + 2 * 3"), Ok(7));
+    assert_eq!(eval_infix("(1 + 2) * 3"), Ok(9));
+    assert_eq!(eval_infix("10 - 4 - 3"), Ok(3));
+    assert_eq!(eval_infix("100 / 10 / 5"), Ok(2));
+    assert_eq!(eval_infix("2 ** 3 ** 2"), Ok(512));
+    assert_eq!(eval_infix("-2 ** 2"), Ok(-4));
+    assert_eq!(eval_infix("(-2) ** 2"), Ok(4));
+    assert_eq!(eval_infix("2 ** -1"), Err(error(3, CalcErrorKind::Math(MathError::NegativeExponent))));
+    assert_eq!(eval_infix("--3"), Ok(3));
+    assert_eq!(eval_infix("neg 3 * 2"), Ok(-6));
+    assert_eq!(eval_infix("1 << 2 + 1"), Ok(8));
+    assert_eq!(eval_infix("6 & 3 ^ 1 | 8"), Ok(11));
+    assert_eq!(eval_infix("1 | 2 ^ 3 & 6"), Ok(1));
+    assert_eq!(eval_infix("7 % 4 * 3"), Ok(9));
+
+    assert_eq!(to_rpn("-2 ** 3 + (7 - 1) % 4"), Ok("2 3 ** neg 7 1 - 4 % +".to_string()));
+    assert_eq!(to_rpn("1 - -1"), Ok("1 1 neg -".to_string()));
+}
+
+#[test]
+fn infix_errors_point_at_the_problem() {
+    let parse = |s: &str| eval_infix(s).unwrap_err();
+    assert_eq!(parse(""), error(1, CalcErrorKind::Empty));
+    assert_eq!(parse("1 +"), error(4, CalcErrorKind::ExpectedOperand));
+    assert_eq!(parse("1 + * 2"), error(5, CalcErrorKind::ExpectedOperand));
+    assert_eq!(parse("()"), error(2, CalcErrorKind::ExpectedOperand));
+    assert_eq!(parse("1 2"), error(3, CalcErrorKind::ExpectedOperator));
+    assert_eq!(parse("2 (3)"), error(3, CalcErrorKind::ExpectedOperator));
+    assert_eq!(parse("1 neg 2"), error(3, CalcErrorKind::ExpectedOperator));
+    assert_eq!(parse("(1 + (2)"), error(1, CalcErrorKind::UnmatchedOpen));
+    assert_eq!(parse("1 + 2)"), error(6, CalcErrorKind::UnmatchedClose));
+    assert_eq!(parse("1 + 2 / (3 - 3)"), error(7, CalcErrorKind::Math(MathError::DivisionByZero)));
+    let overflow = CalcErrorKind::Math(MathError::Overflow(Subtract.into()));
+    assert_eq!(parse("-2147483647 - 2"), error(13, overflow));
+    assert_eq!(parse("1 + 2 / (3 - 3)").to_string(), "column 7: division by zero");
+}
+
+// Writes a random, fully parenthesised expression to `text` and returns
+// its value, worked out directly with `run`.
+fn expression(rng: &mut Rng, depth: u32, text: &mut String) -> Result<i32, MathError> {
+    if depth == 0 || rng.next().is_multiple_of(4) {
+        let n = (rng.next() % 20) as i32;
+        text.push_str(&n.to_string());
+        return Ok(n);
+    }
+    text.push('(');
+    let value = if rng.next().is_multiple_of(6) {
+        text.push('-');
+        let x = expression(rng, depth - 1, text);
+        x.and_then(negate)
+    } else {
+        let op = TodoWithNumbers::ALL[rng.next() as usize % 11];
+        let x = expression(rng, depth - 1, text);
+        text.push_str(&format!(" {op} "));
+        let y = expression(rng, depth - 1, text);
+        // Evaluation fails on the leftmost error, as RPN does.
+        x.and_then(|x| y.and_then(|y| op.run(x, y)))
+    };
+    text.push(')');
+    value
+}
+
+#[test]
+fn infix_agrees_with_its_rpn() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let mut evaluated = 0;
+    for _ in 0..2000 {
+        let mut text = String::new();
+        let expected = expression(&mut rng, 5, &mut text);
+        let result = eval_infix(&text).map_err(|err| match err.kind {
+            CalcErrorKind::Math(err) => err,
+            kind => panic!("{text}: {kind:?}"),
+        });
+        assert_eq!(result, expected, "{text}");
+        let rpn = to_rpn(&text).unwrap();
+        let kind = |err: CalcError| err.kind;
+        assert_eq!(eval_rpn(&rpn).map_err(kind), eval_infix(&text).map_err(kind), "{text} = {rpn}");
+        evaluated += result.is_ok() as usize;
+    }
+    assert!(evaluated > 500, "only {evaluated} expressions evaluated");
+}
+
+#[test]
+fn the_repl_evaluates_line_by_line() {
+    let mut calc = Command::new(env!("CARGO_BIN_EXE_calc"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let input = "(3 + 4) * 2\n:rpn\n3 4 + 2 *\n1 2\n:infix\n:show -2 ** 3\n\u{3000}:show 1 +\n\n\
+                 1 / 0\n:what\n:quit\n5\n";
+    calc.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = calc.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "14\n14\nerror: column 3: 1 operand left unused\n2 3 ** neg\n\
+         error: column 11: expected a number or `(`\n\
+         error: column 3: division by zero\nerror: unknown command `:what`, try :help\n"
+    );
+}
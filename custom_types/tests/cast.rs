@@ -0,0 +1,126 @@
+use custom_types::cast::{cast_table, explain_cast, CastKind, CastTo, Primitive};
+
+// `explain_cast` must agree with `as` itself.
+fn kind<From: CastTo<To>, To: Primitive + PartialEq>(value: From) -> CastKind {
+    let explanation = explain_cast::<From, To>(value);
+    assert!(explanation.result == value.cast(), "{explanation}");
+    explanation.kind
+}
+
+#[test]
+fn integer_to_integer() {
+    assert_eq!(kind::<i32, u16>(1000), CastKind::Lossless);
+    assert_eq!(kind::<i32, u8>(1000), CastKind::Truncated);
+    assert_eq!(kind::<i32, i8>(128), CastKind::Truncated);
+    assert_eq!(kind::<i32, i8>(-128), CastKind::Lossless);
+    assert_eq!(kind::<i16, u8>(-1), CastKind::Truncated);
+    assert_eq!(kind::<i8, u8>(-1), CastKind::Wrapped);
+    assert_eq!(kind::<u8, i8>(127), CastKind::Lossless);
+    assert_eq!(kind::<u8, i8>(232), CastKind::Wrapped);
+    assert_eq!(kind::<i8, u32>(-1), CastKind::Wrapped);
+    assert_eq!(kind::<i8, i128>(i8::MIN), CastKind::Lossless);
+    assert_eq!(kind::<u64, i128>(u64::MAX), CastKind::Lossless);
+    assert_eq!(kind::<i128, u128>(i128::MIN), CastKind::Wrapped);
+    assert_eq!(kind::<u128, i128>(u128::MAX), CastKind::Wrapped);
+    assert_eq!(kind::<u128, u64>(u128::MAX), CastKind::Truncated);
+}
+
+#[test]
+fn float_to_integer() {
+    assert_eq!(kind::<f32, u8>(65.4321), CastKind::Truncated);
+    assert_eq!(kind::<f64, i8>(-2.5), CastKind::Truncated);
+    assert_eq!(kind::<f64, u8>(-0.5), CastKind::Truncated);
+    assert_eq!(kind::<f32, u8>(255.0), CastKind::Lossless);
+    assert_eq!(kind::<f32, u8>(-0.0), CastKind::Lossless);
+    assert_eq!(kind::<f32, u8>(300.0), CastKind::Saturated);
+    assert_eq!(kind::<f32, u8>(255.5), CastKind::Truncated);
+    assert_eq!(kind::<f32, u8>(256.0), CastKind::Saturated);
+    assert_eq!(kind::<f32, u8>(-100.0), CastKind::Saturated);
+    assert_eq!(kind::<f64, i64>(f64::INFINITY), CastKind::Saturated);
+    assert_eq!(kind::<f64, u128>(1e40), CastKind::Saturated);
+    assert_eq!(kind::<f32, u128>(f32::MAX), CastKind::Lossless);
+    assert_eq!(kind::<f32, u8>(f32::NAN), CastKind::NanToZero);
+    assert_eq!(kind::<f64, i32>(-f64::NAN), CastKind::NanToZero);
+}
+
+#[test]
+fn to_and_between_floats() {
+    assert_eq!(kind::<i32, f32>(16_777_216), CastKind::Lossless);
+    assert_eq!(kind::<i32, f32>(16_777_217), CastKind::PrecisionLost);
+    assert_eq!(kind::<i32, f32>(i32::MIN), CastKind::Lossless);
+    assert_eq!(kind::<u64, f64>(u64::MAX), CastKind::PrecisionLost);
+    assert_eq!(kind::<u128, f32>(u128::MAX), CastKind::PrecisionLost);
+    assert_eq!(kind::<u128, f64>(1 << 127), CastKind::Lossless);
+    assert_eq!(kind::<f32, f64>(0.1), CastKind::Lossless);
+    assert_eq!(kind::<f64, f32>(0.1), CastKind::PrecisionLost);
+    assert_eq!(kind::<f64, f32>(0.5), CastKind::Lossless);
+    assert_eq!(kind::<f64, f32>(1e300), CastKind::PrecisionLost);
+    assert_eq!(kind::<f64, f32>(f64::INFINITY), CastKind::Lossless);
+    assert_eq!(kind::<f64, f32>(1e-50), CastKind::PrecisionLost);
+    let nan = explain_cast::<f64, f32>(f64::NAN);
+    assert!(nan.result.is_nan());
+    assert_eq!(nan.kind, CastKind::Lossless);
+}
+
+#[test]
+fn explanations_show_the_bits() {
+    assert_eq!(
+        explain_cast::<i32, u8>(1000).to_string(),
+        "1000_i32 as u8 = 232 (truncated): 1000 is outside u8's range 0..=255; \
+         keeping the low 8 of 32 bits turns 0x000003e8 into 0xe8"
+    );
+    assert_eq!(
+        explain_cast::<u8, i8>(232).to_string(),
+        "232_u8 as i8 = -24 (wrapped): the 8 bits 0xe8 stay the same, but as i8 the top bit counts -2^7"
+    );
+    assert_eq!(
+        explain_cast::<i16, i32>(-2).reason,
+        "sign-extends 0xfffe to 0xfffffffe, which keeps the value"
+    );
+    assert_eq!(
+        explain_cast::<u16, i32>(0xfffe).reason,
+        "zero-extends 0xfffe to 0x0000fffe, which keeps the value"
+    );
+    assert_eq!(
+        explain_cast::<f32, u8>(300.0).reason,
+        "300.0 is above u8's range 0..=255, so the cast saturates to 255"
+    );
+    assert_eq!(
+        explain_cast::<u32, f32>(u32::MAX).reason,
+        "4294967295 needs 32 significant bits and f32 has 24, so it rounds to the nearest f32"
+    );
+}
+
+#[test]
+fn the_table_covers_every_pair() {
+    let table = cast_table();
+    assert_eq!(table.types.len(), 14);
+    for from in &table.types {
+        for to in &table.types {
+            let kinds = table.kinds(from, to).unwrap();
+            assert!(!kinds.is_empty(), "{from} -> {to}");
+            if from == to {
+                assert_eq!(kinds, [CastKind::Lossless], "{from}");
+            }
+        }
+    }
+    assert_eq!(table.kinds("u8", "u16"), Some(&[CastKind::Lossless][..]));
+    assert_eq!(
+        table.kinds("i8", "u64"),
+        Some(&[CastKind::Lossless, CastKind::Wrapped][..])
+    );
+    assert_eq!(
+        table.kinds("f64", "i32"),
+        Some(&[CastKind::Lossless, CastKind::Truncated, CastKind::Saturated, CastKind::NanToZero][..])
+    );
+    assert_eq!(
+        table.kinds("u64", "f64"),
+        Some(&[CastKind::Lossless, CastKind::PrecisionLost][..])
+    );
+    assert_eq!(table.kinds("u8", "char"), None);
+
+    let text = table.to_string();
+    assert!(text.starts_with("    as    u8   u16"), "{text}");
+    assert!(text.contains("\n    u8     =     =     =     =     =     =     W"), "{text}");
+    assert!(text.ends_with("\nP: precision lost"), "{text}");
+}
@@ -0,0 +1,16 @@
+// Geometry fixtures shared by the integration tests.
+
+use custom_types::geometry::Point;
+use testutil::Rng;
+
+pub fn p(x: f64, y: f64) -> Point {
+    Point::new(x, y)
+}
+
+// A point with whole-number coordinates in `-range..=range`. They're exact in
+// binary, so areas come out exact, and shared edges and ties turn up often.
+#[allow(dead_code)] // The SVG tests only draw fixed shapes.
+pub fn random_point(rng: &mut Rng, range: u32) -> Point {
+    let mut coordinate = || rng.below(2 * range as u64 + 1) as f64 - range as f64;
+    p(coordinate(), coordinate())
+}
@@ -0,0 +1,160 @@
+use std::cell::RefCell;
+
+use custom_types::web::{clicks_within, format_session, parse_session, Dispatcher, EventKind, WebEvent};
+
+fn click(x: i64, y: i64) -> WebEvent {
+    WebEvent::Click { x, y }
+}
+
+#[test]
+fn handlers_only_see_their_variant() {
+    let seen = RefCell::new(Vec::new());
+    let mut bus = Dispatcher::new();
+    bus.on(EventKind::KeyPress, 0, |event, _| seen.borrow_mut().push(format!("key: {event}")));
+    bus.on(EventKind::Paste, 0, |event, _| seen.borrow_mut().push(format!("paste: {event}")));
+
+    bus.emit(WebEvent::KeyPress('a'));
+    bus.emit(click(1, 2));
+    bus.emit(WebEvent::Paste("x".into()));
+    let deliveries = bus.run();
+
+    assert_eq!(deliveries.len(), 3);
+    assert!(deliveries[1].handlers.is_empty());
+    drop(bus);
+    assert_eq!(seen.into_inner(), ["key: key a", "paste: paste \"x\""]);
+}
+
+#[test]
+fn priorities_decide_the_order() {
+    let order = RefCell::new(Vec::new());
+    let mut bus = Dispatcher::new();
+    let low = bus.on_any(-5, |_, _| order.borrow_mut().push("low"));
+    let first = bus.on_any(0, |_, _| order.borrow_mut().push("first"));
+    let high = bus.on_any(10, |_, _| order.borrow_mut().push("high"));
+    let second = bus.on_any(0, |_, _| order.borrow_mut().push("second"));
+
+    bus.emit(WebEvent::PageLoad);
+    let delivery = bus.step().unwrap();
+    assert_eq!(delivery.handlers, [high, first, second, low]);
+    assert!(!delivery.stopped);
+    assert_eq!(bus.step(), None);
+    drop(bus);
+    assert_eq!(order.into_inner(), ["high", "first", "second", "low"]);
+}
+
+#[test]
+fn stop_propagation_skips_lower_priorities() {
+    let handled = RefCell::new(Vec::new());
+    let mut bus = Dispatcher::new();
+    bus.on(EventKind::Click, 0, |event, _| handled.borrow_mut().push(format!("page {event}")));
+    let button = bus.on_match(clicks_within(0..=10, 0..=10), 5, |event, context| {
+        handled.borrow_mut().push(format!("button {event}"));
+        context.stop_propagation();
+    });
+
+    bus.emit(click(10, 0));
+    bus.emit(click(11, 0));
+    let deliveries = bus.run();
+    assert_eq!(deliveries[0].handlers, [button]);
+    assert!(deliveries[0].stopped);
+    assert!(!deliveries[1].stopped);
+    drop(bus);
+    assert_eq!(handled.into_inner(), ["button click 10 0", "page click 11 0"]);
+}
+
+#[test]
+fn removed_handlers_stop_running() {
+    let count = RefCell::new(0);
+    let mut bus = Dispatcher::new();
+    let id = bus.on(EventKind::PageLoad, 0, |_, _| *count.borrow_mut() += 1);
+    bus.emit(WebEvent::PageLoad);
+    bus.run();
+    assert!(bus.remove(id));
+    assert!(!bus.remove(id));
+    bus.emit(WebEvent::PageLoad);
+    bus.run();
+    drop(bus);
+    assert_eq!(count.into_inner(), 1);
+}
+
+#[test]
+fn emitted_events_queue_behind_pending_ones() {
+    let mut bus = Dispatcher::new();
+    bus.on(EventKind::KeyPress, 0, |event, context| {
+        if *event == WebEvent::KeyPress('\n') {
+            context.emit(WebEvent::Paste("submitted".into()));
+        }
+    });
+    bus.emit(WebEvent::KeyPress('\n'));
+    bus.emit(WebEvent::KeyPress('x'));
+    assert_eq!(bus.pending(), 2);
+
+    let events: Vec<WebEvent> = bus.run().into_iter().map(|d| d.event).collect();
+    assert_eq!(
+        events,
+        [WebEvent::KeyPress('\n'), WebEvent::KeyPress('x'), WebEvent::Paste("submitted".into())]
+    );
+    assert_eq!(bus.pending(), 0);
+}
+
+#[test]
+fn the_log_replays_the_session() {
+    let seen = RefCell::new(Vec::new());
+    let mut bus = Dispatcher::new();
+    bus.on_any(0, |event, _| seen.borrow_mut().push(event.to_string()));
+    for event in [WebEvent::PageLoad, click(3, 4), WebEvent::KeyPress(' '), WebEvent::PageUnload] {
+        bus.emit(event);
+    }
+    bus.run();
+    let file = format_session(bus.log());
+    assert_eq!(bus.take_log().len(), 4);
+    assert!(bus.log().is_empty());
+    drop(bus);
+    let first_run = seen.take();
+
+    let mut replay = Dispatcher::new();
+    replay.on_any(0, |event, _| seen.borrow_mut().push(event.to_string()));
+    let deliveries = replay.replay(parse_session(&file).unwrap());
+    assert_eq!(deliveries.len(), 4);
+    drop(replay);
+    assert_eq!(seen.into_inner(), first_run);
+}
+
+// Handlers that emit events of their own, the way a page would.
+fn emitting_bus<'a>(seen: &'a RefCell<Vec<String>>) -> Dispatcher<'a> {
+    let mut bus = Dispatcher::new();
+    bus.on_match(clicks_within(0..=10, 0..=10), 10, |_, context| {
+        context.emit(WebEvent::PageUnload);
+        context.stop_propagation();
+    });
+    bus.on(EventKind::KeyPress, 0, |event, context| {
+        if *event == WebEvent::KeyPress('\n') {
+            context.emit(WebEvent::Paste("submitted".into()));
+        }
+    });
+    bus.on_any(-1, |event, _| seen.borrow_mut().push(event.to_string()));
+    bus
+}
+
+#[test]
+fn replaying_the_log_into_emitting_handlers_gives_the_same_deliveries() {
+    let seen = RefCell::new(Vec::new());
+    let mut bus = emitting_bus(&seen);
+    for event in [WebEvent::PageLoad, WebEvent::KeyPress('\n'), click(20, 80), click(5, 5)] {
+        bus.emit(event);
+    }
+    let first = bus.run();
+    assert_eq!(first.len(), 6);
+    // Only what came from outside is logged.
+    let file = format_session(bus.log());
+    assert_eq!(file, "load\nkey \"\\n\"\nclick 20 80\nclick 5 5\n");
+    drop(bus);
+    let first_seen = seen.take();
+
+    let mut replay = emitting_bus(&seen);
+    let again = replay.replay(parse_session(&file).unwrap());
+    assert_eq!(again, first);
+    assert_eq!(replay.log(), parse_session(&file).unwrap());
+    drop(replay);
+    assert_eq!(seen.into_inner(), first_seen);
+}
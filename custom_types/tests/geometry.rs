@@ -0,0 +1,186 @@
+mod common;
+
+use std::f64::consts::PI;
+
+use common::{p, random_point};
+use custom_types::geometry::{Circle, GeometryError, HasArea, Rectangle, Shape, Triangle};
+use testutil::Rng;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+}
+
+#[test]
+fn points() {
+    assert_eq!(p(3.0, 4.0).length(), 5.0);
+    assert_eq!(p(1.0, 1.0).distance(p(4.0, 5.0)), 5.0);
+    assert_eq!(p(1.0, 2.0) + p(3.0, 4.0) - p(1.0, 1.0), p(3.0, 5.0));
+    assert_eq!(-p(1.0, -2.0) * 2.0, p(-2.0, 4.0));
+    assert_eq!(p(1.0, 0.0).cross(p(0.0, 1.0)), 1.0);
+    assert_eq!(p(1.0, 2.0).dot(p(3.0, 4.0)), 11.0);
+    assert_eq!(p(1.5, -2.0).to_string(), "(1.5, -2)");
+    assert_eq!(format!("{:.1}", p(1.25, 2.0)), "(1.2, 2.0)");
+}
+
+#[test]
+fn rectangles() {
+    let r = Rectangle::from_corners(p(0.0, 4.0), p(3.0, 0.0));
+    assert_eq!(r, Rectangle::from_size(p(0.0, 0.0), 3.0, 4.0));
+    assert_eq!(r, Rectangle::from_size(p(3.0, 4.0), -3.0, -4.0));
+    assert_eq!(Rectangle::try_from_corners(p(3.0, 0.0), p(0.0, 4.0)), Ok(r));
+    let not_finite = Err(GeometryError::NotFinite);
+    assert_eq!(Rectangle::try_from_corners(p(f64::NAN, 0.0), p(1.0, 1.0)), not_finite);
+    assert_eq!(Rectangle::try_from_corners(p(0.0, 0.0), p(1.0, f64::INFINITY)), not_finite);
+    assert_eq!(Rectangle::try_from_size(p(0.0, 0.0), 3.0, 4.0), Ok(r));
+    // Finite arguments can still overflow to an infinite corner.
+    assert_eq!(Rectangle::try_from_size(p(f64::MAX, 0.0), f64::MAX, 1.0), not_finite);
+    assert_eq!((r.min(), r.max()), (p(0.0, 0.0), p(3.0, 4.0)));
+    assert_eq!(r.area(), 12.0);
+    assert_eq!(r.perimeter(), 14.0);
+    assert_eq!(r.centroid(), p(1.5, 2.0));
+    assert_eq!(r.bounding_box(), r);
+    assert!(r.contains(p(3.0, 4.0)));
+    assert!(r.contains(p(1.0, 1.0)));
+    assert!(!r.contains(p(3.0001, 1.0)));
+    assert_eq!(r.corners(), [p(0.0, 0.0), p(3.0, 0.0), p(3.0, 4.0), p(0.0, 4.0)]);
+
+    let mut moved = r;
+    moved.translate(1.0, -1.0);
+    assert_eq!((moved.min(), moved.max()), (p(1.0, -1.0), p(4.0, 3.0)));
+    assert_eq!(Rectangle::around([p(1.0, 5.0), p(-2.0, 0.0), p(0.0, 7.0)]),
+               Some(Rectangle::from_corners(p(-2.0, 0.0), p(1.0, 7.0))));
+    assert_eq!(Rectangle::around([]), None);
+}
+
+#[test]
+fn rectangle_intersection_and_union() {
+    let a = Rectangle::from_corners(p(0.0, 0.0), p(3.0, 4.0));
+    let b = Rectangle::from_corners(p(2.0, 1.0), p(6.0, 5.0));
+    assert_eq!(a.intersection(&b), Some(Rectangle::from_corners(p(2.0, 1.0), p(3.0, 4.0))));
+    assert_eq!(a.union(&b), Rectangle::from_corners(p(0.0, 0.0), p(6.0, 5.0)));
+
+    // Touching edges share a line; apart shares nothing.
+    let touching = Rectangle::from_corners(p(3.0, 0.0), p(5.0, 2.0));
+    assert_eq!(a.intersection(&touching).map(|r| r.area()), Some(0.0));
+    let apart = Rectangle::from_corners(p(10.0, 10.0), p(11.0, 11.0));
+    assert!(!a.intersects(&apart));
+    assert_eq!(a.intersection(&apart), None);
+
+    let inner = Rectangle::from_corners(p(1.0, 1.0), p(2.0, 2.0));
+    assert!(a.contains_rectangle(&inner));
+    assert!(!inner.contains_rectangle(&a));
+    assert_eq!(a.intersection(&inner), Some(inner));
+    assert_eq!(a.union(&inner), a);
+}
+
+#[test]
+fn rectangle_intersection_properties() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..2000 {
+        let a = Rectangle::from_corners(random_point(&mut rng, 10), random_point(&mut rng, 10));
+        let b = Rectangle::from_corners(random_point(&mut rng, 10), random_point(&mut rng, 10));
+        let union = a.union(&b);
+        assert_eq!(union, b.union(&a));
+        assert!(union.contains_rectangle(&a) && union.contains_rectangle(&b));
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        match a.intersection(&b) {
+            Some(shared) => {
+                assert!(a.contains_rectangle(&shared) && b.contains_rectangle(&shared));
+                assert!(shared.area() <= a.area().min(b.area()));
+            }
+            None => assert!(!a.intersects(&b)),
+        }
+        let q = random_point(&mut rng, 10);
+        assert_eq!(a.contains(q) && b.contains(q), a.intersection(&b).is_some_and(|s| s.contains(q)));
+    }
+}
+
+#[test]
+fn circles() {
+    let c = Circle::new(p(3.0, 4.0), 2.0).unwrap();
+    assert_eq!(c.area(), 4.0 * PI);
+    assert_eq!(c.perimeter(), 4.0 * PI);
+    assert_eq!(c.centroid(), p(3.0, 4.0));
+    assert_eq!(c.bounding_box(), Rectangle::from_corners(p(1.0, 2.0), p(5.0, 6.0)));
+    assert!(c.contains(p(5.0, 4.0)));
+    assert!(!c.contains(p(4.5, 5.5)));
+    assert_eq!(Circle::new(p(0.0, 0.0), 0.0).unwrap().area(), 0.0);
+    assert_eq!(Circle::new(p(0.0, 0.0), -1.0), Err(GeometryError::NegativeRadius(-1.0)));
+    assert_eq!(Circle::new(p(f64::NAN, 0.0), 1.0), Err(GeometryError::NotFinite));
+}
+
+#[test]
+fn triangles() {
+    let t = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0)).unwrap();
+    assert_eq!(t.area(), 6.0);
+    assert_eq!(t.perimeter(), 12.0);
+    assert_eq!(t.sides(), [5.0, 3.0, 4.0]);
+    assert_eq!(t.centroid(), p(4.0 / 3.0, 1.0));
+    assert_eq!(t.bounding_box(), Rectangle::from_corners(p(0.0, 0.0), p(4.0, 3.0)));
+    assert!(t.contains(p(1.0, 1.0)));
+    assert!(t.contains(p(2.0, 1.5)));
+    assert!(!t.contains(p(2.1, 1.5)));
+    // Clockwise vertices work the same.
+    let clockwise = Triangle::new(p(0.0, 0.0), p(0.0, 3.0), p(4.0, 0.0)).unwrap();
+    assert_eq!(clockwise.area(), 6.0);
+    assert!(clockwise.contains(p(1.0, 1.0)));
+    assert!(!clockwise.contains(p(-0.1, 1.0)));
+
+    assert_eq!(Triangle::new(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)), Err(GeometryError::Collinear));
+    assert_eq!(Triangle::new(p(0.0, 0.0), p(1.0, f64::INFINITY), p(2.0, 2.0)), Err(GeometryError::NotFinite));
+}
+
+#[test]
+fn triangles_from_sides() {
+    let t = Triangle::from_sides(4.0, 5.0, 6.0).unwrap();
+    // Heron's formula, for sides that do make a triangle.
+    let k: f64 = 7.5;
+    assert!(close(t.area(), (k * (k - 4.0) * (k - 5.0) * (k - 6.0)).sqrt()));
+    let [a, b, c] = t.sides();
+    assert!(close(a, 4.0) && close(b, 5.0) && close(c, 6.0));
+    assert!(close(Triangle::from_sides(3.0, 4.0, 5.0).unwrap().area(), 6.0));
+
+    for (a, b, c) in [(1.0, 2.0, 10.0), (1.0, 2.0, 3.0), (10.0, 1.0, 2.0), (0.0, 1.0, 1.0), (-3.0, 4.0, 5.0)] {
+        assert_eq!(Triangle::from_sides(a, b, c), Err(GeometryError::ImpossibleSides { a, b, c }));
+    }
+    assert_eq!(Triangle::from_sides(f64::NAN, 1.0, 1.0), Err(GeometryError::NotFinite));
+    assert_eq!(
+        Triangle::from_sides(1.0, 2.0, 10.0).unwrap_err().to_string(),
+        "sides 1, 2 and 10 don't make a triangle"
+    );
+}
+
+#[test]
+fn shapes_contain_their_centroid_and_stay_in_their_bounds() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..2000 {
+        let [a, b, c] = [(); 3].map(|_| random_point(&mut rng, 50));
+        let Ok(triangle) = Triangle::new(a, b, c) else {
+            continue;
+        };
+        let circle = Circle::new(random_point(&mut rng, 50), rng.unit() * 20.0).unwrap();
+        let shapes: [&dyn Shape; 2] = [&triangle, &circle];
+        for shape in shapes {
+            assert!(shape.contains(shape.centroid()));
+            let q = random_point(&mut rng, 60);
+            if shape.contains(q) {
+                assert!(shape.bounding_box().contains(q));
+            }
+        }
+        // Points the three triangles they make with the edges don't add up
+        // to the whole are outside, and blends of the vertices are inside.
+        let [a, b, c] = triangle.vertices();
+        let q = random_point(&mut rng, 50);
+        let parts: f64 = [(a, b), (b, c), (c, a)]
+            .iter()
+            .map(|&(from, to)| (to - from).cross(q - from).abs() / 2.0)
+            .sum();
+        if !close(parts, triangle.area()) {
+            assert!(!triangle.contains(q));
+        }
+        let weights = [rng.unit() + 0.01, rng.unit() + 0.01, 0.01];
+        let total: f64 = weights.iter().sum();
+        let blend = a * (weights[0] / total) + b * (weights[1] / total) + c * (weights[2] / total);
+        assert!(triangle.contains(blend), "{blend} in {triangle:?}");
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use custom_types::list::{List, SharedList};
+
+// Counts how many values have been dropped.
+struct Tracked<'a>(&'a Cell<usize>);
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn prepend_append_and_len() {
+    let list = List::new().prepend(1).prepend(2).prepend(3).append(5);
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.to_string(), "[3, 2, 1, 5]");
+    assert_eq!(format!("{list:?}"), "[3, 2, 1, 5]");
+    assert_eq!(format!("{:?}", List::from_iter(["a"])), "[\"a\"]");
+    assert_eq!(List::<u8>::new().to_string(), "[]");
+    assert_eq!(format!("{:.1}", List::from_iter([1.0, 2.25])), "[1.0, 2.2]");
+}
+
+#[test]
+fn push_pop_and_reverse() {
+    let mut list: List<i32> = List::new();
+    assert!(list.is_empty());
+    assert_eq!(list.pop_front(), None);
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(list.front(), Some(&1));
+    *list.front_mut().unwrap() = 10;
+    list.reverse();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 2, 10]);
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.len(), 2);
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+}
+
+#[test]
+fn iterators() {
+    let mut list: List<i32> = (1..=4).collect();
+    assert_eq!(list.iter().len(), 4);
+    for value in &mut list {
+        *value *= 10;
+    }
+    assert_eq!((&list).into_iter().sum::<i32>(), 100);
+
+    list.extend([50, 60]);
+    assert_eq!(list.len(), 6);
+    assert_eq!(list.iter().last(), Some(&60));
+
+    let mut into = list.clone().into_iter();
+    assert_eq!(into.len(), 6);
+    assert_eq!(into.next(), Some(10));
+    assert_eq!(into.collect::<Vec<_>>(), [20, 30, 40, 50, 60]);
+
+    assert_eq!(list, (1..=6).map(|n| n * 10).collect());
+    assert_ne!(list, (1..=5).map(|n| n * 10).collect());
+}
+
+#[test]
+fn long_lists_do_not_overflow_the_stack() {
+    // Run on a thread with a small stack, where recursion would fail quickly.
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut list: List<u32> = (0..1_000_000).collect();
+            list = list.append(7);
+            let copy = list.clone();
+            assert_eq!(copy.len(), 1_000_001);
+            assert!(list == copy);
+            drop(list);
+
+            let shared: SharedList<u32> = (0..1_000_000).collect();
+            let longer = shared.prepend(1);
+            drop(shared);
+            assert_eq!(longer.len(), 1_000_001);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn dropping_a_list_drops_every_value() {
+    let dropped = Cell::new(0);
+    let list: List<Tracked> = (0..10).map(|_| Tracked(&dropped)).collect();
+    drop(list);
+    assert_eq!(dropped.get(), 10);
+}
+
+#[test]
+fn shared_lists_share_their_tails() {
+    let tail: SharedList<&str> = ["c", "d"].into_iter().collect();
+    let a = tail.prepend("a");
+    let b = tail.prepend("b");
+    assert_eq!(a.to_string(), "[a, c, d]");
+    assert_eq!(format!("{b:?}"), "[\"b\", \"c\", \"d\"]");
+    assert!(a.tail().ptr_eq(&tail));
+    assert!(b.tail().ptr_eq(&a.tail()));
+    assert_eq!(a.tail(), b.tail());
+    assert_ne!(a, b);
+    assert_eq!(a.front(), Some(&"a"));
+    assert_eq!(a.len(), 3);
+    assert_eq!(SharedList::<u8>::new().tail().len(), 0);
+
+    let copy = a.clone();
+    assert!(copy.ptr_eq(&a));
+    assert_eq!(copy.iter().len(), 3);
+}
+
+#[test]
+fn dropping_a_shared_list_keeps_nodes_still_in_use() {
+    let dropped = Cell::new(0);
+    let tail: SharedList<Tracked> = (0..3).map(|_| Tracked(&dropped)).collect();
+    let a = tail.prepend(Tracked(&dropped));
+    let b = tail.prepend(Tracked(&dropped));
+    drop(tail);
+    drop(a);
+    // Only `a`'s own node is gone; `b` still needs the shared tail.
+    assert_eq!(dropped.get(), 1);
+    assert_eq!(b.len(), 4);
+    drop(b);
+    assert_eq!(dropped.get(), 5);
+
+    // The values themselves can be shared too.
+    let value = Rc::new(1);
+    let list: SharedList<Rc<i32>> = std::iter::repeat_n(Rc::clone(&value), 3).collect();
+    assert_eq!(Rc::strong_count(&value), 4);
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,168 @@
+mod common;
+
+use common::{p, random_point};
+use custom_types::geometry::{
+    convex_hull, GeometryError, HasArea, Point, Polygon, Rectangle, Shape, Triangle,
+};
+use testutil::Rng;
+
+fn polygon(corners: &[(f64, f64)]) -> Polygon {
+    Polygon::new(corners.iter().map(|&(x, y)| p(x, y)).collect()).unwrap()
+}
+
+#[test]
+fn needs_three_finite_vertices() {
+    assert_eq!(Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.0)]), Err(GeometryError::TooFewVertices(2)));
+    assert_eq!(
+        Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(f64::NAN, 1.0)]),
+        Err(GeometryError::NotFinite)
+    );
+}
+
+#[test]
+fn shoelace_area() {
+    let room = polygon(&[(0.0, 0.0), (6.0, 0.0), (6.0, 2.5), (2.5, 2.5), (2.5, 5.0), (0.0, 5.0)]);
+    assert_eq!(room.area(), 21.25);
+    assert_eq!(room.signed_area(), 21.25);
+    assert!(room.is_counterclockwise());
+    assert_eq!(room.reversed().signed_area(), -21.25);
+    assert_eq!(room.reversed().area(), 21.25);
+    assert_eq!(room.perimeter(), 22.0);
+    assert_eq!(room.bounding_box(), Rectangle::from_corners(p(0.0, 0.0), p(6.0, 5.0)));
+
+    let rectangle = Rectangle::from_corners(p(1.0, 1.0), p(4.0, 3.0));
+    assert_eq!(Polygon::from(rectangle).area(), rectangle.area());
+    assert_eq!(Polygon::from(rectangle).centroid(), rectangle.centroid());
+    let triangle = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0)).unwrap();
+    assert_eq!(Polygon::from(triangle).area(), 6.0);
+    assert_eq!(Polygon::from(triangle).centroid(), triangle.centroid());
+}
+
+#[test]
+fn centroid_of_an_l_shape() {
+    // Two rectangles: 4x1 centred at (2, 0.5) and 1x3 centred at (0.5, 2.5).
+    let l = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (1.0, 1.0), (1.0, 4.0), (0.0, 4.0)]);
+    assert_eq!(l.area(), 7.0);
+    let expected = (p(2.0, 0.5) * 4.0 + p(0.5, 2.5) * 3.0) * (1.0 / 7.0);
+    assert!(l.centroid().distance(expected) < 1e-12);
+    assert!(!l.contains(l.centroid()));
+    // With no area, the vertices are averaged.
+    assert_eq!(polygon(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]).centroid(), p(2.0, 0.0));
+}
+
+#[test]
+fn convexity_and_self_intersection() {
+    let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    assert!(square.is_convex() && square.is_simple());
+    assert!(square.reversed().is_convex());
+    // A vertex in the middle of an edge doesn't stop it being convex.
+    assert!(polygon(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]).is_convex());
+
+    let arrow = polygon(&[(0.0, 0.0), (2.0, 1.0), (4.0, 0.0), (2.0, 4.0)]);
+    assert!(!arrow.is_convex() && arrow.is_simple());
+
+    let bowtie = polygon(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+    assert_eq!(bowtie.self_intersections(), vec![(0, 2)]);
+    assert!(!bowtie.is_convex());
+    // The halves cancel out in the shoelace sum.
+    assert_eq!(bowtie.area(), 0.0);
+
+    // Every turn is a left turn, but it goes round twice.
+    let star: Vec<Point> = (0..5)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * (2 * i) as f64 / 5.0;
+            p(angle.cos(), angle.sin())
+        })
+        .collect();
+    let star = Polygon::new(star).unwrap();
+    assert!(!star.is_convex());
+    assert_eq!(star.self_intersections().len(), 5);
+
+    // Folding back along the previous edge counts as meeting it, and the
+    // next edge then starts on edge 1 as well.
+    let spike = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (2.0, 1.0), (0.0, 1.0)]);
+    assert_eq!(spike.self_intersections(), vec![(1, 2), (1, 3)]);
+    // So does touching another edge at a vertex.
+    let pinched = polygon(&[(0.0, 0.0), (4.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+    assert!(!pinched.is_simple());
+}
+
+#[test]
+fn winding_numbers() {
+    let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    assert_eq!(square.winding_number(p(1.0, 1.0)), 1);
+    assert_eq!(square.reversed().winding_number(p(1.0, 1.0)), -1);
+    assert_eq!(square.winding_number(p(3.0, 1.0)), 0);
+    assert!(square.contains(p(2.0, 1.0)));
+    assert!(square.contains(p(0.0, 0.0)));
+    assert!(!square.contains(p(2.0, 2.5)));
+
+    // Twice round the same square.
+    let twice = polygon(&[
+        (0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0),
+        (0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0),
+    ]);
+    assert_eq!(twice.winding_number(p(1.0, 1.0)), 2);
+
+    // The centre of a pentagram is wound round twice, its points once.
+    let star: Vec<Point> = (0..5)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * (2 * i) as f64 / 5.0;
+            p(10.0 * angle.cos(), 10.0 * angle.sin())
+        })
+        .collect();
+    let star = Polygon::new(star).unwrap();
+    assert_eq!(star.winding_number(p(0.0, 0.0)), 2);
+    assert_eq!(star.winding_number(p(8.0, 0.0)), 1);
+    assert_eq!(star.winding_number(p(11.0, 0.0)), 0);
+}
+
+#[test]
+fn hull_of_points() {
+    let points = [p(0.0, 0.0), p(2.0, 0.0), p(1.0, 1.0), p(2.0, 2.0), p(0.0, 2.0), p(1.0, 0.0), p(2.0, 2.0)];
+    let hull = convex_hull(&points).unwrap();
+    assert_eq!(hull.vertices(), [p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+    assert!(hull.is_convex() && hull.is_counterclockwise());
+    assert_eq!(convex_hull(&[p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)]), None);
+    assert_eq!(convex_hull(&[p(1.0, 1.0); 4]), None);
+    assert_eq!(convex_hull(&[]), None);
+}
+
+#[test]
+fn hull_properties() {
+    let mut rng = Rng(0x853C_49E6_748F_EA9B);
+    for _ in 0..500 {
+        let count = 3 + rng.below(40);
+        let points: Vec<Point> = (0..count).map(|_| random_point(&mut rng, 50)).collect();
+        let Some(hull) = convex_hull(&points) else {
+            continue;
+        };
+        assert!(hull.is_convex(), "{hull:?}");
+        assert!(hull.is_counterclockwise());
+        assert!(points.iter().all(|&q| hull.contains(q)));
+        assert!(hull.vertices().iter().all(|v| points.contains(v)));
+        // The hull of the hull is itself.
+        assert_eq!(convex_hull(hull.vertices()).as_ref(), Some(&hull));
+    }
+}
+
+#[test]
+fn winding_agrees_with_triangles() {
+    // A fan of triangles from a hull's first vertex covers the hull, so a
+    // point is in the hull exactly when it is in one of them.
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..300 {
+        let points: Vec<Point> = (0..12).map(|_| random_point(&mut rng, 25)).collect();
+        let Some(hull) = convex_hull(&points) else {
+            continue;
+        };
+        let v = hull.vertices();
+        let fan: Vec<Triangle> =
+            (1..v.len() - 1).map(|i| Triangle::new(v[0], v[i], v[i + 1]).unwrap()).collect();
+        assert_eq!(fan.iter().map(|t| t.area()).sum::<f64>(), hull.area());
+        for _ in 0..20 {
+            let q = random_point(&mut rng, 30);
+            assert_eq!(hull.contains(q), fan.iter().any(|t| t.contains(q)), "{q}");
+        }
+    }
+}
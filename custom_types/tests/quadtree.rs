@@ -0,0 +1,180 @@
+mod common;
+
+use common::{p, random_point};
+use custom_types::geometry::{ItemId, Point, Quadtree, Rectangle};
+use testutil::Rng;
+
+fn rect(x: f64, y: f64, width: f64, height: f64) -> Rectangle {
+    Rectangle::from_size(p(x, y), width, height)
+}
+
+// A whole-number point within `margin` of the 1000 by 1000 square the trees
+// cover, so distances tie often.
+fn random_spot(rng: &mut Rng, margin: u32) -> Point {
+    p(500.0, 500.0) + random_point(rng, 500 + margin)
+}
+
+// Mostly small rectangles, some points and a few large ones.
+fn random_rectangle(rng: &mut Rng) -> Rectangle {
+    let size = match rng.below(10) {
+        0 => 0,
+        1 => 300,
+        _ => 20,
+    };
+    let (w, h) = if size == 0 { (0, 0) } else { (rng.below(size), rng.below(size)) };
+    Rectangle::from_size(random_spot(rng, 0), w as f64, h as f64)
+}
+
+fn scan_query(items: &[(ItemId, Rectangle)], area: &Rectangle) -> Vec<ItemId> {
+    let mut found: Vec<ItemId> =
+        items.iter().filter(|(_, r)| r.intersects(area)).map(|&(id, _)| id).collect();
+    found.sort();
+    found
+}
+
+fn scan_nearest(items: &[(ItemId, Rectangle)], point: Point, k: usize) -> Vec<(ItemId, f64)> {
+    let mut all: Vec<(ItemId, f64)> = items.iter().map(|&(id, r)| (id, r.distance_to(point))).collect();
+    all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    all.truncate(k);
+    all
+}
+
+#[test]
+fn distance_to_rectangles() {
+    let r = rect(0.0, 0.0, 4.0, 2.0);
+    assert_eq!(r.distance_to(p(1.0, 1.0)), 0.0);
+    assert_eq!(r.distance_to(p(4.0, 1.0)), 0.0);
+    assert_eq!(r.distance_to(p(6.0, 1.0)), 2.0);
+    assert_eq!(r.distance_to(p(7.0, 6.0)), 5.0);
+    assert_eq!(r.distance_to(p(-3.0, -4.0)), 5.0);
+}
+
+#[test]
+fn insert_query_and_remove() {
+    let mut tree = Quadtree::with_capacity(rect(0.0, 0.0, 100.0, 100.0), 2, 8);
+    assert!(tree.is_empty());
+    let a = tree.insert(rect(10.0, 10.0, 5.0, 5.0), "a");
+    let b = tree.insert(rect(60.0, 60.0, 10.0, 10.0), "b");
+    let c = tree.insert_point(p(12.0, 12.0), "c");
+    let d = tree.insert(rect(45.0, 45.0, 10.0, 10.0), "d");
+    let e = tree.insert(rect(11.0, 11.0, 1.0, 1.0), "e");
+    assert_eq!(tree.len(), 5);
+    assert!(tree.depth() >= 1);
+    assert_eq!(tree.get(b), Some((&rect(60.0, 60.0, 10.0, 10.0), &"b")));
+
+    assert_eq!(tree.query(&rect(0.0, 0.0, 20.0, 20.0)), vec![a, c, e]);
+    assert_eq!(tree.query(&rect(50.0, 50.0, 1.0, 1.0)), vec![d]);
+    assert_eq!(tree.query(&rect(0.0, 0.0, 100.0, 100.0)), vec![a, b, c, d, e]);
+    assert_eq!(tree.query_point(p(12.0, 12.0)), vec![a, c, e]);
+    assert_eq!(tree.query_point(p(70.0, 70.0)), vec![b]);
+    assert!(tree.query_point(p(99.0, 1.0)).is_empty());
+
+    assert_eq!(tree.remove(c), Some("c"));
+    assert_eq!(tree.remove(c), None);
+    assert_eq!(tree.get(c), None);
+    assert_eq!(tree.query_point(p(12.0, 12.0)), vec![a, e]);
+    assert_eq!(tree.len(), 4);
+    // The freed slot is reused, but not the id: the old one stays dead.
+    let f = tree.insert_point(p(90.0, 90.0), "f");
+    assert_ne!(f, c);
+    assert_eq!(tree.get(c), None);
+    assert_eq!(tree.remove(c), None);
+    assert_eq!(tree.get(f), Some((&rect(90.0, 90.0, 0.0, 0.0), &"f")));
+    assert_eq!(tree.len(), 5);
+
+    for id in [a, b, d, e, f] {
+        assert!(tree.remove(id).is_some());
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.depth(), 0);
+}
+
+#[test]
+fn items_outside_the_root_are_still_found() {
+    let mut tree = Quadtree::with_capacity(rect(0.0, 0.0, 10.0, 10.0), 1, 4);
+    let inside = tree.insert_point(p(1.0, 1.0), ());
+    let outside = tree.insert_point(p(500.0, -20.0), ());
+    let straddling = tree.insert(rect(8.0, 8.0, 5.0, 5.0), ());
+    assert_eq!(tree.query(&rect(400.0, -50.0, 200.0, 100.0)), vec![outside]);
+    assert_eq!(tree.query_point(p(12.0, 12.0)), vec![straddling]);
+    assert_eq!(tree.nearest(p(0.0, 0.0), 1)[0].0, inside);
+    assert_eq!(tree.remove(outside), Some(()));
+}
+
+#[test]
+fn nearest_neighbours() {
+    let mut tree = Quadtree::with_capacity(rect(0.0, 0.0, 100.0, 100.0), 1, 8);
+    let ids: Vec<ItemId> =
+        [(10.0, 10.0), (20.0, 10.0), (50.0, 50.0), (90.0, 90.0)].iter().map(|&(x, y)| tree.insert_point(p(x, y), ())).collect();
+    let nearest = tree.nearest(p(12.0, 10.0), 3);
+    assert_eq!(nearest, vec![(ids[0], 2.0), (ids[1], 8.0), (ids[2], 38.0f64.hypot(40.0))]);
+    assert_eq!(tree.nearest(p(0.0, 0.0), 0), vec![]);
+    assert_eq!(tree.nearest(p(0.0, 0.0), 10).len(), 4);
+    // Inside a rectangle is distance zero.
+    let big = tree.insert(rect(0.0, 60.0, 40.0, 40.0), ());
+    assert_eq!(tree.nearest(p(20.0, 80.0), 1), vec![(big, 0.0)]);
+}
+
+#[test]
+fn matches_a_linear_scan() {
+    let mut rng = Rng(0x853C_49E6_748F_EA9B);
+    for round in 0..20 {
+        let mut tree = Quadtree::with_capacity(rect(0.0, 0.0, 1000.0, 1000.0), 1 + round % 8, 12);
+        let mut items: Vec<(ItemId, Rectangle)> = Vec::new();
+        for step in 0..600 {
+            if step % 5 == 4 && !items.is_empty() {
+                let (id, _) = items.swap_remove(rng.below(items.len() as u64) as usize);
+                assert!(tree.remove(id).is_some());
+            } else {
+                let r = random_rectangle(&mut rng);
+                items.push((tree.insert(r, step), r));
+            }
+        }
+        assert_eq!(tree.len(), items.len());
+        for _ in 0..50 {
+            let area = random_rectangle(&mut rng);
+            assert_eq!(tree.query(&area), scan_query(&items, &area));
+            let point = random_spot(&mut rng, 100);
+            let k = rng.below(12) as usize;
+            assert_eq!(tree.nearest(point, k), scan_nearest(&items, point, k), "{point} k={k}");
+        }
+    }
+}
+
+#[test]
+fn bulk_load_matches_inserting() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    let rectangles: Vec<Rectangle> = (0..3000).map(|_| random_rectangle(&mut rng)).collect();
+    let bulk = Quadtree::bulk_load(rectangles.iter().map(|&r| (r, ())).collect());
+    assert_eq!(bulk.len(), rectangles.len());
+    assert!(bulk.depth() > 2);
+    assert_eq!(bulk.bounds(), Rectangle::around(rectangles.iter().flat_map(|r| [r.min(), r.max()])).unwrap());
+    let items: Vec<(ItemId, Rectangle)> = bulk.iter().map(|(id, &r, _)| (id, r)).collect();
+    assert_eq!(items.iter().map(|&(_, r)| r).collect::<Vec<_>>(), rectangles);
+    for _ in 0..200 {
+        let area = random_rectangle(&mut rng);
+        assert_eq!(bulk.query(&area), scan_query(&items, &area));
+        let point = random_spot(&mut rng, 0);
+        assert_eq!(bulk.nearest(point, 5), scan_nearest(&items, point, 5));
+    }
+
+    // A bulk loaded tree takes inserts and removals like any other.
+    let mut bulk = bulk;
+    let extra = bulk.insert_point(p(5.0, 5.0), ());
+    assert!(bulk.query_point(p(5.0, 5.0)).contains(&extra));
+    assert_eq!(bulk.remove(items[0].0), Some(()));
+
+    let empty: Quadtree<()> = Quadtree::bulk_load(Vec::new());
+    assert!(empty.is_empty());
+    assert!(empty.query(&rect(0.0, 0.0, 1.0, 1.0)).is_empty());
+}
+
+#[test]
+fn many_identical_points_stop_at_max_depth() {
+    let mut tree = Quadtree::with_capacity(rect(0.0, 0.0, 1.0, 1.0), 2, 6);
+    for i in 0..100 {
+        tree.insert_point(p(0.25, 0.25), i);
+    }
+    assert_eq!(tree.depth(), 6);
+    assert_eq!(tree.query_point(p(0.25, 0.25)).len(), 100);
+}
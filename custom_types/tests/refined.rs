@@ -0,0 +1,72 @@
+use std::error::Error;
+use std::num::Wrapping;
+
+use custom_types::refined;
+use custom_types::refined::RefinementError;
+
+refined!(#[derive(Debug, Clone, PartialEq)] pub EvenNumber: i32 where |v| v % 2 == 0);
+refined!(#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)] Percent: u8 in 0..=100);
+refined!(#[derive(Debug)] Username: String where |name| {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase())
+});
+refined!(Celsius: f64 in -273.15..);
+refined!(Port: ::core::primitive::u16 in 1..);
+refined!(#[derive(Debug)] Rank: Wrapping<u8> in Wrapping(1)..=Wrapping(10));
+
+#[test]
+fn accepts_values_passing_the_check() {
+    let even = EvenNumber::try_from(-4).unwrap();
+    assert_eq!(*even, -4);
+    assert_eq!(even.clone().into_inner(), -4);
+    assert_eq!(even.abs(), 4);
+    let percent: Percent = 100u8.try_into().unwrap();
+    assert_eq!(*percent, 100);
+    assert!(Percent::try_from(0).unwrap() < percent);
+    assert_eq!(Username::try_from("ferris".to_string()).unwrap().len(), 6);
+    assert_eq!(*Celsius::try_from(-273.15).unwrap(), -273.15);
+}
+
+#[test]
+fn rejects_the_rest_with_the_value_and_constraint() {
+    assert_eq!(
+        EvenNumber::try_from(5),
+        Err(RefinementError {
+            type_name: "EvenNumber",
+            constraint: "i32 where |v| v % 2 == 0",
+            value: 5,
+        })
+    );
+    let error = Percent::try_from(101).unwrap_err();
+    assert_eq!(error.value, 101);
+    assert_eq!(error.to_string(), "101 is not a valid Percent (u8 in 0..=100)");
+    let error = Username::try_from("Ferris".to_string()).unwrap_err();
+    assert_eq!(error.value, "Ferris");
+    assert!(error.to_string().starts_with("\"Ferris\" is not a valid Username (String where |name|"));
+    assert!(Celsius::try_from(-300.0).is_err());
+    assert!(Celsius::try_from(f64::NAN).is_err());
+
+    let boxed: Box<dyn Error> = Box::new(EvenNumber::try_from(1).unwrap_err());
+    assert_eq!(boxed.to_string(), "1 is not a valid EvenNumber (i32 where |v| v % 2 == 0)");
+}
+
+#[test]
+fn constraint_and_check_are_available_without_a_value() {
+    assert_eq!(Percent::CONSTRAINT, "u8 in 0..=100");
+    assert_eq!(Celsius::CONSTRAINT, "f64 in -273.15..");
+    assert_eq!(Port::CONSTRAINT, "::core::primitive::u16 in 1..");
+    assert_eq!(Rank::CONSTRAINT, "Wrapping<u8> in Wrapping(1)..=Wrapping(10)");
+    assert!(Port::is_valid(&80));
+    assert!(!Rank::is_valid(&Wrapping(0)));
+    assert_eq!(*Rank::try_from(Wrapping(3)).unwrap(), Wrapping(3));
+    assert!(EvenNumber::is_valid(&0));
+    assert!(!EvenNumber::is_valid(&7));
+    assert!(!Username::is_valid(&String::new()));
+}
+
+#[test]
+fn displays_as_the_inner_value() {
+    let percent = Percent::try_from(7).unwrap();
+    assert_eq!(percent.to_string(), "7");
+    assert_eq!(format!("{:>3}%", percent), "  7%");
+    assert_eq!(format!("{:.1}", Celsius::try_from(21.55).unwrap()), "21.6");
+}
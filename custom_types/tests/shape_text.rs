@@ -0,0 +1,199 @@
+mod common;
+
+use common::{p, random_point};
+use custom_types::geometry::{
+    format_shapes, parse_shapes, AnyShape, Circle, GeometryError, ParseShapeError,
+    ParseShapeErrorKind, Point, Polygon, Rectangle, Triangle,
+};
+use testutil::Rng;
+
+fn error(line: usize, column: usize, kind: ParseShapeErrorKind) -> ParseShapeError {
+    ParseShapeError { line, column, kind }
+}
+
+#[test]
+fn formats_shapes() {
+    let circle = Circle::new(p(0.0, 0.0), 100.0).unwrap();
+    assert_eq!(circle.to_string(), "circle r=100 at (0,0)");
+    let rect = Rectangle::from_corners(p(3.0, 4.0), p(0.0, 0.0));
+    assert_eq!(rect.to_string(), "rect (0,0)-(3,4)");
+    let triangle = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, -3.5)).unwrap();
+    assert_eq!(triangle.to_string(), "triangle (0,0) (4,0) (0,-3.5)");
+    let polygon = Polygon::new(vec![p(0.0, 0.0), p(6.0, 0.0), p(6.0, 2.5), p(0.0, 2.5)]).unwrap();
+    assert_eq!(polygon.to_string(), "polygon (0,0) (6,0) (6,2.5) (0,2.5)");
+    assert_eq!(AnyShape::Point(p(1.0, 2.0)).to_string(), "point (1,2)");
+    assert_eq!(AnyShape::from(circle).to_string(), circle.to_string());
+}
+
+#[test]
+fn parses_shapes() {
+    assert_eq!("circle r=100 at (0,0)".parse(), Ok(Circle::new(p(0.0, 0.0), 100.0).unwrap()));
+    assert_eq!("  circle  r=2.5 ".parse(), Ok(Circle::new(p(0.0, 0.0), 2.5).unwrap()));
+    assert_eq!("circle r=1 at( -1 , 2e3 )".parse(), Ok(Circle::new(p(-1.0, 2000.0), 1.0).unwrap()));
+    assert_eq!(
+        "rect (0,0)-(3,4)".parse(),
+        Ok(Rectangle::from_corners(p(0.0, 0.0), p(3.0, 4.0)))
+    );
+    assert_eq!(
+        "rect(3,4) - (0,0)".parse(),
+        Ok(Rectangle::from_corners(p(0.0, 0.0), p(3.0, 4.0)))
+    );
+    assert_eq!("triangle 4 5 6".parse(), Ok(Triangle::from_sides(4.0, 5.0, 6.0).unwrap()));
+    assert_eq!(
+        "triangle (0,0) (4,0) (0,3)".parse(),
+        Ok(Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0)).unwrap())
+    );
+    assert_eq!(
+        "polygon (0,0)(2,0)(1,1)".parse(),
+        Ok(Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(1.0, 1.0)]).unwrap())
+    );
+    assert_eq!("point (1,2)".parse(), Ok(AnyShape::Point(p(1.0, 2.0))));
+}
+
+#[test]
+fn parses_points_as_they_display() {
+    let point = p(1.5, -2.0);
+    assert_eq!(point.to_string().parse(), Ok(point));
+    assert_eq!("(1,2)".parse(), Ok(p(1.0, 2.0)));
+    assert_eq!("(1,2) x".parse::<Point>(), Err(error(1, 7, ParseShapeErrorKind::UnexpectedInput)));
+}
+
+#[test]
+fn reports_where_a_shape_goes_wrong() {
+    use ParseShapeErrorKind::*;
+
+    let parse = |s: &str| s.parse::<AnyShape>().unwrap_err();
+    assert_eq!(parse("   "), error(1, 4, Empty));
+    assert_eq!(parse("square (0,0)"), error(1, 1, UnknownShape("square".into())));
+    assert_eq!(parse("circle"), error(1, 7, MissingArgument));
+    assert_eq!(parse("circle 100"), error(1, 8, Expected("r=")));
+    assert_eq!(parse("circle r=ten"), error(1, 10, InvalidNumber("ten".into())));
+    assert_eq!(parse("circle r=inf"), error(1, 10, InvalidNumber("inf".into())));
+    assert_eq!(parse("circle r=-1"), error(1, 10, Invalid(GeometryError::NegativeRadius(-1.0))));
+    assert_eq!(parse("circle r=1 at"), error(1, 14, MissingArgument));
+    assert_eq!(parse("circle r=1 at 0,0"), error(1, 15, Expected("(")));
+    assert_eq!(parse("rect (0,0) (3,4)"), error(1, 12, Expected("-")));
+    assert_eq!(parse("rect (0 0)-(3,4)"), error(1, 9, Expected(",")));
+    assert_eq!(parse("rect (0,)-(3,4)"), error(1, 9, InvalidNumber(")".into())));
+    assert_eq!(parse("rect (0,0)-(3,4) (5,6)"), error(1, 18, UnexpectedInput));
+    assert_eq!(
+        parse("triangle 1 2 3"),
+        error(1, 1, Invalid(GeometryError::ImpossibleSides { a: 1.0, b: 2.0, c: 3.0 }))
+    );
+    assert_eq!(parse("triangle 1 2"), error(1, 13, MissingArgument));
+    assert_eq!(parse("triangle (0,0) (1,1) (2,2)"), error(1, 1, Invalid(GeometryError::Collinear)));
+    let too_few = Invalid(GeometryError::TooFewVertices(2));
+    assert_eq!(parse("polygon (0,0) (1,1)"), error(1, 1, too_few));
+    assert_eq!(parse("point (½,1)"), error(1, 8, InvalidNumber("½".into())));
+    assert_eq!(parse("point (1,1) é"), error(1, 13, UnexpectedInput));
+    // A shape is one line.
+    assert_eq!(parse("rect (0,0)\n-(1,1)"), error(1, 11, Expected("-")));
+    assert_eq!(parse("rect (0,0)-(1,1)\r\n"), error(1, 17, UnexpectedInput));
+    assert_eq!(parse("\npoint (0,0)"), error(1, 1, Empty));
+}
+
+#[test]
+fn parses_one_kind_of_shape() {
+    assert_eq!(
+        "  rect (0,0)-(1,1)".parse::<Circle>(),
+        Err(error(1, 3, ParseShapeErrorKind::WrongShape { expected: "circle", found: "rect" }))
+    );
+    // Columns count characters, so wide leading space is one column each.
+    assert_eq!(
+        "\u{3000}\u{3000}rect (0,0)-(1,1)".parse::<Circle>(),
+        Err(error(1, 3, ParseShapeErrorKind::WrongShape { expected: "circle", found: "rect" }))
+    );
+    assert_eq!(
+        "point (0,0)".parse::<Polygon>().unwrap_err().to_string(),
+        "line 1, column 1: expected a polygon, found a point"
+    );
+    assert!("rect (0,0)\n-(1,1)".parse::<Rectangle>().is_err());
+}
+
+#[test]
+fn parses_documents() {
+    let text = "\
+# A floor plan
+rect (0,0)-(6,4)
+
+  circle r=0.5 at (1,1)
+triangle 3 4 5
+";
+    let shapes = parse_shapes(text).unwrap();
+    assert_eq!(
+        shapes,
+        vec![
+            AnyShape::Rectangle(Rectangle::from_corners(p(0.0, 0.0), p(6.0, 4.0))),
+            AnyShape::Circle(Circle::new(p(1.0, 1.0), 0.5).unwrap()),
+            AnyShape::Triangle(Triangle::from_sides(3.0, 4.0, 5.0).unwrap()),
+        ]
+    );
+    assert_eq!(parse_shapes(&format_shapes(&shapes)), Ok(shapes));
+    assert_eq!(parse_shapes(""), Ok(vec![]));
+
+    let error = parse_shapes("rect (0,0)-(1,1)\n\n# fine so far\n  circle r=-2").unwrap_err();
+    assert_eq!(error.line, 4);
+    assert_eq!(error.to_string(), "line 4, column 12: radius -2 is negative");
+}
+
+// Any finite `f64`, from tiny to huge, most of them with a long expansion.
+fn awkward_number(rng: &mut Rng) -> f64 {
+    loop {
+        let n = f64::from_bits(rng.next());
+        if n.is_finite() {
+            return n;
+        }
+    }
+}
+
+fn awkward_point(rng: &mut Rng) -> Point {
+    match rng.below(3) {
+        0 => p(awkward_number(rng), awkward_number(rng)),
+        // Ordinary coordinates, which aren't exact in binary either.
+        _ => random_point(rng, 700) * (1.0 / 7.0),
+    }
+}
+
+fn random_shape(rng: &mut Rng) -> AnyShape {
+    loop {
+        let shape = match rng.below(5) {
+            0 => AnyShape::Point(awkward_point(rng)),
+            1 => match Circle::new(awkward_point(rng), awkward_number(rng).abs()) {
+                Ok(circle) => circle.into(),
+                Err(_) => continue,
+            },
+            2 => Rectangle::from_corners(awkward_point(rng), awkward_point(rng)).into(),
+            3 => match Triangle::new(awkward_point(rng), awkward_point(rng), awkward_point(rng)) {
+                Ok(triangle) => triangle.into(),
+                Err(_) => continue,
+            },
+            _ => {
+                let count = 3 + rng.below(6);
+                Polygon::new((0..count).map(|_| awkward_point(rng)).collect()).unwrap().into()
+            }
+        };
+        return shape;
+    }
+}
+
+#[test]
+fn formatting_and_parsing_round_trips() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..2000 {
+        let shape = random_shape(&mut rng);
+        let text = shape.to_string();
+        let parsed: AnyShape = text.parse().unwrap();
+        // Compare bit for bit, so that `-0` stays `-0`.
+        assert_eq!(format!("{parsed:?}"), format!("{shape:?}"), "{text}");
+        assert_eq!(parsed.to_string(), text);
+    }
+}
+
+#[test]
+fn documents_round_trip() {
+    let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+    let shapes: Vec<AnyShape> = (0..200).map(|_| random_shape(&mut rng)).collect();
+    let text = format_shapes(&shapes);
+    assert_eq!(text.lines().count(), shapes.len());
+    assert_eq!(parse_shapes(&text), Ok(shapes));
+}
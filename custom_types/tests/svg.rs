@@ -0,0 +1,107 @@
+// Golden-file tests for the SVG writer.
+//
+// Each document is compared with `golden/<name>.svg`; a missing file fails
+// like a changed one. Run with `UPDATE_SNAPSHOTS=1` to record new output after
+// a deliberate change, and look at the files before committing them.
+
+mod common;
+
+use std::path::Path;
+
+use chapter::snapshot;
+use common::p;
+
+use custom_types::geometry::{Affine2, AnyShape, Circle, Polygon, Rectangle, Style, Svg, Triangle};
+
+fn check_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{name}.svg"));
+    if let Err(drift) = snapshot::check(&path, actual) {
+        panic!("{drift}");
+    }
+}
+
+fn room() -> Polygon {
+    let corners = [(0.0, 0.0), (6.0, 0.0), (6.0, 2.5), (2.5, 2.5), (2.5, 5.0), (0.0, 5.0)];
+    Polygon::new(corners.iter().map(|&(x, y)| p(x, y)).collect()).unwrap()
+}
+
+#[test]
+fn every_kind_of_shape() {
+    let mut svg = Svg::new();
+    svg.title("Every kind of shape")
+        .add(Rectangle::from_corners(p(0.0, 0.0), p(3.0, 4.0)), Style::new().fill("#ddeeff").label("rect"))
+        .add(Circle::new(p(6.0, 2.0), 1.5).unwrap(), Style::new().stroke("blue").label("circle"))
+        .add(Triangle::from_sides(4.0, 5.0, 6.0).unwrap(), Style::new().stroke("green").stroke_width(2.0))
+        .add(room(), Style::new().no_stroke().fill("orange").opacity(0.5))
+        .add(p(1.0, 1.0), Style::new().no_stroke().fill("red"));
+    check_golden("every_kind_of_shape", &svg.to_string());
+}
+
+#[test]
+fn floor_plan_with_hull() {
+    let room = room();
+    let hull = custom_types::geometry::convex_hull(room.vertices()).unwrap();
+    let mut svg = Svg::new();
+    svg.size(300.0)
+        .margin(0.5)
+        .add(hull, Style::new().stroke("gray").dashed(4.0))
+        .add(room, Style::new().fill("#eeeeee").label("kitchen & dining"));
+    check_golden("floor_plan_with_hull", &svg.to_string());
+}
+
+#[test]
+fn y_down_and_transforms() {
+    let square = Rectangle::from_size(p(0.0, 0.0), 2.0, 2.0);
+    let turned = Affine2::rotate_about(p(1.0, 1.0), std::f64::consts::PI / 6.0);
+    let mut svg = Svg::new();
+    svg.y_down()
+        .point_radius(5.0)
+        .add(square, Style::new().label("<before>").label_color("navy").font_size(10.0))
+        .add(turned.apply_rectangle(&square).unwrap(), Style::new().stroke("crimson").label("after"))
+        .add(turned.apply(p(0.0, 0.0)), Style::new().fill("crimson").no_stroke());
+    check_golden("y_down_and_transforms", &svg.to_string());
+}
+
+#[test]
+fn empty_document() {
+    check_golden("empty", &Svg::new().to_string());
+}
+
+#[test]
+fn view_fits_the_shapes() {
+    let mut svg = Svg::new();
+    svg.add(Rectangle::from_corners(p(-2.0, 1.0), p(4.0, 3.0)), Style::new());
+    assert_eq!(svg.view(), Rectangle::from_corners(p(-3.0, 0.0), p(5.0, 4.0)));
+    let text = svg.to_string();
+    // The longer side gets the full size, and y is flipped into the view.
+    assert!(text.contains(r#"width="400" height="200" viewBox="-3 -4 8 4""#), "{text}");
+    assert!(text.contains(r#"<rect x="-2" y="-3" width="6" height="2""#), "{text}");
+
+    svg.y_down();
+    assert!(svg.to_string().contains(r#"viewBox="-3 0 8 4""#));
+
+    // Coordinates keep enough decimals for a hundredth of a pixel.
+    let mut tiny = Svg::new();
+    tiny.margin(0.0).add(Rectangle::from_size(p(0.0, 0.0), 0.004, 0.001), Style::new());
+    assert!(tiny.to_string().contains(r#"viewBox="0 -0.001 0.004 0.001""#));
+    assert!(tiny.to_string().contains(r#"<rect x="0" y="-0.001" width="0.004" height="0.001""#));
+    let mut huge = Svg::new();
+    huge.add(Rectangle::from_size(p(0.0, 0.0), 40_000.123, 1.0), Style::new());
+    assert!(huge.to_string().contains(r#"<rect x="0" y="-1" width="40000" height="1""#));
+
+    let mut single = Svg::new();
+    single.margin(0.0).add(p(2.0, 2.0), Style::new());
+    assert_eq!(single.view().width(), 1.0);
+}
+
+#[test]
+fn text_is_escaped() {
+    let mut svg = Svg::new();
+    svg.title("a < b & \"c\"")
+        .add(AnyShape::Point(p(0.0, 0.0)), Style::new().label("it's <b>").fill("url('#x')"));
+    let text = svg.to_string();
+    assert!(text.contains("<title>a &lt; b &amp; &quot;c&quot;</title>"));
+    assert!(text.contains(">it&apos;s &lt;b&gt;</text>"));
+    assert!(text.contains(r#"fill="url(&apos;#x&apos;)""#));
+    assert!(!text.contains("<b>"));
+}
@@ -0,0 +1,99 @@
+use custom_types::units::{
+    AnyQuantity, Length, Mass, ParseQuantityError, Time, CENTIMETER, DAY, FOOT, GRAM, INCH,
+    KILOGRAM, METER, MICROMETER, MILE, NANOSECOND, OUNCE, POUND, SECOND, YEAR,
+};
+
+#[test]
+fn conversions_are_exact() {
+    let foot = 12.0 * INCH;
+    assert_eq!(foot.get(CENTIMETER), 30.48);
+    assert_eq!(foot, 1.0 * FOOT);
+    assert_eq!((1.0 * MILE).get(FOOT), 5280.0);
+    assert_eq!((1.0 * POUND).get(OUNCE), 16.0);
+    assert_eq!((1.0 * POUND).get(GRAM), 453.59237);
+    assert_eq!((1.0 * YEAR).get(DAY), 365.0);
+    assert_eq!(INCH.of(2.0), 2.0 * INCH);
+}
+
+#[test]
+fn arithmetic_keeps_the_dimension() {
+    let mut total = 1.0 * METER + 50.0 * CENTIMETER;
+    assert_eq!(total.get(CENTIMETER), 150.0);
+    total -= 0.5 * METER;
+    assert_eq!(total, 1.0 * METER);
+    assert_eq!((total * 3.0).get(METER), 3.0);
+    assert_eq!((2.0 * total).get(METER), 2.0);
+    assert_eq!((total / 4.0).get(CENTIMETER), 25.0);
+    assert_eq!((1.0 * FOOT) / (1.0 * INCH), 12.0);
+    assert_eq!((-total).abs(), total);
+
+    let laps: Time = [30.0, 45.5, 44.5].iter().map(|&s| s * SECOND).sum();
+    assert_eq!(laps.get(SECOND), 120.0);
+    assert!(1.0 * KILOGRAM > 1.0 * POUND);
+    assert_eq!((1.0 * KILOGRAM).min(1.0 * POUND), 1.0 * POUND);
+    assert_eq!(Mass::default(), Mass::zero());
+}
+
+#[test]
+fn parses_units_of_the_requested_dimension() {
+    assert_eq!("12in".parse::<Length>(), Ok(12.0 * INCH));
+    assert_eq!("30.48cm".parse::<Length>(), Ok(12.0 * INCH));
+    assert_eq!("5ns".parse::<Time>(), Ok(5.0 * NANOSECOND));
+    assert_eq!(" 2.5 lb ".parse::<Mass>(), Ok(2.5 * POUND));
+    assert_eq!("-3e2 µm".parse::<Length>(), Ok(-300.0 * MICROMETER));
+    assert_eq!("7um".parse::<Length>(), Ok(7.0 * MICROMETER));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!("".parse::<Length>(), Err(ParseQuantityError::Empty));
+    assert_eq!("12".parse::<Length>(), Err(ParseQuantityError::MissingUnit));
+    assert_eq!(
+        "1.2.3m".parse::<Length>(),
+        Err(ParseQuantityError::InvalidNumber("1.2.3".to_string()))
+    );
+    assert_eq!("in".parse::<Length>(), Err(ParseQuantityError::InvalidNumber(String::new())));
+    assert_eq!(
+        "3 parsecs".parse::<Length>(),
+        Err(ParseQuantityError::UnknownUnit("parsecs".to_string()))
+    );
+    let error = "5ns".parse::<Length>().unwrap_err();
+    assert_eq!(
+        error,
+        ParseQuantityError::WrongDimension {
+            unit: "ns".to_string(),
+            expected: "length",
+            found: "time",
+        }
+    );
+    assert_eq!(error.to_string(), "`ns` is a unit of time, expected length");
+}
+
+#[test]
+fn any_quantity_follows_the_unit() {
+    assert_eq!("12in".parse(), Ok(AnyQuantity::Length(12.0 * INCH)));
+    assert_eq!("5ns".parse(), Ok(AnyQuantity::Time(5.0 * NANOSECOND)));
+    assert_eq!("2g".parse(), Ok(AnyQuantity::Mass(2.0 * GRAM)));
+    assert_eq!(
+        "2 furlongs".parse::<AnyQuantity>(),
+        Err(ParseQuantityError::UnknownUnit("furlongs".to_string()))
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!((12.0 * INCH).to_string(), "0.3048 m");
+    assert_eq!((12.0 * INCH).display(CENTIMETER).to_string(), "30.48 cm");
+    assert_eq!(format!("{:.1}", (1.0 * POUND).display(KILOGRAM)), "0.5 kg");
+    assert_eq!(format!("{:.3}", 1.5 * SECOND), "1.500 s");
+    assert_eq!(format!("{:?}", 2.0 * GRAM), "mass(0.002 kg)");
+    assert_eq!(AnyQuantity::Time(5.0 * NANOSECOND).to_string(), "0.000000005 s");
+}
+
+#[test]
+fn display_round_trips_through_parse() {
+    for text in ["12in", "30.48cm", "5ns", "2.5lb", "1.75y", "0.1mi"] {
+        let quantity: AnyQuantity = text.parse().unwrap();
+        assert_eq!(quantity.to_string().parse(), Ok(quantity), "{text}");
+    }
+}
@@ -0,0 +1,110 @@
+use custom_types::web::{format_session, parse_session, ParseError, ParseErrorKind, WebEvent};
+use testutil::Rng;
+
+fn error(line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+    ParseError { line, column, kind }
+}
+
+#[test]
+fn formats_events() {
+    assert_eq!(WebEvent::PageLoad.to_string(), "load");
+    assert_eq!(WebEvent::PageUnload.to_string(), "unload");
+    assert_eq!(WebEvent::KeyPress('x').to_string(), "key x");
+    assert_eq!(WebEvent::KeyPress(' ').to_string(), "key \" \"");
+    assert_eq!(WebEvent::KeyPress('"').to_string(), "key \"\\\"\"");
+    assert_eq!(WebEvent::KeyPress('é').to_string(), "key é");
+    assert_eq!(WebEvent::Paste("my text".into()).to_string(), "paste \"my text\"");
+    assert_eq!(
+        WebEvent::Paste("say \"hi\"\\\n\t\u{7}".into()).to_string(),
+        r#"paste "say \"hi\"\\\n\t\u{7}""#
+    );
+    assert_eq!(WebEvent::Click { x: 20, y: -80 }.to_string(), "click 20 -80");
+}
+
+#[test]
+fn parses_events() {
+    assert_eq!("load".parse(), Ok(WebEvent::PageLoad));
+    assert_eq!("  click\t20   80 ".parse(), Ok(WebEvent::Click { x: 20, y: 80 }));
+    assert_eq!("key x".parse(), Ok(WebEvent::KeyPress('x')));
+    assert_eq!("key \\".parse(), Ok(WebEvent::KeyPress('\\')));
+    assert_eq!("key \"\\n\"".parse(), Ok(WebEvent::KeyPress('\n')));
+    assert_eq!(r#"paste "a\u{1F600}b""#.parse(), Ok(WebEvent::Paste("a😀b".into())));
+    assert_eq!(r#"paste """#.parse(), Ok(WebEvent::Paste(String::new())));
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    let parse = |s: &str| s.parse::<WebEvent>().unwrap_err();
+    assert_eq!(parse("   "), error(1, 4, ParseErrorKind::Empty));
+    assert_eq!(parse("  scroll 1"), error(1, 3, ParseErrorKind::UnknownEvent("scroll".into())));
+    assert_eq!(parse("click 20"), error(1, 9, ParseErrorKind::MissingArgument));
+    assert_eq!(parse("click 20 x8"), error(1, 10, ParseErrorKind::InvalidNumber("x8".into())));
+    assert_eq!(parse("load now"), error(1, 6, ParseErrorKind::UnexpectedInput));
+    assert_eq!(parse("key xy"), error(1, 5, ParseErrorKind::InvalidKey("xy".into())));
+    assert_eq!(parse("key \"\""), error(1, 5, ParseErrorKind::InvalidKey("\"\"".into())));
+    assert_eq!(parse("paste text"), error(1, 7, ParseErrorKind::ExpectedQuote));
+    assert_eq!(parse("paste \"text"), error(1, 7, ParseErrorKind::UnterminatedString));
+    assert_eq!(parse("paste \"é\\q\""), error(1, 9, ParseErrorKind::InvalidEscape("\\q".into())));
+    assert_eq!(
+        parse(r#"paste "\u{110000}""#),
+        error(1, 8, ParseErrorKind::InvalidEscape(r"\u{110000}".into()))
+    );
+    assert_eq!(
+        parse(r#"paste "\u{+41}""#),
+        error(1, 8, ParseErrorKind::InvalidEscape(r"\u{+41}".into()))
+    );
+    assert_eq!(parse(r#"paste "\u12""#), error(1, 8, ParseErrorKind::InvalidEscape(r"\u".into())));
+    assert_eq!(parse(r#"paste "a"b"#), error(1, 10, ParseErrorKind::UnexpectedInput));
+    assert_eq!(
+        parse("click 1 two").to_string(),
+        "line 1, column 9: `two` is not a number"
+    );
+}
+
+#[test]
+fn sessions_skip_blank_lines_and_comments() {
+    let text = "# recorded session\nload\n\n  key a\npaste \"x y\"\n";
+    let events = parse_session(text).unwrap();
+    assert_eq!(
+        events,
+        [WebEvent::PageLoad, WebEvent::KeyPress('a'), WebEvent::Paste("x y".into())]
+    );
+    assert_eq!(format_session(&events), "load\nkey a\npaste \"x y\"\n");
+
+    let err = parse_session("load\n\nclick 1 2 3\n").unwrap_err();
+    assert_eq!(err, error(3, 11, ParseErrorKind::UnexpectedInput));
+}
+
+fn random_char(rng: &mut Rng) -> char {
+    const AWKWARD: &[char] = &[' ', '"', '\\', '\n', '\r', '\t', '\0', '\u{7f}', 'é', '😀', '{', '}', 'u'];
+    if rng.next().is_multiple_of(3) {
+        AWKWARD[rng.next() as usize % AWKWARD.len()]
+    } else {
+        char::from_u32(rng.next() as u32 % 0x3000).unwrap_or('?')
+    }
+}
+
+fn random_event(rng: &mut Rng) -> WebEvent {
+    match rng.next() % 5 {
+        0 => WebEvent::PageLoad,
+        1 => WebEvent::PageUnload,
+        2 => WebEvent::KeyPress(random_char(rng)),
+        3 => {
+            let len = rng.next() % 12;
+            WebEvent::Paste((0..len).map(|_| random_char(rng)).collect())
+        }
+        _ => WebEvent::Click { x: rng.next() as i64, y: -(rng.next() as i64 >> 40) },
+    }
+}
+
+#[test]
+fn every_event_round_trips() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    let events: Vec<WebEvent> = (0..5000).map(|_| random_event(&mut rng)).collect();
+    for event in &events {
+        let line = event.to_string();
+        assert!(!line.contains('\n'), "{line:?} spans lines");
+        assert_eq!(line.parse::<WebEvent>().as_ref(), Ok(event), "{line:?}");
+    }
+    assert_eq!(parse_session(&format_session(&events)).unwrap(), events);
+}
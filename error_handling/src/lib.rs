@@ -1,7 +1,20 @@
+#![allow(
+    dead_code,
+    unused_imports,
+    clippy::manual_map,
+    clippy::question_mark,
+    clippy::redundant_closure,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
 use std::{num::ParseIntError, fmt::write, string::ParseError};
 
 
-fn option_unwrap() {
+// Panics: `drink(nothing)` unwraps a `None`.
+pub fn option_unwrap() {
     fn give_adult(drink: Option<&str>) {
         match drink {
             Some("lemonade") => println!("Yuck! Too sugary."),
@@ -33,7 +46,7 @@ fn option_unwrap() {
     drink(nothing);
 }
 
-fn unpacking_options_with() {
+pub fn unpacking_options_with() {
     struct Person {
         job: Option<Job>,
     }
@@ -72,7 +85,7 @@ fn unpacking_options_with() {
     assert_eq!(p.work_phone_area_code(), Some(61));
 }
 
-fn combinators() {
+pub fn combinators() {
     #![allow(dead_code)]
 
     #[derive(Debug)] enum Food { Apple, Carrot, Potato }
@@ -133,7 +146,7 @@ fn combinators() {
     eat(cooked_potato);
 }
 
-fn some_different_ways() {
+pub fn some_different_ways() {
     #[derive(Debug)]
     enum Fruit {Apple, Orange, Banana, Kiwi, Lemon}
     
@@ -173,7 +186,8 @@ fn some_different_ways() {
     println!("second_available_fruit: {:?},   should_be_apple is: {:?}", second_available_fruit, should_be_apple);
 }
 
-fn result_hello() {
+// Panics: `multiply("tttt", "2")` unwraps a `ParseIntError`.
+pub fn result_hello() {
     fn multiply(first_number_str: &str, second_number_str: &str) ->i32 {
         let first_number = first_number_str.parse::<i32>().unwrap();
         let second_number = second_number_str.parse::<i32>().unwrap();
@@ -186,7 +200,7 @@ fn result_hello() {
     println!("double is {error}");
 }
 
-fn map_for_result() {
+pub fn map_for_result() {
     use std::num::ParseIntError;
 
     fn multiply(first_number_str: &str, second_number_str: &str) ->Result<i32, ParseIntError> {
@@ -246,7 +260,7 @@ fn map_for_result() {
 
 }
 
-fn aliases_for_result() {
+pub fn aliases_for_result() {
     use std::num::ParseIntError;
 
     // Define a generic alias for a `Result` with the error type `ParseIntError`.
@@ -270,7 +284,7 @@ fn aliases_for_result() {
     print(multiply("t", "2"));
 }
 
-fn pulling_result_out_of_options() {
+pub fn pulling_result_out_of_options() {
     fn double_first(vec: Vec<&str>) -> Result<Option<i32>, ParseIntError> {
         let opt = vec.first().map(|first| {
             first.parse::<i32>().map(|n| 2 * n)
@@ -288,7 +302,7 @@ fn pulling_result_out_of_options() {
     println!("The first doubled is {:?}", double_first(strings));
 }
 
-fn defining_error_type() {
+pub fn defining_error_type() {
     use std::fmt;
 
     type Result<T> = std::result::Result<T, DoubleError>;
@@ -324,7 +338,7 @@ fn defining_error_type() {
     print(double_first(strings));
 }
 
-fn boxing_errors() {
+pub fn boxing_errors() {
     use std::error;
     use std::fmt;
 
@@ -367,7 +381,7 @@ fn boxing_errors() {
     print(double_first(strings));
 }
 
-fn other_use_of() {
+pub fn other_use_of() {
     use std::error;
     use std::fmt;
 
@@ -406,7 +420,7 @@ fn other_use_of() {
     print(double_first(strings));
 }
 
-fn wrapping_errors() {
+pub fn wrapping_errors() {
     use std::error;
     use std::error::Error;
     use std::num::ParseIntError;
@@ -445,7 +459,7 @@ fn wrapping_errors() {
     }
 }
 
-fn iterating_over_results() {
+pub fn iterating_over_results() {
     
     fn first_case() {
         let strings = vec!["tofu", "93", "18"];
@@ -474,19 +488,3 @@ fn iterating_over_results() {
     second_case();
     third_case();
 }
-
-fn main() {
-    // option_unwrap();
-    // unpacking_options_with();
-    // combinators();
-    // some_different_ways();
-    // result_hello();
-    // map_for_result();
-    // // aliases_for_result();
-    // pulling_result_out_of_options();
-    // defining_error_type();
-    // boxing_errors();
-    // other_use_of();
-    // wrapping_errors();
-    iterating_over_results();
-}
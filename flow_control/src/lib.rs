@@ -1,8 +1,27 @@
-#![allow(unreachable_code, unused_labels)]
+#![allow(
+    unreachable_code,
+    unused_labels,
+    dead_code,
+    unreachable_patterns,
+    unused_variables,
+    clippy::disallowed_names,
+    clippy::match_single_binding,
+    clippy::never_loop,
+    clippy::redundant_guards,
+    clippy::single_match,
+    clippy::toplevel_ref_arg,
+    clippy::upper_case_acronyms,
+    clippy::useless_vec,
+    clippy::while_let_loop,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
 
 use std::str::FromStr;
 
-fn if_else() {
+pub fn if_else() {
     let n: i32 = 5;
 
     if n < 0 {
@@ -25,7 +44,7 @@ fn if_else() {
     println!("{} -> {}", n, big_n);
 }
 
-fn loop_test() {
+pub fn loop_test() {
     let mut count = 0u32;
 
     println!("Let's count until infinity!");
@@ -45,7 +64,7 @@ fn loop_test() {
     }
 }
 
-fn nesting_lavels() {
+pub fn nesting_lavels() {
     'outer: loop {
         println!("Entered outer loop");
         'inner: loop {
@@ -56,7 +75,7 @@ fn nesting_lavels() {
     }
 }
 
-fn return_loops() {
+pub fn return_loops() {
     let mut counter = 0;
     let result = loop {
         counter += 1;
@@ -67,7 +86,7 @@ fn return_loops() {
     println!("Result: {}", result);
 }
 
-fn for_test() {
+pub fn for_test() {
     for n in 1..101 { // or 1..=100
         if n % 15 == 0 {
             println!("FizzBuzz");
@@ -107,7 +126,7 @@ fn for_test() {
     println!("names: {:?}", names);
 }
 
-fn match_test() {
+pub fn match_test() {
     let numbers = [1, 2, 3, 5, 7, 9, 10, 11, 13, 14, 15, 17, 19, 20, 21];
     println!("Tell me about {numbers:?}");
     for number in numbers.iter() {
@@ -131,7 +150,7 @@ fn match_test() {
     println!("binary: {:?}", binary);
 }
 
-fn match_tuples() {
+pub fn match_tuples() {
     let triple = (1, 2, 3);
     println!("Tell me about {triple:?}");
     match triple {
@@ -154,7 +173,7 @@ fn match_tuples() {
     }
 }
 
-fn match_array_slice() {
+pub fn match_array_slice() {
     // Try changing the values in the array, or make it a slice!
     let array = [3, -2, 6, 2];
 
@@ -193,7 +212,7 @@ fn match_array_slice() {
     }
 }
 
-fn match_enums() {
+pub fn match_enums() {
     enum Color {
         Red,
         Blue,
@@ -231,7 +250,7 @@ fn match_enums() {
     }
 }
 
-fn match_pointers_ref() {
+pub fn match_pointers_ref() {
     // Assign a reference of type `i32`. The `&` signifies there
     // is a reference being assigned.
     let reference = &4;
@@ -282,7 +301,7 @@ fn match_pointers_ref() {
     }
 }
 
-fn match_struct() {
+pub fn match_struct() {
     struct Foo {
         x: (u32, u32),
         y: u32,
@@ -311,7 +330,7 @@ fn match_struct() {
     println!("Outside: x0 = {x0:?}, y0 = {y0}");
 }
 
-fn match_guards() {
+pub fn match_guards() {
     #[allow(dead_code)]
     enum Temperature {
         Celsius(i32),
@@ -339,7 +358,7 @@ fn match_guards() {
     }
 }
 
-fn match_binding() {
+pub fn match_binding() {
     fn age() -> u32 {
         15
     }
@@ -357,7 +376,7 @@ fn match_binding() {
     }
 }
 
-fn if_let() {
+pub fn if_let() {
     let optional = Some(7);
 
     match optional {
@@ -439,7 +458,7 @@ fn if_let() {
     }
 }
 
-fn let_else() {
+pub fn let_else() {
     fn get_count_item(s: &str) -> (u64, &str) {
         let mut it = s.split(' ');
         let (Some(count_str), Some(item)) = (it.next(), it.next()) else {
@@ -454,7 +473,7 @@ fn let_else() {
     assert_eq!(get_count_item("3 chairs"), (3, "chairs"));
 }
 
-fn while_let() {
+pub fn while_let() {
     let mut optional = Some(0);
 
     // Repeatedly try this test.
@@ -495,22 +514,3 @@ fn while_let() {
         // explicitly handling the failing case.
     }
 }
-
-fn main() {
-    if_else();
-    loop_test();
-    nesting_lavels();
-    return_loops();
-    for_test();
-    match_test();
-    match_tuples();
-    match_array_slice();
-    match_enums();
-    match_pointers_ref();
-    match_struct();
-    match_guards();
-    match_binding();
-    if_let();
-    let_else();
-    while_let();
-}
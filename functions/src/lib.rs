@@ -1,5 +1,17 @@
-
-fn methods() {
+#![allow(
+    clippy::manual_contains,
+    clippy::redundant_field_names,
+    clippy::useless_vec,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
+pub fn methods() {
+    // Just enough to show method syntax. `custom_types::geometry` has the
+    // full `Point` and `Rectangle`, which keep the corners ordered and check
+    // that they are finite with `Rectangle::try_from_corners`.
     struct Point {
         x: f64,
         y: f64,
@@ -95,7 +107,7 @@ fn methods() {
     // Error! `rectangle` is immutable, but this method requires a mutable
     // object
     //rectangle.translate(1.0, 0.0);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0596)
 
     // Okay! Mutable objects can call mutable methods
     square.translate(1.0, 1.0);
@@ -106,10 +118,10 @@ fn methods() {
 
     // Error! Previous `destroy` call "consumed" `pair`
     //pair.destroy();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0382)
 }
 
-fn closures_capture() {
+pub fn closures_capture() {
     use std::mem;
     
     let color = String::from("green");
@@ -152,7 +164,7 @@ fn closures_capture() {
     // The closure still mutably borrows `count` because it is called later.
     // An attempt to reborrow will lead to an error.
     // let _reborrow = &count; 
-    // ^ TODO: try uncommenting this line.
+    // ^ TODO: try uncommenting this line (E0502).
     inc();
 
     // The closure no longer needs to borrow `&mut count`. Therefore, it is
@@ -175,7 +187,7 @@ fn closures_capture() {
     // `consume` consumes the variable so this can only be called once.
     consume();
     // consume();
-    // ^ TODO: Try uncommenting this line.
+    // ^ TODO: Try uncommenting this line (E0382).
     let haystack = vec![1, 2, 3];
 
     let contains = move |needle| haystack.contains(needle);
@@ -193,7 +205,7 @@ fn closures_capture() {
 
 }
 
-fn closures_as_input_param() {
+pub fn closures_as_input_param() {
     // fn apply<F>(f: F) where F: FnOnce() {
     //     f();
     // }
@@ -240,7 +252,7 @@ fn closures_as_input_param() {
     println!("3 doubled: {}", apply_to_3(double));
 }
 
-fn closures_as_output_param() {
+pub fn closures_as_output_param() {
     fn create_fn() -> impl Fn() {
         let text = "Fn".to_owned();
     
@@ -268,7 +280,7 @@ fn closures_as_output_param() {
     fn_create_fnonce();
 }
 
-fn closures_example_std_iterator() {
+pub fn closures_example_std_iterator() {
     // get boolean by any
     let vec1 = vec![1, 2, 3];
     let vec2 = vec![4, 5, 6];
@@ -284,7 +296,7 @@ fn closures_example_std_iterator() {
     // `into_iter()` does move `vec2` and its elements, so they cannot be used again
     // println!("First element of vec2 is: {}", vec2[0]);
     // println!("vec2 len: {}", vec2.len());
-    // TODO: uncomment two lines above and see compiler errors.
+    // TODO: uncomment two lines above and see compiler errors (E0382).
 
     let array1 = [1, 2, 3];
     let array2 = [4, 5, 6];
@@ -331,7 +343,7 @@ fn closures_example_std_iterator() {
     assert_eq!(index_of_first_negative_number, None);
 }
 
-fn highter_order_function() {
+pub fn highter_order_function() {
     println!("Find the sum of all the squared odd numbers under 1000");
     let upper = 1000;
 
@@ -366,7 +378,7 @@ fn highter_order_function() {
     println!("functional style: {}", sum_of_squared_odd_numbers);
 }
 
-fn diverging_function() {
+pub fn diverging_function() {
     fn sum_odd_numbers(up_to: u32) -> u32 {
         let mut acc = 0;
         for i in 0..up_to {
@@ -386,13 +398,3 @@ fn diverging_function() {
     }
     println!("Sum of odd numbers up to 9 (excluding): {}", sum_odd_numbers(9));
 }
-
-fn main() {
-    methods();
-    closures_capture();
-    closures_as_input_param();
-    closures_as_output_param();
-    closures_example_std_iterator();
-    highter_order_function();
-    diverging_function();
-}
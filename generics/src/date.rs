@@ -0,0 +1,454 @@
+// Calendar dates, so that `new_type_idom` can stop turning years into days
+// with `* 365`.
+//
+// A `Date` is a day of the proleptic Gregorian calendar: today's leap year
+// rules run backwards forever, and year 0 is 1 BC. Internally dates are
+// counted as days since 1970-01-01, using Howard Hinnant's `days_from_civil`
+// and `civil_from_days`.
+//
+// A `Duration` is a calendar period of years, months and days. Months and
+// years don't have a fixed length, so adding one moves the month and keeps
+// the day, clamped to the end of a shorter month: 2024-01-31 plus one month
+// is 2024-02-29.
+
+use std::error;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+pub const MIN_YEAR: i32 = -999_999;
+pub const MAX_YEAR: i32 = 999_999;
+
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+// `month` is 1 to 12.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateError {
+    // Not `YYYY-MM-DD`.
+    InvalidFormat,
+    YearOutOfRange(i64),
+    MonthOutOfRange(u8),
+    DayOutOfRange { year: i32, month: u8, day: u8 },
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::InvalidFormat => write!(f, "expected a date as YYYY-MM-DD"),
+            DateError::YearOutOfRange(year) => {
+                write!(f, "year {year} is outside {MIN_YEAR} to {MAX_YEAR}")
+            }
+            DateError::MonthOutOfRange(month) => write!(f, "month {month} is not 1 to 12"),
+            DateError::DayOutOfRange { year, month, day } => write!(
+                f,
+                "day {day} is not 1 to {} in {year}-{month:02}",
+                days_in_month(*year, *month)
+            ),
+        }
+    }
+}
+
+impl error::Error for DateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    // 1 for Monday to 7 for Sunday, as ISO 8601 numbers them.
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+// A week of an ISO week-numbering year, which starts on the Monday of the
+// week holding the year's first Thursday. Its `year` can differ from the
+// calendar year around new year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek {
+    pub year: i32,
+    pub week: u8,
+    pub weekday: Weekday,
+}
+
+impl fmt::Display for IsoWeek {
+    // `2026-W42-7`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let week = format!("{}-W{:02}-{}", Year(self.year), self.week, self.weekday.number());
+        f.pad(&week)
+    }
+}
+
+// How ISO 8601 writes a year: four digits, or a sign and at least four
+// digits outside 0000 to 9999.
+struct Year(i32);
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            0..=9999 => write!(f, "{:04}", self.0),
+            year if year < 0 => write!(f, "-{:04}", year.unsigned_abs()),
+            year => write!(f, "+{year:04}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    // Field order makes the derived `Ord` chronological.
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub const MIN: Date = Date { year: MIN_YEAR, month: 1, day: 1 };
+    pub const MAX: Date = Date { year: MAX_YEAR, month: 12, day: 31 };
+    // 1970-01-01, day 0 of `to_days`.
+    pub const EPOCH: Date = Date { year: 1970, month: 1, day: 1 };
+
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Date, DateError> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(DateError::YearOutOfRange(year as i64));
+        }
+        if !(1..=12).contains(&month) {
+            return Err(DateError::MonthOutOfRange(month));
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(DateError::DayOutOfRange { year, month, day });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    // Days since 1970-01-01, negative before it.
+    pub fn to_days(self) -> i64 {
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        // Days since March 1st, which puts the leap day at the end of the year.
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    // The inverse of `to_days`, or `None` outside `MIN` to `MAX`.
+    pub fn from_days(days: i64) -> Option<Date> {
+        if days < Date::MIN.to_days() || days > Date::MAX.to_days() {
+            return None;
+        }
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let march_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * march_month + 2) / 5 + 1;
+        let month = (march_month + 2) % 12 + 1;
+        let year = era * 400 + year_of_era + (month <= 2) as i64;
+        Some(Date { year: year as i32, month: month as u8, day: day as u8 })
+    }
+
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::ALL[(self.to_days() + 3).rem_euclid(7) as usize]
+    }
+
+    // 1 for January 1st.
+    pub fn ordinal(self) -> u16 {
+        let before: u16 = (1..self.month).map(|m| days_in_month(self.year, m) as u16).sum();
+        before + self.day as u16
+    }
+
+    pub fn iso_week(self) -> IsoWeek {
+        let weekday = self.weekday();
+        let week = (self.ordinal() as i32 - weekday.number() as i32 + 10) / 7;
+        let (year, week) = if week < 1 {
+            (self.year - 1, iso_weeks_in_year(self.year - 1))
+        } else if week as u8 > iso_weeks_in_year(self.year) {
+            (self.year + 1, 1)
+        } else {
+            (self.year, week as u8)
+        };
+        IsoWeek { year, week, weekday }
+    }
+
+    pub fn checked_add_days(self, days: i64) -> Option<Date> {
+        Date::from_days(self.to_days().checked_add(days)?)
+    }
+
+    // Moves the month, keeping the day unless the new month is shorter.
+    pub fn checked_add_months(self, months: i64) -> Option<Date> {
+        let index = (self.year as i64 * 12 + self.month as i64 - 1).checked_add(months)?;
+        let year = index.div_euclid(12);
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let (year, month) = (year as i32, index.rem_euclid(12) as u8 + 1);
+        let day = self.day.min(days_in_month(year, month));
+        Some(Date { year, month, day })
+    }
+
+    pub fn checked_add_years(self, years: i64) -> Option<Date> {
+        self.checked_add_months(years.checked_mul(12)?)
+    }
+
+    // Years first, then months, then days.
+    pub fn checked_add(self, duration: Duration) -> Option<Date> {
+        let months = duration.years.checked_mul(12)?.checked_add(duration.months)?;
+        self.checked_add_months(months)?.checked_add_days(duration.days)
+    }
+
+    pub fn checked_sub(self, duration: Duration) -> Option<Date> {
+        self.checked_add(duration.checked_neg()?)
+    }
+
+    // Days from `self` to `other`, negative if `other` is earlier.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.to_days() - self.to_days()
+    }
+
+    // Whole years from `self` to `other`: an age, if `self` is a birth date.
+    // Someone born on February 29th has a birthday on the 28th in other
+    // years, since that's where adding years clamps it.
+    pub fn years_until(self, other: Date) -> i32 {
+        let mut years = other.year - self.year;
+        let anniversary = |years: i32| self.checked_add_years(years as i64).unwrap_or(other);
+        if years > 0 && anniversary(years) > other {
+            years -= 1;
+        } else if years < 0 && anniversary(years) < other {
+            years += 1;
+        }
+        years
+    }
+}
+
+fn iso_weeks_in_year(year: i32) -> u8 {
+    // Years starting on a Thursday have 53 weeks, and so do leap years
+    // starting on a Wednesday.
+    let first = Date { year, month: 1, day: 1 }.weekday();
+    match first {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+impl fmt::Display for Date {
+    // `2026-10-18`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date = format!("{}-{:02}-{:02}", Year(self.year), self.month, self.day);
+        f.pad(&date)
+    }
+}
+
+// One or more ASCII digits, as a number.
+fn digits(s: &str) -> Result<i64, DateError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(DateError::InvalidFormat);
+    }
+    s.parse().map_err(|_| DateError::InvalidFormat)
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    // `YYYY-MM-DD`, or with a signed year of four or more digits for years
+    // outside 0000 to 9999.
+    fn from_str(s: &str) -> Result<Date, DateError> {
+        let (sign, rest) = match s.as_bytes().first() {
+            Some(b'+') => (1, &s[1..]),
+            Some(b'-') => (-1, &s[1..]),
+            _ => (0, s),
+        };
+        let mut parts = rest.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(DateError::InvalidFormat);
+        };
+        let year_fits = if sign == 0 { year.len() == 4 } else { year.len() >= 4 };
+        if !year_fits || month.len() != 2 || day.len() != 2 {
+            return Err(DateError::InvalidFormat);
+        }
+        let year = digits(year)? * if sign < 0 { -1 } else { 1 };
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return Err(DateError::YearOutOfRange(year));
+        }
+        Date::new(year as i32, digits(month)? as u8, digits(day)? as u8)
+    }
+}
+
+// A calendar period. Adding one to a date applies the years and months
+// together, clamping the day, and then the days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Duration {
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+}
+
+impl Duration {
+    pub fn days(days: i64) -> Duration {
+        Duration { days, ..Duration::default() }
+    }
+
+    // Panics if the days overflow an `i64`.
+    pub fn weeks(weeks: i64) -> Duration {
+        Duration::days(weeks.checked_mul(7).expect("duration overflow"))
+    }
+
+    pub fn months(months: i64) -> Duration {
+        Duration { months, ..Duration::default() }
+    }
+
+    pub fn years(years: i64) -> Duration {
+        Duration { years, ..Duration::default() }
+    }
+
+    // The years, months and days that take `from` to `to`: the largest
+    // number of whole months, then the days left over. If `to` is earlier it
+    // is the negation of `between(to, from)`.
+    pub fn between(from: Date, to: Date) -> Duration {
+        if to < from {
+            return -Duration::between(to, from);
+        }
+        let mut months =
+            (to.year as i64 - from.year as i64) * 12 + to.month as i64 - from.month as i64;
+        let landed = |months| from.checked_add_months(months).unwrap_or(to);
+        if landed(months) > to {
+            months -= 1;
+        }
+        let days = landed(months).days_until(to);
+        Duration { years: months / 12, months: months % 12, days }
+    }
+
+    // `None` if any field overflows.
+    pub fn checked_add(self, rhs: Duration) -> Option<Duration> {
+        Some(Duration {
+            years: self.years.checked_add(rhs.years)?,
+            months: self.months.checked_add(rhs.months)?,
+            days: self.days.checked_add(rhs.days)?,
+        })
+    }
+
+    // `None` if a field is `i64::MIN`.
+    pub fn checked_neg(self) -> Option<Duration> {
+        Some(Duration {
+            years: self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+        })
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    // Panics if a field is `i64::MIN`; see `checked_neg`.
+    fn neg(self) -> Duration {
+        self.checked_neg().expect("duration overflow")
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    // Panics if a field overflows; see `checked_add`.
+    fn add(self, rhs: Duration) -> Duration {
+        self.checked_add(rhs).expect("duration overflow")
+    }
+}
+
+impl Add<Duration> for Date {
+    type Output = Date;
+
+    // Panics outside `Date::MIN` to `Date::MAX`; see `checked_add`.
+    fn add(self, rhs: Duration) -> Date {
+        self.checked_add(rhs).expect("date out of range")
+    }
+}
+
+impl Sub<Duration> for Date {
+    type Output = Date;
+
+    // Panics outside `Date::MIN` to `Date::MAX`; see `checked_sub`.
+    fn sub(self, rhs: Duration) -> Date {
+        self.checked_sub(rhs).expect("date out of range")
+    }
+}
+
+impl fmt::Display for Duration {
+    // ISO 8601's `P1Y2M3D`; `P0D` when empty.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut period = String::from("P");
+        for (amount, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if amount != 0 {
+                period += &format!("{amount}{designator}");
+            }
+        }
+        if period.len() == 1 {
+            period += "0D";
+        }
+        f.pad(&period)
+    }
+}
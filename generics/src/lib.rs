@@ -1,6 +1,14 @@
+#![allow(
+    clippy::needless_return,
+)]
 
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
 
-fn function() {
+pub mod date;
+
+pub fn function() {
     #[derive(Debug)]
     struct A;          // Concrete type `A`.
     struct S(A);       // Concrete type `S`.
@@ -42,7 +50,7 @@ fn function() {
     println!("{a:?}");
 }
 
-fn implementation() {
+pub fn implementation() {
     struct Val {
         val: f64,
     }
@@ -73,7 +81,7 @@ fn implementation() {
     println!("{} {}", x.value(), y.value());
 }
 
-fn traits() {
+pub fn traits() {
     // Non-copyable types.
     struct Empty;
     struct Null;
@@ -99,16 +107,17 @@ fn traits() {
 
     // empty.double_drop(null);
     // null;
-    // ^ TODO: Try uncommenting these lines.
+    // ^ TODO: Try uncommenting these lines (E0382).
 }
 
-fn bounds() {
-    use std::fmt::Debug;
+pub fn bounds() {
+    use std::fmt::{self, Debug};
 
     trait HasArea {
         fn area(&self) -> f64;
     }
 
+    // `custom_types::geometry` has the full shapes, checked on construction.
     #[derive(Debug)]
     struct Rectangle {
         width: f64,
@@ -126,6 +135,34 @@ fn bounds() {
         }
     }
 
+    // Sides that break the triangle inequality, named like
+    // `GeometryError::ImpossibleSides` in `custom_types::geometry`.
+    #[derive(Debug)]
+    struct ImpossibleSides {
+        a: f64,
+        b: f64,
+        c: f64,
+    }
+    impl fmt::Display for ImpossibleSides {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "sides {}, {} and {} don't make a triangle", self.a, self.b, self.c)
+        }
+    }
+
+    impl Trangle {
+        // Heron's formula turns sides that break the triangle inequality
+        // into `NaN`, so they are refused here, as `from_sides` does for
+        // `custom_types::geometry::Triangle`. `NaN` and infinite sides fail
+        // the comparisons too.
+        fn new(a: f64, b: f64, c: f64) -> Result<Trangle, ImpossibleSides> {
+            if a + b > c && b + c > a && a + c > b {
+                Ok(Trangle { a, b, c })
+            } else {
+                Err(ImpossibleSides { a, b, c })
+            }
+        }
+    }
+
     impl HasArea for Trangle {
         fn area(&self) -> f64 {
             let k = (self.a + self.b + self.c) / 2.0;
@@ -137,11 +174,7 @@ fn bounds() {
         width: 30f64,
         height: 30f64
     };
-    let trangle = Trangle{
-        a: 4f64,
-        b: 5f64,
-        c: 6f64,
-    };
+    let trangle = Trangle::new(4f64, 5f64, 6f64).unwrap();
 
     fn get_area<T : HasArea>(t: &T) -> f64 {
         t.area()
@@ -149,9 +182,13 @@ fn bounds() {
 
     println!("Rectangle debug:{:?} area:{}", rect, get_area(&rect));
     println!("Trangle debug:{:?} area:{}", trangle, get_area(&trangle));
+    match Trangle::new(1.0, 2.0, 3.0) {
+        Ok(trangle) => println!("Trangle debug:{:?}", trangle),
+        Err(err) => println!("Error: {}", err),
+    }
 }
 
-fn empty_bounds() {
+pub fn empty_bounds() {
     struct Cardinal;
     struct BlueJay;
     struct Turkey;
@@ -175,10 +212,10 @@ fn empty_bounds() {
     println!("A cardinal is {}", red(&cardinal));
     println!("A blue jay is {}", blue(&blue_jay));
     //println!("A turkey is {}", red(&_turkey));
-    // ^ TODO: Try uncommenting this line.
+    // ^ TODO: Try uncommenting this line (E0277).
 }
 
-fn multiple_bounds() {
+pub fn multiple_bounds() {
     use std::fmt::{Debug, Display};
 
     fn compare_prints<T: Debug + Display>(t: &T) {
@@ -196,12 +233,12 @@ fn multiple_bounds() {
 
     compare_prints(&string);
     //compare_prints(&array);
-    // TODO ^ Try uncommenting this.
+    // TODO ^ Try uncommenting this (E0277).
 
     compare_types(&array, &vec);
 }
 
-fn where_clauses() {
+pub fn where_clauses() {
     use std::fmt::Debug;
     trait PrintInOption {
         fn print_in_option(self);
@@ -222,30 +259,38 @@ fn where_clauses() {
     vec.print_in_option()
 }
 
-fn new_type_idom() {
+pub fn new_type_idom() {
+    use date::{Date, Duration};
     struct Years(i64);
     struct Days(i64);
-    impl Years {
-        pub fn to_days(&self) -> Days {
-            Days(self.0 * 365)
-        }
-    }
-    impl Days {
-        pub fn to_years(&self) -> Years {
-            Years(self.0 / 365)
-        }
-    }
+    // Years and days don't convert with `* 365`: how many days a year has
+    // depends on which year it is. Both are counted between two dates
+    // instead.
     fn old_enought(age: &Years) -> bool {
         return age.0 >= 18
     }
-    let years = Years(2);
-    let days = years.to_days();
-    println!("Old enougth {}", old_enought(&years));
-    println!("Old enougth {}", old_enought(&days.to_years()));
+    let birth: Date = "2008-02-29".parse().unwrap();
+    for on in ["2026-02-27", "2026-02-28", "2026-10-18"] {
+        let on: Date = on.parse().unwrap();
+        let age = Years(birth.years_until(on) as i64);
+        let days = Days(birth.days_until(on));
+        println!("On {} ({}), {} days and {} after {}: old enougth {}",
+                 on, on.weekday(), days.0, Duration::between(birth, on), birth,
+                 old_enought(&age));
+    }
     // println!("Old enougth {}", old_enought(&days));
+
+    // Adding months keeps the day where it can and clamps it where it can't.
+    let end_of_january: Date = "2024-01-31".parse().unwrap();
+    for months in 1..=3 {
+        println!("{} + {} = {}", end_of_january, Duration::months(months),
+                 end_of_january + Duration::months(months));
+    }
+    let new_year = Date::new(2027, 1, 1).unwrap();
+    println!("{} is in ISO week {}", new_year, new_year.iso_week());
 }
 
-fn associated_items() {
+pub fn associated_items() {
     struct Container(i32, i32);
 
     // A trait which checks if 2 items are stored inside of container.
@@ -291,7 +336,7 @@ fn associated_items() {
 
 }
 
-fn associated_types() {
+pub fn associated_types() {
     struct Container(i32, i32);
 
     // A trait which checks if 2 items are stored inside of container.
@@ -342,16 +387,3 @@ fn associated_types() {
     
     println!("The difference is: {}", difference(&container));
 }
-
-fn main() {
-    function();
-    implementation();
-    traits();
-    bounds();
-    empty_bounds();
-    multiple_bounds();
-    where_clauses();
-    new_type_idom();
-    associated_items();
-    associated_types();
-}
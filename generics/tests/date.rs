@@ -0,0 +1,214 @@
+use generics::date::{days_in_month, is_leap_year, Date, DateError, Duration, IsoWeek, Weekday};
+use testutil::Rng;
+
+fn date(text: &str) -> Date {
+    text.parse().unwrap()
+}
+
+#[test]
+fn leap_years() {
+    assert!(is_leap_year(2024));
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(1900));
+    assert!(!is_leap_year(2026));
+    assert!(is_leap_year(0));
+    assert!(is_leap_year(-4));
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2100, 2), 28);
+    assert_eq!(days_in_month(2026, 9), 30);
+}
+
+#[test]
+fn validation() {
+    assert!(Date::new(2024, 2, 29).is_ok());
+    assert_eq!(
+        Date::new(2023, 2, 29),
+        Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+    );
+    assert_eq!(Date::new(2023, 13, 1), Err(DateError::MonthOutOfRange(13)));
+    assert_eq!(Date::new(2023, 4, 0), Err(DateError::DayOutOfRange { year: 2023, month: 4, day: 0 }));
+    assert_eq!(Date::new(1_000_000, 1, 1), Err(DateError::YearOutOfRange(1_000_000)));
+    assert_eq!(
+        Date::new(2023, 2, 29).unwrap_err().to_string(),
+        "day 29 is not 1 to 28 in 2023-02"
+    );
+}
+
+#[test]
+fn days_since_the_epoch() {
+    assert_eq!(Date::EPOCH.to_days(), 0);
+    assert_eq!(date("1970-01-02").to_days(), 1);
+    assert_eq!(date("1969-12-31").to_days(), -1);
+    assert_eq!(date("2000-03-01").to_days(), 11_017);
+    assert_eq!(date("0000-03-01").to_days(), -719_468);
+    assert_eq!(Date::from_days(19_782), Some(date("2024-02-29")));
+    assert_eq!(Date::from_days(Date::MAX.to_days()), Some(Date::MAX));
+    assert_eq!(Date::from_days(Date::MAX.to_days() + 1), None);
+    assert_eq!(Date::from_days(Date::MIN.to_days() - 1), None);
+}
+
+#[test]
+fn days_count_up_one_at_a_time() {
+    // Walk day by day across several 400 year cycles, including negative
+    // years, checking the count against the calendar rules.
+    let (mut year, mut month, mut day) = (-401, 1, 1);
+    let mut days = Date::new(year, month, day).unwrap().to_days();
+    while year < 401 {
+        let date = Date::new(year, month, day).unwrap();
+        assert_eq!(date.to_days(), days, "{date}");
+        assert_eq!(Date::from_days(days), Some(date));
+        days += 1;
+        day += 1;
+        if day > days_in_month(year, month) {
+            day = 1;
+            month += 1;
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn weekdays() {
+    assert_eq!(Date::EPOCH.weekday(), Weekday::Thursday);
+    assert_eq!(date("2026-10-18").weekday(), Weekday::Sunday);
+    assert_eq!(date("2000-01-01").weekday(), Weekday::Saturday);
+    assert_eq!(date("1582-10-15").weekday(), Weekday::Friday);
+    assert_eq!(date("0001-01-01").weekday(), Weekday::Monday);
+    assert_eq!(Weekday::Sunday.number(), 7);
+    assert_eq!(format!("{:>9}|", Weekday::Monday), "   Monday|");
+}
+
+#[test]
+fn iso_weeks() {
+    let week = |text| date(text).iso_week();
+    assert_eq!(week("2026-10-18"), IsoWeek { year: 2026, week: 42, weekday: Weekday::Sunday });
+    // 2027 starts on a Friday, which still belongs to week 53 of 2026.
+    assert_eq!(week("2027-01-01").to_string(), "2026-W53-5");
+    assert_eq!(week("2027-01-04").to_string(), "2027-W01-1");
+    // 2024 ends on a Tuesday, in week 1 of 2025.
+    assert_eq!(week("2024-12-30").to_string(), "2025-W01-1");
+    assert_eq!(week("2021-01-03").to_string(), "2020-W53-7");
+    assert_eq!(week("2022-01-02").to_string(), "2021-W52-7");
+    assert_eq!(date("2024-12-31").ordinal(), 366);
+}
+
+#[test]
+fn iso_weeks_are_consecutive() {
+    // Every day is one weekday after the day before, and the week number
+    // moves on exactly on Mondays.
+    let mut previous = date("1999-12-27").iso_week();
+    for days in date("1999-12-28").to_days()..date("2030-01-01").to_days() {
+        let week = Date::from_days(days).unwrap().iso_week();
+        if week.weekday == Weekday::Monday {
+            assert_eq!(previous.weekday, Weekday::Sunday);
+            assert!(week.week == previous.week + 1 || week.week == 1 && week.year == previous.year + 1);
+        } else {
+            assert_eq!(week.weekday.number(), previous.weekday.number() + 1);
+            assert_eq!((week.year, week.week), (previous.year, previous.week));
+        }
+        previous = week;
+    }
+}
+
+#[test]
+fn adding_months_clamps_the_day() {
+    let end_of_january = date("2024-01-31");
+    assert_eq!(end_of_january + Duration::months(1), date("2024-02-29"));
+    assert_eq!(end_of_january + Duration::months(13), date("2025-02-28"));
+    assert_eq!(end_of_january - Duration::months(2), date("2023-11-30"));
+    assert_eq!(date("2024-02-29") + Duration::years(1), date("2025-02-28"));
+    assert_eq!(date("2024-02-29") + Duration::years(4), date("2028-02-29"));
+    assert_eq!(date("2024-12-31") + Duration::days(1), date("2025-01-01"));
+    assert_eq!(date("2024-03-01") - Duration::weeks(1), date("2024-02-23"));
+    // Years and months first, then days.
+    let period = Duration { years: 1, months: 1, days: 1 };
+    assert_eq!(date("2023-01-31") + period, date("2024-03-01"));
+    assert_eq!(Date::MAX.checked_add(Duration::days(1)), None);
+    assert_eq!(Date::MIN.checked_add(Duration::months(-1)), None);
+    assert_eq!(date("2000-01-01").checked_add_years(i64::MAX), None);
+    assert_eq!(date("2000-01-01").checked_add(Duration::years(i64::MAX)), None);
+    assert_eq!(date("2000-01-01").checked_sub(Duration::days(i64::MIN)), None);
+}
+
+#[test]
+fn duration_arithmetic_is_checked() {
+    let most = Duration::days(i64::MAX);
+    assert_eq!(most.checked_add(Duration::days(1)), None);
+    assert_eq!(most.checked_add(Duration::years(1)), Some(Duration { years: 1, ..most }));
+    assert_eq!(Duration::months(i64::MIN).checked_neg(), None);
+    assert_eq!(Duration::years(-2).checked_neg(), Some(Duration::years(2)));
+}
+
+#[test]
+#[should_panic(expected = "duration overflow")]
+fn adding_durations_panics_on_overflow() {
+    let _ = Duration::years(i64::MAX) + Duration::years(1);
+}
+
+#[test]
+#[should_panic(expected = "duration overflow")]
+fn weeks_panic_on_overflow() {
+    Duration::weeks(i64::MAX / 6);
+}
+
+#[test]
+fn ages() {
+    let birth = date("2008-02-29");
+    assert_eq!(birth.years_until(date("2026-02-27")), 17);
+    assert_eq!(birth.years_until(date("2026-02-28")), 18);
+    assert_eq!(birth.years_until(date("2028-02-28")), 19);
+    assert_eq!(birth.years_until(date("2028-02-29")), 20);
+    assert_eq!(birth.years_until(birth), 0);
+    assert_eq!(date("2026-02-27").years_until(birth), -17);
+    assert_eq!(date("2000-06-15").years_until(date("2000-06-14")), 0);
+}
+
+#[test]
+fn durations_between_dates() {
+    let between = |a, b| Duration::between(date(a), date(b));
+    assert_eq!(between("2008-02-29", "2026-02-27"), Duration { years: 17, months: 11, days: 29 });
+    assert_eq!(between("2024-01-31", "2024-03-01"), Duration { years: 0, months: 1, days: 1 });
+    assert_eq!(between("2024-03-01", "2024-01-31"), Duration { years: 0, months: -1, days: -1 });
+    assert_eq!(between("2026-10-18", "2026-10-18"), Duration::default());
+    assert_eq!(between("2008-02-29", "2026-10-18").to_string(), "P18Y7M19D");
+    assert_eq!(Duration::default().to_string(), "P0D");
+    assert_eq!(format!("{:>6}", Duration::weeks(1)), "   P7D");
+    assert_eq!((Duration::years(1) + Duration::days(-3)).to_string(), "P1Y-3D");
+}
+
+#[test]
+fn between_lands_on_the_later_date() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..5000 {
+        let from = Date::from_days(rng.below(200_000) as i64 - 100_000).unwrap();
+        let to = Date::from_days(from.to_days() + rng.below(20_000) as i64).unwrap();
+        let period = Duration::between(from, to);
+        assert_eq!(from + period, to, "{from} + {period}");
+        assert!((0..12).contains(&period.months));
+        assert!(period.days >= 0 && period.days < 31);
+    }
+}
+
+#[test]
+fn parse_and_format() {
+    for text in ["2026-10-18", "0000-01-01", "9999-12-31", "-0001-03-01", "+10000-01-01"] {
+        assert_eq!(date(text).to_string(), text);
+    }
+    assert_eq!(date("-0001-03-01").year(), -1);
+    assert_eq!(date("+2026-10-18"), date("2026-10-18"));
+    assert_eq!(format!("{:>12}", date("2026-10-18")), "  2026-10-18");
+    assert_eq!(Date::MIN.to_string(), "-999999-01-01");
+    assert_eq!(Date::MIN.to_string().parse(), Ok(Date::MIN));
+
+    let error = |text: &str| text.parse::<Date>().unwrap_err();
+    for text in ["", "2026-10", "2026-10-18-", "26-10-18", "2026-1-18", "2026/10/18", "2026-+1-18"] {
+        assert_eq!(error(text), DateError::InvalidFormat, "{text:?}");
+    }
+    assert_eq!(error("2026-13-01"), DateError::MonthOutOfRange(13));
+    assert_eq!(error("2026-02-29"), DateError::DayOutOfRange { year: 2026, month: 2, day: 29 });
+    assert_eq!(error("+1000000-01-01"), DateError::YearOutOfRange(1_000_000));
+    assert_eq!(error("+99999999999999999999-01-01"), DateError::InvalidFormat);
+}
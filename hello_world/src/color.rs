@@ -0,0 +1,668 @@
+// Colour models, grown out of the `Color` in `learn_display_more` and the
+// `RGB/HSV/HSL/CMY/CMYK` variants of `flow_control::match_enums`.
+//
+// `Rgb` is the hub: every model converts to and from it. Its channels are
+// bytes, the other models use `f64` with hues in degrees `[0, 360)` and every
+// other component in `[0, 1]`. Converting an `Rgb` to another model and back
+// gives the same `Rgb`. HSV <-> HSL and CMY <-> CMYK convert directly, without
+// going through the bytes of `Rgb`, so those round trips lose nothing either.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cmy {
+    pub cyan: f64,
+    pub magenta: f64,
+    pub yellow: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cmyk {
+    pub cyan: f64,
+    pub magenta: f64,
+    pub yellow: f64,
+    pub key: f64,
+}
+
+// A colour in any of the models, as parsed from text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    Rgb(Rgb),
+    Hsv(Hsv),
+    Hsl(Hsl),
+    Cmy(Cmy),
+    Cmyk(Cmyk),
+}
+
+fn unit(x: f64) -> f64 {
+    if x.is_nan() {
+        0.0
+    } else {
+        x.clamp(0.0, 1.0)
+    }
+}
+
+fn degrees(hue: f64) -> f64 {
+    if hue.is_finite() {
+        // `rem_euclid` can round up to exactly 360 for tiny negative hues.
+        let hue = hue.rem_euclid(360.0);
+        if hue >= 360.0 {
+            0.0
+        } else {
+            hue
+        }
+    } else {
+        0.0
+    }
+}
+
+fn to_byte(x: f64) -> u8 {
+    (unit(x) * 255.0).round() as u8
+}
+
+impl Rgb {
+    pub fn new(red: u8, green: u8, blue: u8) -> Rgb {
+        Rgb { red, green, blue }
+    }
+
+    // Channels as fractions of 255.
+    fn fractions(&self) -> (f64, f64, f64) {
+        (
+            self.red as f64 / 255.0,
+            self.green as f64 / 255.0,
+            self.blue as f64 / 255.0,
+        )
+    }
+
+    fn from_fractions(red: f64, green: f64, blue: f64) -> Rgb {
+        Rgb::new(to_byte(red), to_byte(green), to_byte(blue))
+    }
+
+    // Hue in degrees together with the largest and smallest channel.
+    fn hue_max_min(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.fractions();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (degrees(hue), max, min)
+    }
+
+    // Shared tail of HSV and HSL to RGB: `chroma` spread over the hue sector,
+    // then lifted by `m`.
+    fn from_hue_chroma(hue: f64, chroma: f64, m: f64) -> Rgb {
+        let sector = degrees(hue) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Rgb::from_fractions(r + m, g + m, b + m)
+    }
+
+    // `#RRGGBB`.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.red, self.green, self.blue)
+    }
+
+    // Looks up a CSS named colour, ignoring case.
+    pub fn named(name: &str) -> Option<Rgb> {
+        let name = name.to_ascii_lowercase();
+        NAMED_COLORS
+            .binary_search_by(|&(n, _)| n.cmp(name.as_str()))
+            .ok()
+            .map(|i| NAMED_COLORS[i].1)
+    }
+
+    // The CSS name of this colour, if it has one. Where two names share a
+    // colour (`gray`/`grey`, `aqua`/`cyan`) the first alphabetically is used.
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_COLORS
+            .iter()
+            .find(|&&(_, rgb)| rgb == *self)
+            .map(|&(name, _)| name)
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Hsv {
+        let (hue, max, min) = rgb.hue_max_min();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { hue, saturation, value: max }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Rgb {
+        let value = unit(hsv.value);
+        let chroma = value * unit(hsv.saturation);
+        Rgb::from_hue_chroma(hsv.hue, chroma, value - chroma)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Hsl {
+        let (hue, max, min) = rgb.hue_max_min();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Hsl { hue, saturation: unit(saturation), lightness }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Rgb {
+        let lightness = unit(hsl.lightness);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * unit(hsl.saturation);
+        Rgb::from_hue_chroma(hsl.hue, chroma, lightness - chroma / 2.0)
+    }
+}
+
+impl From<Rgb> for Cmy {
+    fn from(rgb: Rgb) -> Cmy {
+        let (r, g, b) = rgb.fractions();
+        Cmy { cyan: 1.0 - r, magenta: 1.0 - g, yellow: 1.0 - b }
+    }
+}
+
+impl From<Cmy> for Rgb {
+    fn from(cmy: Cmy) -> Rgb {
+        Rgb::from_fractions(1.0 - unit(cmy.cyan), 1.0 - unit(cmy.magenta), 1.0 - unit(cmy.yellow))
+    }
+}
+
+impl From<Rgb> for Cmyk {
+    fn from(rgb: Rgb) -> Cmyk {
+        Cmyk::from(Cmy::from(rgb))
+    }
+}
+
+impl From<Cmyk> for Rgb {
+    fn from(cmyk: Cmyk) -> Rgb {
+        Rgb::from(Cmy::from(cmyk))
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Hsl {
+        let value = unit(hsv.value);
+        let lightness = value * (1.0 - unit(hsv.saturation) / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (value - lightness) / lightness.min(1.0 - lightness)
+        };
+        Hsl { hue: degrees(hsv.hue), saturation: unit(saturation), lightness }
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Hsv {
+        let lightness = unit(hsl.lightness);
+        let value = lightness + unit(hsl.saturation) * lightness.min(1.0 - lightness);
+        let saturation = if value == 0.0 { 0.0 } else { 2.0 * (1.0 - lightness / value) };
+        Hsv { hue: degrees(hsl.hue), saturation: unit(saturation), value }
+    }
+}
+
+impl From<Cmy> for Cmyk {
+    fn from(cmy: Cmy) -> Cmyk {
+        let (c, m, y) = (unit(cmy.cyan), unit(cmy.magenta), unit(cmy.yellow));
+        let key = c.min(m).min(y);
+        if key == 1.0 {
+            return Cmyk { cyan: 0.0, magenta: 0.0, yellow: 0.0, key };
+        }
+        Cmyk {
+            cyan: (c - key) / (1.0 - key),
+            magenta: (m - key) / (1.0 - key),
+            yellow: (y - key) / (1.0 - key),
+            key,
+        }
+    }
+}
+
+impl From<Cmyk> for Cmy {
+    fn from(cmyk: Cmyk) -> Cmy {
+        let key = unit(cmyk.key);
+        let ink = |x: f64| unit(x) * (1.0 - key) + key;
+        Cmy { cyan: ink(cmyk.cyan), magenta: ink(cmyk.magenta), yellow: ink(cmyk.yellow) }
+    }
+}
+
+// The remaining pairs go through the hub.
+macro_rules! via_rgb {
+    ($($from:ident => $to:ident),* $(,)?) => {
+        $(
+            impl From<$from> for $to {
+                fn from(color: $from) -> $to {
+                    $to::from(Rgb::from(color))
+                }
+            }
+        )*
+    };
+}
+
+via_rgb! {
+    Hsv => Cmy, Hsv => Cmyk,
+    Hsl => Cmy, Hsl => Cmyk,
+    Cmy => Hsv, Cmy => Hsl,
+    Cmyk => Hsv, Cmyk => Hsl,
+}
+
+impl Color {
+    pub fn to_rgb(&self) -> Rgb {
+        match *self {
+            Color::Rgb(rgb) => rgb,
+            Color::Hsv(hsv) => hsv.into(),
+            Color::Hsl(hsl) => hsl.into(),
+            Color::Cmy(cmy) => cmy.into(),
+            Color::Cmyk(cmyk) => cmyk.into(),
+        }
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Rgb {
+        color.to_rgb()
+    }
+}
+
+// `#0064FF`, or `rgb(0, 100, 255)` with `{:#}`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "rgb({}, {}, {})", self.red, self.green, self.blue)
+        } else {
+            write!(f, "{}", self.to_hex())
+        }
+    }
+}
+
+// Writes `name(a, b%, c%)`, with the first component in degrees when
+// `hue_first` is set and every component as a percentage otherwise. The
+// formatter's precision applies to every number.
+fn write_components(
+    f: &mut fmt::Formatter,
+    name: &str,
+    hue_first: bool,
+    components: &[f64],
+) -> fmt::Result {
+    write!(f, "{name}(")?;
+    for (i, &x) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        let (x, unit) = if i == 0 && hue_first { (x, "") } else { (x * 100.0, "%") };
+        match f.precision() {
+            Some(precision) => write!(f, "{x:.precision$}{unit}")?,
+            None => write!(f, "{x}{unit}")?,
+        }
+    }
+    write!(f, ")")
+}
+
+impl fmt::Display for Hsv {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, "hsv", true, &[self.hue, self.saturation, self.value])
+    }
+}
+
+impl fmt::Display for Hsl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, "hsl", true, &[self.hue, self.saturation, self.lightness])
+    }
+}
+
+impl fmt::Display for Cmy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, "cmy", false, &[self.cyan, self.magenta, self.yellow])
+    }
+}
+
+impl fmt::Display for Cmyk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, "cmyk", false, &[self.cyan, self.magenta, self.yellow, self.key])
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Color::Rgb(c) => fmt::Display::fmt(c, f),
+            Color::Hsv(c) => fmt::Display::fmt(c, f),
+            Color::Hsl(c) => fmt::Display::fmt(c, f),
+            Color::Cmy(c) => fmt::Display::fmt(c, f),
+            Color::Cmyk(c) => fmt::Display::fmt(c, f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    Empty,
+    // Not a CSS colour name, and not `#...` or `rgb(...)`/`hsl(...)` either.
+    UnknownName(String),
+    // `#` followed by something other than 3 or 6 hex digits.
+    BadHex(String),
+    // A malformed `rgb(...)` or `hsl(...)`.
+    BadSyntax(String),
+    // A well-formed component outside its range, e.g. `rgb(300, 0, 0)`.
+    OutOfRange(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "empty colour"),
+            ParseColorError::UnknownName(s) => write!(f, "unknown colour name `{s}`"),
+            ParseColorError::BadHex(s) => write!(f, "`{s}` is not a `#RGB` or `#RRGGBB` colour"),
+            ParseColorError::BadSyntax(s) => write!(f, "malformed colour `{s}`"),
+            ParseColorError::OutOfRange(s) => write!(f, "colour component out of range in `{s}`"),
+        }
+    }
+}
+
+impl error::Error for ParseColorError {}
+
+// The comma-separated arguments of `name(...)`, or `None` if `s` is not a
+// call to `name`.
+fn arguments<'a>(s: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let head = s.get(..name.len())?;
+    if !head.eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let inner = s[name.len()..].trim_start().strip_prefix('(')?.strip_suffix(')')?;
+    Some(inner.split(',').map(str::trim).collect())
+}
+
+fn parse_hex(s: &str) -> Result<Rgb, ParseColorError> {
+    let bad = || ParseColorError::BadHex(s.to_string());
+    let digits = &s[1..];
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(bad());
+    }
+    let channel = |i: usize, len: usize| u8::from_str_radix(&digits[i * len..(i + 1) * len], 16);
+    match digits.len() {
+        // `#abc` is short for `#aabbcc`.
+        3 => {
+            let short = |i| channel(i, 1).map(|c| c * 0x11);
+            Ok(Rgb::new(short(0).unwrap(), short(1).unwrap(), short(2).unwrap()))
+        }
+        6 => Ok(Rgb::new(channel(0, 2).unwrap(), channel(1, 2).unwrap(), channel(2, 2).unwrap())),
+        _ => Err(bad()),
+    }
+}
+
+fn parse_rgb_function(s: &str, args: &[&str]) -> Result<Rgb, ParseColorError> {
+    if args.len() != 3 {
+        return Err(ParseColorError::BadSyntax(s.to_string()));
+    }
+    let mut channels = [0u8; 3];
+    for (channel, arg) in channels.iter_mut().zip(args) {
+        let value: i64 = arg.parse().map_err(|_| ParseColorError::BadSyntax(s.to_string()))?;
+        *channel = u8::try_from(value).map_err(|_| ParseColorError::OutOfRange(s.to_string()))?;
+    }
+    Ok(Rgb::new(channels[0], channels[1], channels[2]))
+}
+
+// `#RRGGBB`, `#RGB`, `rgb(r, g, b)` with channels in `0..=255`, or a CSS
+// colour name.
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Rgb, ParseColorError> {
+        let s = s.trim();
+        if s.is_empty() {
+            Err(ParseColorError::Empty)
+        } else if s.starts_with('#') {
+            parse_hex(s)
+        } else if let Some(args) = arguments(s, "rgb") {
+            parse_rgb_function(s, &args)
+        } else {
+            Rgb::named(s).ok_or_else(|| ParseColorError::UnknownName(s.to_string()))
+        }
+    }
+}
+
+// `hsl(h, s%, l%)` with the hue in degrees and the percentages in `0..=100`.
+impl FromStr for Hsl {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Hsl, ParseColorError> {
+        let s = s.trim();
+        let bad = || ParseColorError::BadSyntax(s.to_string());
+        let args = arguments(s, "hsl").ok_or_else(bad)?;
+        let [hue, saturation, lightness] = args[..] else {
+            return Err(bad());
+        };
+
+        let hue: f64 = hue.strip_suffix("deg").unwrap_or(hue).parse().map_err(|_| bad())?;
+        let percent = |arg: &str| -> Result<f64, ParseColorError> {
+            let x: f64 = arg.strip_suffix('%').ok_or_else(bad)?.trim_end().parse().map_err(|_| bad())?;
+            if (0.0..=100.0).contains(&x) {
+                Ok(x / 100.0)
+            } else {
+                Err(ParseColorError::OutOfRange(s.to_string()))
+            }
+        };
+        if !hue.is_finite() {
+            return Err(ParseColorError::OutOfRange(s.to_string()));
+        }
+        Ok(Hsl {
+            hue: degrees(hue),
+            saturation: percent(saturation)?,
+            lightness: percent(lightness)?,
+        })
+    }
+}
+
+// Anything `Rgb` or `Hsl` accepts, keeping the model it was written in.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Color, ParseColorError> {
+        if arguments(s.trim(), "hsl").is_some() {
+            s.parse().map(Color::Hsl)
+        } else {
+            s.parse().map(Color::Rgb)
+        }
+    }
+}
+
+const fn hex(rgb: u32) -> Rgb {
+    Rgb {
+        red: (rgb >> 16) as u8,
+        green: (rgb >> 8) as u8,
+        blue: rgb as u8,
+    }
+}
+
+// The CSS named colours, sorted by name for `binary_search`.
+const NAMED_COLORS: &[(&str, Rgb)] = &[
+    ("aliceblue", hex(0xF0F8FF)),
+    ("antiquewhite", hex(0xFAEBD7)),
+    ("aqua", hex(0x00FFFF)),
+    ("aquamarine", hex(0x7FFFD4)),
+    ("azure", hex(0xF0FFFF)),
+    ("beige", hex(0xF5F5DC)),
+    ("bisque", hex(0xFFE4C4)),
+    ("black", hex(0x000000)),
+    ("blanchedalmond", hex(0xFFEBCD)),
+    ("blue", hex(0x0000FF)),
+    ("blueviolet", hex(0x8A2BE2)),
+    ("brown", hex(0xA52A2A)),
+    ("burlywood", hex(0xDEB887)),
+    ("cadetblue", hex(0x5F9EA0)),
+    ("chartreuse", hex(0x7FFF00)),
+    ("chocolate", hex(0xD2691E)),
+    ("coral", hex(0xFF7F50)),
+    ("cornflowerblue", hex(0x6495ED)),
+    ("cornsilk", hex(0xFFF8DC)),
+    ("crimson", hex(0xDC143C)),
+    ("cyan", hex(0x00FFFF)),
+    ("darkblue", hex(0x00008B)),
+    ("darkcyan", hex(0x008B8B)),
+    ("darkgoldenrod", hex(0xB8860B)),
+    ("darkgray", hex(0xA9A9A9)),
+    ("darkgreen", hex(0x006400)),
+    ("darkgrey", hex(0xA9A9A9)),
+    ("darkkhaki", hex(0xBDB76B)),
+    ("darkmagenta", hex(0x8B008B)),
+    ("darkolivegreen", hex(0x556B2F)),
+    ("darkorange", hex(0xFF8C00)),
+    ("darkorchid", hex(0x9932CC)),
+    ("darkred", hex(0x8B0000)),
+    ("darksalmon", hex(0xE9967A)),
+    ("darkseagreen", hex(0x8FBC8F)),
+    ("darkslateblue", hex(0x483D8B)),
+    ("darkslategray", hex(0x2F4F4F)),
+    ("darkslategrey", hex(0x2F4F4F)),
+    ("darkturquoise", hex(0x00CED1)),
+    ("darkviolet", hex(0x9400D3)),
+    ("deeppink", hex(0xFF1493)),
+    ("deepskyblue", hex(0x00BFFF)),
+    ("dimgray", hex(0x696969)),
+    ("dimgrey", hex(0x696969)),
+    ("dodgerblue", hex(0x1E90FF)),
+    ("firebrick", hex(0xB22222)),
+    ("floralwhite", hex(0xFFFAF0)),
+    ("forestgreen", hex(0x228B22)),
+    ("fuchsia", hex(0xFF00FF)),
+    ("gainsboro", hex(0xDCDCDC)),
+    ("ghostwhite", hex(0xF8F8FF)),
+    ("gold", hex(0xFFD700)),
+    ("goldenrod", hex(0xDAA520)),
+    ("gray", hex(0x808080)),
+    ("green", hex(0x008000)),
+    ("greenyellow", hex(0xADFF2F)),
+    ("grey", hex(0x808080)),
+    ("honeydew", hex(0xF0FFF0)),
+    ("hotpink", hex(0xFF69B4)),
+    ("indianred", hex(0xCD5C5C)),
+    ("indigo", hex(0x4B0082)),
+    ("ivory", hex(0xFFFFF0)),
+    ("khaki", hex(0xF0E68C)),
+    ("lavender", hex(0xE6E6FA)),
+    ("lavenderblush", hex(0xFFF0F5)),
+    ("lawngreen", hex(0x7CFC00)),
+    ("lemonchiffon", hex(0xFFFACD)),
+    ("lightblue", hex(0xADD8E6)),
+    ("lightcoral", hex(0xF08080)),
+    ("lightcyan", hex(0xE0FFFF)),
+    ("lightgoldenrodyellow", hex(0xFAFAD2)),
+    ("lightgray", hex(0xD3D3D3)),
+    ("lightgreen", hex(0x90EE90)),
+    ("lightgrey", hex(0xD3D3D3)),
+    ("lightpink", hex(0xFFB6C1)),
+    ("lightsalmon", hex(0xFFA07A)),
+    ("lightseagreen", hex(0x20B2AA)),
+    ("lightskyblue", hex(0x87CEFA)),
+    ("lightslategray", hex(0x778899)),
+    ("lightslategrey", hex(0x778899)),
+    ("lightsteelblue", hex(0xB0C4DE)),
+    ("lightyellow", hex(0xFFFFE0)),
+    ("lime", hex(0x00FF00)),
+    ("limegreen", hex(0x32CD32)),
+    ("linen", hex(0xFAF0E6)),
+    ("magenta", hex(0xFF00FF)),
+    ("maroon", hex(0x800000)),
+    ("mediumaquamarine", hex(0x66CDAA)),
+    ("mediumblue", hex(0x0000CD)),
+    ("mediumorchid", hex(0xBA55D3)),
+    ("mediumpurple", hex(0x9370DB)),
+    ("mediumseagreen", hex(0x3CB371)),
+    ("mediumslateblue", hex(0x7B68EE)),
+    ("mediumspringgreen", hex(0x00FA9A)),
+    ("mediumturquoise", hex(0x48D1CC)),
+    ("mediumvioletred", hex(0xC71585)),
+    ("midnightblue", hex(0x191970)),
+    ("mintcream", hex(0xF5FFFA)),
+    ("mistyrose", hex(0xFFE4E1)),
+    ("moccasin", hex(0xFFE4B5)),
+    ("navajowhite", hex(0xFFDEAD)),
+    ("navy", hex(0x000080)),
+    ("oldlace", hex(0xFDF5E6)),
+    ("olive", hex(0x808000)),
+    ("olivedrab", hex(0x6B8E23)),
+    ("orange", hex(0xFFA500)),
+    ("orangered", hex(0xFF4500)),
+    ("orchid", hex(0xDA70D6)),
+    ("palegoldenrod", hex(0xEEE8AA)),
+    ("palegreen", hex(0x98FB98)),
+    ("paleturquoise", hex(0xAFEEEE)),
+    ("palevioletred", hex(0xDB7093)),
+    ("papayawhip", hex(0xFFEFD5)),
+    ("peachpuff", hex(0xFFDAB9)),
+    ("peru", hex(0xCD853F)),
+    ("pink", hex(0xFFC0CB)),
+    ("plum", hex(0xDDA0DD)),
+    ("powderblue", hex(0xB0E0E6)),
+    ("purple", hex(0x800080)),
+    ("rebeccapurple", hex(0x663399)),
+    ("red", hex(0xFF0000)),
+    ("rosybrown", hex(0xBC8F8F)),
+    ("royalblue", hex(0x4169E1)),
+    ("saddlebrown", hex(0x8B4513)),
+    ("salmon", hex(0xFA8072)),
+    ("sandybrown", hex(0xF4A460)),
+    ("seagreen", hex(0x2E8B57)),
+    ("seashell", hex(0xFFF5EE)),
+    ("sienna", hex(0xA0522D)),
+    ("silver", hex(0xC0C0C0)),
+    ("skyblue", hex(0x87CEEB)),
+    ("slateblue", hex(0x6A5ACD)),
+    ("slategray", hex(0x708090)),
+    ("slategrey", hex(0x708090)),
+    ("snow", hex(0xFFFAFA)),
+    ("springgreen", hex(0x00FF7F)),
+    ("steelblue", hex(0x4682B4)),
+    ("tan", hex(0xD2B48C)),
+    ("teal", hex(0x008080)),
+    ("thistle", hex(0xD8BFD8)),
+    ("tomato", hex(0xFF6347)),
+    ("turquoise", hex(0x40E0D0)),
+    ("violet", hex(0xEE82EE)),
+    ("wheat", hex(0xF5DEB3)),
+    ("white", hex(0xFFFFFF)),
+    ("whitesmoke", hex(0xF5F5F5)),
+    ("yellow", hex(0xFFFF00)),
+    ("yellowgreen", hex(0x9ACD32)),
+];
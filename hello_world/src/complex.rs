@@ -0,0 +1,541 @@
+// Complex numbers, grown out of the `Complex { real, imag }` that
+// `learn_display` prints.
+//
+// Arithmetic works for any `Num` (the primitive integers and floats). The
+// functions that need square roots, logarithms or angles (`norm`, `arg`,
+// `exp`, `ln`, roots, ...) are only available for `Real`, i.e. `f32`/`f64`.
+
+use std::error;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+// The arithmetic a component type needs.
+pub trait Num:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    // Whether the sign bit is set, so that `-0.0` displays as negative.
+    fn is_negative(self) -> bool;
+    fn abs(self) -> Self;
+    // Rounds towards zero; integers are already whole.
+    fn trunc(self) -> Self;
+}
+
+macro_rules! impl_num_int {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+
+                fn abs(self) -> $t {
+                    <$t>::abs(self)
+                }
+
+                fn trunc(self) -> $t {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_num_int!(i8, i16, i32, i64, i128, isize);
+
+// Floating point components, for the transcendental functions.
+pub trait Real: Num + PartialOrd {
+    const PI: Self;
+
+    fn from_f64(x: f64) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: $t = 0.0;
+                const ONE: $t = 1.0;
+
+                fn is_negative(self) -> bool {
+                    self.is_sign_negative()
+                }
+
+                fn abs(self) -> $t {
+                    $t::abs(self)
+                }
+
+                fn trunc(self) -> $t {
+                    $t::trunc(self)
+                }
+            }
+
+            impl Real for $t {
+                const PI: $t = std::$t::consts::PI;
+
+                fn from_f64(x: f64) -> $t {
+                    x as $t
+                }
+
+                fn sqrt(self) -> $t {
+                    $t::sqrt(self)
+                }
+
+                fn exp(self) -> $t {
+                    $t::exp(self)
+                }
+
+                fn ln(self) -> $t {
+                    $t::ln(self)
+                }
+
+                fn powf(self, n: $t) -> $t {
+                    $t::powf(self, n)
+                }
+
+                fn sin(self) -> $t {
+                    $t::sin(self)
+                }
+
+                fn cos(self) -> $t {
+                    $t::cos(self)
+                }
+
+                fn atan2(self, other: $t) -> $t {
+                    $t::atan2(self, other)
+                }
+
+                fn hypot(self, other: $t) -> $t {
+                    $t::hypot(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_real!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Complex<T> {
+    pub real: T,
+    pub imag: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(real: T, imag: T) -> Complex<T> {
+        Complex { real, imag }
+    }
+}
+
+impl<T: Num> Complex<T> {
+    // The imaginary unit.
+    pub fn i() -> Complex<T> {
+        Complex::new(T::ZERO, T::ONE)
+    }
+
+    pub fn conj(self) -> Complex<T> {
+        Complex::new(self.real, -self.imag)
+    }
+
+    // `|z|²`, which unlike `norm` needs no square root.
+    pub fn norm_sqr(self) -> T {
+        self.real * self.real + self.imag * self.imag
+    }
+
+    // `1 / z`.
+    pub fn inv(self) -> Complex<T> {
+        let n = self.norm_sqr();
+        Complex::new(self.real / n, -self.imag / n)
+    }
+
+    // `z` raised to an integer power by repeated squaring.
+    pub fn powi(self, n: i32) -> Complex<T> {
+        let mut base = if n < 0 { self.inv() } else { self };
+        let mut n = n.unsigned_abs();
+        let mut result = Complex::new(T::ONE, T::ZERO);
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            n >>= 1;
+        }
+        result
+    }
+}
+
+impl<T: Real> Complex<T> {
+    pub fn from_polar(norm: T, arg: T) -> Complex<T> {
+        Complex::new(norm * arg.cos(), norm * arg.sin())
+    }
+
+    // `(norm, arg)`.
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    // `|z|`.
+    pub fn norm(self) -> T {
+        self.real.hypot(self.imag)
+    }
+
+    // The angle to the positive real axis, in `(-π, π]`.
+    pub fn arg(self) -> T {
+        self.imag.atan2(self.real)
+    }
+
+    pub fn exp(self) -> Complex<T> {
+        Complex::from_polar(self.real.exp(), self.imag)
+    }
+
+    // The principal natural logarithm.
+    pub fn ln(self) -> Complex<T> {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+
+    pub fn powf(self, n: T) -> Complex<T> {
+        if self.real == T::ZERO && self.imag == T::ZERO {
+            return self;
+        }
+        let (norm, arg) = self.to_polar();
+        Complex::from_polar(norm.powf(n), arg * n)
+    }
+
+    // `z^w`, taking the principal logarithm of `z`.
+    pub fn powc(self, w: Complex<T>) -> Complex<T> {
+        if self.real == T::ZERO && self.imag == T::ZERO {
+            return self;
+        }
+        (self.ln() * w).exp()
+    }
+
+    // The principal square root, with a non-negative real part.
+    pub fn sqrt(self) -> Complex<T> {
+        let (norm, arg) = self.to_polar();
+        Complex::from_polar(norm.sqrt(), arg / T::from_f64(2.0))
+    }
+
+    // All `n` distinct `n`th roots, starting with the principal one and going
+    // anticlockwise. Empty for `n == 0`.
+    pub fn roots(self, n: u32) -> Vec<Complex<T>> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let (norm, arg) = self.to_polar();
+        let n_t = T::from_f64(n as f64);
+        let norm = norm.powf(T::ONE / n_t);
+        (0..n)
+            .map(|k| {
+                let turn = T::from_f64(2.0 * k as f64) * T::PI;
+                Complex::from_polar(norm, (arg + turn) / n_t)
+            })
+            .collect()
+    }
+}
+
+impl<T: Num> From<T> for Complex<T> {
+    fn from(real: T) -> Complex<T> {
+        Complex::new(real, T::ZERO)
+    }
+}
+
+impl<T: Num> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.real, -self.imag)
+    }
+}
+
+impl<T: Num> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.real + rhs.real, self.imag + rhs.imag)
+    }
+}
+
+impl<T: Num> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.real - rhs.real, self.imag - rhs.imag)
+    }
+}
+
+impl<T: Num> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.real * rhs.real - self.imag * rhs.imag,
+            self.real * rhs.imag + self.imag * rhs.real,
+        )
+    }
+}
+
+impl<T: Num> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: Complex<T>) -> Complex<T> {
+        let n = rhs.norm_sqr();
+        Complex::new(
+            (self.real * rhs.real + self.imag * rhs.imag) / n,
+            (self.imag * rhs.real - self.real * rhs.imag) / n,
+        )
+    }
+}
+
+// What is left after subtracting `rhs` times the quotient with both of its
+// parts rounded towards zero.
+impl<T: Num> Rem for Complex<T> {
+    type Output = Complex<T>;
+
+    fn rem(self, rhs: Complex<T>) -> Complex<T> {
+        let q = self / rhs;
+        self - rhs * Complex::new(q.real.trunc(), q.imag.trunc())
+    }
+}
+
+impl<T: Num> Add<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real + rhs, self.imag)
+    }
+}
+
+impl<T: Num> Sub<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real - rhs, self.imag)
+    }
+}
+
+impl<T: Num> Mul<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real * rhs, self.imag * rhs)
+    }
+}
+
+impl<T: Num> Div<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real / rhs, self.imag / rhs)
+    }
+}
+
+impl<T: Num> Rem<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn rem(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real % rhs, self.imag % rhs)
+    }
+}
+
+// `a += b` for every operator above, complex or scalar on the right.
+macro_rules! impl_assign {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(
+            impl<T: Num> $trait for Complex<T> {
+                fn $method(&mut self, rhs: Complex<T>) {
+                    *self = *self $op rhs;
+                }
+            }
+
+            impl<T: Num> $trait<T> for Complex<T> {
+                fn $method(&mut self, rhs: T) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign add_assign +,
+    SubAssign sub_assign -,
+    MulAssign mul_assign *,
+    DivAssign div_assign /,
+    RemAssign rem_assign %
+);
+
+// `2.0 * z` and friends. These can't be generic over `T` because of the
+// orphan rule, so each primitive gets its own impls.
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {
+        $(
+            impl Add<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn add(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex::new(self + rhs.real, rhs.imag)
+                }
+            }
+
+            impl Sub<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn sub(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex::new(self - rhs.real, -rhs.imag)
+                }
+            }
+
+            impl Mul<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn mul(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex::new(self * rhs.real, self * rhs.imag)
+                }
+            }
+
+            impl Div<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn div(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex::from(self) / rhs
+                }
+            }
+
+            impl Rem<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn rem(self, rhs: Complex<$t>) -> Complex<$t> {
+                    Complex::from(self) % rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<T: Num> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::ZERO, T::ZERO), Add::add)
+    }
+}
+
+impl<T: Num> Product for Complex<T> {
+    fn product<I: Iterator<Item = Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::ONE, T::ZERO), Mul::mul)
+    }
+}
+
+// `3 + 4.5i`. The precision applies to both parts and `+` to the real part,
+// so `{:+.2}` gives `+3.00 + 4.50i`. A width pads the whole number.
+impl<T: Num + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let real = match (f.precision(), f.sign_plus()) {
+            (Some(p), true) => format!("{:+.*}", p, self.real),
+            (Some(p), false) => format!("{:.*}", p, self.real),
+            (None, true) => format!("{:+}", self.real),
+            (None, false) => format!("{}", self.real),
+        };
+        let sign = if self.imag.is_negative() { '-' } else { '+' };
+        let imag = match f.precision() {
+            Some(p) => format!("{:.*}", p, self.imag.abs()),
+            None => format!("{}", self.imag.abs()),
+        };
+        let text = format!("{real} {sign} {imag}i");
+
+        let Some(width) = f.width() else {
+            return f.write_str(&text);
+        };
+        let padding = width.saturating_sub(text.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            write!(f, "{fill}")?;
+        }
+        f.write_str(&text)?;
+        for _ in 0..after {
+            write!(f, "{fill}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    input: String,
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a complex number like `3 + 4.5i`", self.input)
+    }
+}
+
+impl error::Error for ParseComplexError {}
+
+// `3 + 4.5i`, `3-4i`, `-2i`, `i`, `7`. Whitespace is ignored.
+impl<T: Num + FromStr> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> Result<Complex<T>, ParseComplexError> {
+        let err = || ParseComplexError { input: s.to_string() };
+        let text: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if text.is_empty() {
+            return Err(err());
+        }
+
+        // The sign between the two parts is the last one that doesn't follow
+        // an exponent marker or start the string.
+        let bytes = text.as_bytes();
+        let split = (1..bytes.len())
+            .rev()
+            .find(|&i| matches!(bytes[i], b'+' | b'-') && !matches!(bytes[i - 1], b'e' | b'E'));
+        let number = |part: &str| part.parse::<T>().map_err(|_| err());
+
+        let Some(imag) = text.strip_suffix('i') else {
+            // No imaginary part, so there must be no second term either.
+            return match split {
+                Some(_) => Err(err()),
+                None => Ok(Complex::from(number(&text)?)),
+            };
+        };
+        let (real, imag) = match split {
+            Some(i) => (number(&text[..i])?, &imag[i..]),
+            None => (T::ZERO, imag),
+        };
+        let imag = match imag {
+            "" | "+" => T::ONE,
+            "-" => -T::ONE,
+            _ => number(imag)?,
+        };
+        Ok(Complex::new(real, imag))
+    }
+}
@@ -1,7 +1,19 @@
+#![allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::print_literal,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
+pub mod color;
+pub mod complex;
+
 use std::fmt;
 
-#[allow(dead_code)]
-fn learn_print() {
+pub fn learn_print() {
     // In general, the `{}` will be automatically replaced with any
     // arguments. These will be stringified.
     println!("{} days", 31);
@@ -51,7 +63,7 @@ fn learn_print() {
     //fmt::Display.
     // fmt::Display::fmt(&Structure(3), &mut ::std::io::stdout());
     // println!("This struct `{}` won't print...", Structure(3));
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0277)
 
     // For Rust 1.58 and above, you can directly capture the argument from a
     // surrounding variable. Just like the above, this will output
@@ -64,15 +76,16 @@ fn learn_print() {
     println!("pi = {:#<10}", pi);
 }
 
-#[allow(dead_code)]
-fn learn_debug() {
+pub fn learn_debug() {
     // Derive the `fmt::Debug` implementation for `Structure`. `Structure`
     // is a structure which contains a single `i32`.
+    #[allow(dead_code)] // the field is only read by `Debug`
     #[derive(Debug)]
     struct Structure(i32);
 
     // Put a `Structure` inside of the structure `Deep`. Make it printable
     // also.
+    #[allow(dead_code)]
     #[derive(Debug)]
     struct Deep(Structure);
 
@@ -104,8 +117,7 @@ fn learn_debug() {
     println!("{:#?} {}, {}", peter, peter.name, peter.age);
 }
 
-#[allow(dead_code)]
-fn learn_display() {
+pub fn learn_display() {
     #[derive(Debug)]
     struct Point {
         x: i32,
@@ -146,24 +158,17 @@ fn learn_display() {
     let small_range: MinMax = MinMax(-100, 100);
     println!("The big range is {}, the small range is {}", big_range, small_range);
 
-    #[derive(Debug)]
-    struct Complex {
-        real: f64,
-        imag: f64,
-    }
-    impl fmt::Display for Complex {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "{} + {}i", self.real, self.imag)
-        }
-    }
+    // `Complex` lives in its own module, with the arithmetic to go with it.
+    use complex::Complex;
     let complex = Complex { real: 3.0, imag: 4.5 };
     println!("Display: {}", complex);
     println!("Debug: {:?}", complex);
+    println!("Precision and sign: {:+.2}", complex.conj());
+    println!("Squared: {}, norm: {}", complex * complex, complex.norm());
 
 }
 
-#[allow(dead_code)]
-fn learn_display_vec() {
+pub fn learn_display_vec() {
     #[derive(Debug)]
     struct List(Vec<i32>);
     impl fmt::Display for List {
@@ -188,8 +193,7 @@ fn learn_add_two(a : i32, b : i32) -> i32 {
     a + b
 }
 
-#[allow(dead_code)]
-fn learn_display_more() {
+pub fn learn_display_more() {
     #[derive(Debug)]
     struct Color {
         red: u8,
@@ -204,11 +208,18 @@ fn learn_display_more() {
     println!("Color: {}", Color { red: 0, green: 100, blue: 255 });
 }
 
-fn main() {
-    // learn_print();
-    // learn_debug();
-    // learn_display();
-    // learn_display_vec();
-    // println!("{}", learn_add_two(4, 5));
-    learn_display_more();
+// The same colour through the models of the `color` module.
+pub fn learn_color_models() {
+    use color::{Cmyk, Hsl, Hsv, Rgb};
+
+    let rgb: Rgb = "#0064FF".parse().unwrap();
+    println!("{} is {:#}", rgb, rgb);
+    println!("as {:.1}", Hsv::from(rgb));
+    println!("as {:.1}", Hsl::from(rgb));
+    println!("as {:.1}", Cmyk::from(rgb));
+
+    let named: Rgb = "RebeccaPurple".parse().unwrap();
+    println!("rebeccapurple is {}", named);
+    let hsl: Hsl = "hsl(120, 100%, 25%)".parse().unwrap();
+    println!("{} is {} ({:?})", hsl, Rgb::from(hsl), Rgb::from(hsl).name());
 }
@@ -0,0 +1,154 @@
+use hello_world::color::{Cmy, Cmyk, Color, Hsl, Hsv, ParseColorError, Rgb};
+use testutil::Rng;
+
+fn random_rgb(rng: &mut Rng) -> Rgb {
+    let bits = rng.next();
+    Rgb::new(bits as u8, (bits >> 8) as u8, (bits >> 16) as u8)
+}
+
+const SAMPLES: usize = 20_000;
+
+// Random colours plus the corners of the cube, where the formulas branch.
+fn sample_rgbs() -> Vec<Rgb> {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let mut colors: Vec<Rgb> = (0..8u8)
+        .map(|i| Rgb::new((i & 1) * 255, (i >> 1 & 1) * 255, (i >> 2 & 1) * 255))
+        .collect();
+    colors.extend((0..=255).map(|v| Rgb::new(v, v, v)));
+    colors.extend((0..SAMPLES).map(|_| random_rgb(&mut rng)));
+    colors
+}
+
+fn assert_close(a: &[f64], b: &[f64], what: &str) {
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() < 1e-9, "{what}: {a:?} != {b:?}");
+    }
+}
+
+#[test]
+fn rgb_survives_every_model() {
+    for rgb in sample_rgbs() {
+        assert_eq!(Rgb::from(Hsv::from(rgb)), rgb, "via HSV");
+        assert_eq!(Rgb::from(Hsl::from(rgb)), rgb, "via HSL");
+        assert_eq!(Rgb::from(Cmy::from(rgb)), rgb, "via CMY");
+        assert_eq!(Rgb::from(Cmyk::from(rgb)), rgb, "via CMYK");
+        assert_eq!(Rgb::from(Hsl::from(Hsv::from(rgb))), rgb, "via HSV and HSL");
+        assert_eq!(Rgb::from(Cmyk::from(Hsv::from(rgb))), rgb, "via HSV and CMYK");
+    }
+}
+
+#[test]
+fn direct_conversions_round_trip() {
+    let mut rng = Rng(42);
+    for _ in 0..SAMPLES {
+        let hsv = Hsv { hue: rng.unit() * 360.0, saturation: rng.unit(), value: rng.unit() };
+        let back = Hsv::from(Hsl::from(hsv));
+        // Hue and saturation are meaningless for black.
+        if hsv.value > 1e-6 {
+            assert_close(
+                &[back.hue, back.saturation, back.value],
+                &[hsv.hue, hsv.saturation, hsv.value],
+                "HSV -> HSL -> HSV",
+            );
+        }
+
+        let hsl = Hsl { hue: rng.unit() * 360.0, saturation: rng.unit(), lightness: rng.unit() };
+        let back = Hsl::from(Hsv::from(hsl));
+        if hsl.lightness > 1e-6 && hsl.lightness < 1.0 - 1e-6 {
+            assert_close(
+                &[back.hue, back.saturation, back.lightness],
+                &[hsl.hue, hsl.saturation, hsl.lightness],
+                "HSL -> HSV -> HSL",
+            );
+        }
+
+        let cmy = Cmy { cyan: rng.unit(), magenta: rng.unit(), yellow: rng.unit() };
+        let back = Cmy::from(Cmyk::from(cmy));
+        assert_close(
+            &[back.cyan, back.magenta, back.yellow],
+            &[cmy.cyan, cmy.magenta, cmy.yellow],
+            "CMY -> CMYK -> CMY",
+        );
+    }
+}
+
+#[test]
+fn display_parses_back() {
+    for rgb in sample_rgbs() {
+        assert_eq!(rgb.to_string().parse::<Rgb>(), Ok(rgb));
+        assert_eq!(format!("{rgb:#}").parse::<Rgb>(), Ok(rgb));
+        assert_eq!(rgb.to_string().to_lowercase().parse::<Rgb>(), Ok(rgb));
+    }
+}
+
+#[test]
+fn known_conversions() {
+    let red = Rgb::new(255, 0, 0);
+    assert_eq!(Hsv::from(red), Hsv { hue: 0.0, saturation: 1.0, value: 1.0 });
+    assert_eq!(Hsl::from(red), Hsl { hue: 0.0, saturation: 1.0, lightness: 0.5 });
+    assert_eq!(Cmyk::from(red), Cmyk { cyan: 0.0, magenta: 1.0, yellow: 1.0, key: 0.0 });
+
+    let black = Rgb::new(0, 0, 0);
+    assert_eq!(Cmyk::from(black), Cmyk { cyan: 0.0, magenta: 0.0, yellow: 0.0, key: 1.0 });
+
+    let rgb = Rgb::new(0, 100, 255);
+    assert_eq!(format!("{:.1}", Hsv::from(rgb)), "hsv(216.5, 100.0%, 100.0%)");
+    assert_eq!(format!("{:.1}", Hsl::from(rgb)), "hsl(216.5, 100.0%, 50.0%)");
+    assert_eq!(format!("{:.1}", Cmyk::from(rgb)), "cmyk(100.0%, 60.8%, 0.0%, 0.0%)");
+
+    // Hues wrap around.
+    let hsl = Hsl { hue: 480.0, saturation: 1.0, lightness: 0.5 };
+    assert_eq!(Rgb::from(hsl), Rgb::new(0, 255, 0));
+    let hsv = Hsv { hue: -120.0, saturation: 1.0, value: 1.0 };
+    assert_eq!(Rgb::from(hsv), Rgb::new(0, 0, 255));
+}
+
+#[test]
+fn parses_css_forms() {
+    assert_eq!("#0064FF".parse(), Ok(Rgb::new(0, 100, 255)));
+    assert_eq!("#f0a".parse(), Ok(Rgb::new(0xFF, 0x00, 0xAA)));
+    assert_eq!(" rgb( 0 , 100,255 ) ".parse(), Ok(Rgb::new(0, 100, 255)));
+    assert_eq!("RGB(1, 2, 3)".parse(), Ok(Rgb::new(1, 2, 3)));
+    assert_eq!("rebeccapurple".parse(), Ok(Rgb::new(0x66, 0x33, 0x99)));
+    assert_eq!("LightGoldenrodYellow".parse(), Ok(Rgb::new(0xFA, 0xFA, 0xD2)));
+
+    assert_eq!(
+        "hsl(120, 100%, 25%)".parse(),
+        Ok(Hsl { hue: 120.0, saturation: 1.0, lightness: 0.25 })
+    );
+    assert_eq!("hsl(-90deg, 0%, 100%)".parse::<Hsl>().unwrap().hue, 270.0);
+    assert_eq!(Rgb::from("hsl(120, 100%, 25%)".parse::<Hsl>().unwrap()), Rgb::new(0, 128, 0));
+
+    assert_eq!("hsl(0, 0%, 0%)".parse::<Color>().unwrap().to_rgb(), Rgb::new(0, 0, 0));
+    assert!(matches!("hsl(0, 0%, 0%)".parse::<Color>(), Ok(Color::Hsl(_))));
+    assert!(matches!("navy".parse::<Color>(), Ok(Color::Rgb(_))));
+}
+
+#[test]
+fn rejects_malformed_colours() {
+    let parse = |s: &str| s.parse::<Color>().unwrap_err();
+    assert_eq!(parse("  "), ParseColorError::Empty);
+    assert_eq!(parse("#12345"), ParseColorError::BadHex("#12345".to_string()));
+    assert_eq!(parse("#ggg"), ParseColorError::BadHex("#ggg".to_string()));
+    assert_eq!(parse("rgb(1, 2)"), ParseColorError::BadSyntax("rgb(1, 2)".to_string()));
+    assert_eq!(parse("rgb(1, x, 2)"), ParseColorError::BadSyntax("rgb(1, x, 2)".to_string()));
+    assert_eq!(parse("rgb(256, 0, 0)"), ParseColorError::OutOfRange("rgb(256, 0, 0)".to_string()));
+    assert_eq!(parse("rgb(-1, 0, 0)"), ParseColorError::OutOfRange("rgb(-1, 0, 0)".to_string()));
+    assert_eq!(parse("hsl(0, 50, 50%)"), ParseColorError::BadSyntax("hsl(0, 50, 50%)".to_string()));
+    assert_eq!(parse("hsl(0, 150%, 50%)"), ParseColorError::OutOfRange("hsl(0, 150%, 50%)".to_string()));
+    assert_eq!(parse("blurple"), ParseColorError::UnknownName("blurple".to_string()));
+    assert_eq!(parse("blurple").to_string(), "unknown colour name `blurple`");
+}
+
+#[test]
+fn named_colours() {
+    assert_eq!(Rgb::named("Gray"), Rgb::named("grey"));
+    assert_eq!(Rgb::new(0, 255, 255).name(), Some("aqua"));
+    assert_eq!(Rgb::new(0x66, 0x33, 0x99).name(), Some("rebeccapurple"));
+    assert_eq!(Rgb::new(1, 2, 3).name(), None);
+    // Every name maps back to itself or an alias of the same colour.
+    for name in ["red", "white", "papayawhip", "yellowgreen", "aliceblue"] {
+        let rgb = Rgb::named(name).unwrap();
+        assert_eq!(Rgb::named(rgb.name().unwrap()), Some(rgb));
+    }
+}
@@ -0,0 +1,157 @@
+use std::f64::consts::{E, PI};
+
+use hello_world::complex::Complex;
+
+type C = Complex<f64>;
+
+fn c(real: f64, imag: f64) -> C {
+    Complex::new(real, imag)
+}
+
+fn assert_close(a: C, b: C) {
+    assert!((a - b).norm() < 1e-9, "{a} != {b}");
+}
+
+#[test]
+fn operators_with_complex_operands() {
+    let a = c(3.0, 4.0);
+    let b = c(1.0, -2.0);
+    assert_eq!(a + b, c(4.0, 2.0));
+    assert_eq!(a - b, c(2.0, 6.0));
+    assert_eq!(a * b, c(11.0, -2.0));
+    assert_close(a / b, c(-1.0, 2.0));
+    assert_close(a / b * b, a);
+    assert_eq!(-a, c(-3.0, -4.0));
+
+    let mut x = a;
+    x += b;
+    x -= b;
+    x *= b;
+    x /= b;
+    assert_close(x, a);
+
+    assert_eq!([a, b, C::i()].into_iter().sum::<C>(), c(4.0, 3.0));
+    assert_eq!([C::i(), C::i()].into_iter().product::<C>(), c(-1.0, 0.0));
+}
+
+#[test]
+fn operators_with_scalar_operands() {
+    let a = c(3.0, 4.0);
+    assert_eq!(a + 1.0, c(4.0, 4.0));
+    assert_eq!(a - 1.0, c(2.0, 4.0));
+    assert_eq!(a * 2.0, c(6.0, 8.0));
+    assert_eq!(a / 2.0, c(1.5, 2.0));
+    assert_eq!(a % 2.0, c(1.0, 0.0));
+    assert_eq!(1.0 + a, c(4.0, 4.0));
+    assert_eq!(1.0 - a, c(-2.0, -4.0));
+    assert_eq!(2.0 * a, c(6.0, 8.0));
+    assert_close(25.0 / a, c(3.0, -4.0));
+
+    let mut x = a;
+    x += 1.0;
+    x *= 2.0;
+    x -= 2.0;
+    x /= 2.0;
+    assert_eq!(x, a);
+}
+
+#[test]
+fn integer_components() {
+    let a = Complex::new(7, 5);
+    let b = Complex::new(2, -1);
+    assert_eq!(a * b, Complex::new(19, 3));
+    // 7 + 5i = (2 - i)(1 + 3i) + (2 + 0i)
+    assert_eq!(a / b, Complex::new(1, 3));
+    assert_eq!(a % b, Complex::new(2, 0));
+    assert_eq!((a / b) * b + a % b, a);
+    assert_eq!(Complex::new(1, 1).powi(4), Complex::new(-4, 0));
+    assert_eq!(3 * Complex::i(), Complex::new(0, 3));
+    assert_eq!(a.conj(), Complex::new(7, -5));
+    assert_eq!(a.norm_sqr(), 74);
+}
+
+#[test]
+fn polar_form() {
+    let z = c(1.0, 1.0);
+    assert!((z.norm() - 2f64.sqrt()).abs() < 1e-12);
+    assert!((z.arg() - PI / 4.0).abs() < 1e-12);
+    assert_eq!(c(-1.0, 0.0).arg(), PI);
+    let (r, theta) = z.to_polar();
+    assert_close(C::from_polar(r, theta), z);
+    assert_close(C::from_polar(2.0, PI / 2.0), c(0.0, 2.0));
+}
+
+#[test]
+fn exp_ln_and_powers() {
+    // Euler's identity.
+    assert_close(c(0.0, PI).exp() + 1.0, c(0.0, 0.0));
+    assert_close(c(1.0, 0.0).exp(), c(E, 0.0));
+    let z = c(0.5, -1.25);
+    assert_close(z.ln().exp(), z);
+    assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+
+    assert_close(z.powi(3), z * z * z);
+    assert_close(z.powi(-2), (z * z).inv());
+    assert_close(z.powf(2.0), z * z);
+    assert_close(z.powc(c(2.0, 0.0)), z * z);
+    // i^i is real.
+    assert_close(C::i().powc(C::i()), c((-PI / 2.0).exp(), 0.0));
+    assert_eq!(c(0.0, 0.0).powf(2.0), c(0.0, 0.0));
+}
+
+#[test]
+fn roots() {
+    assert_close(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_close(c(3.0, 4.0).sqrt(), c(2.0, 1.0));
+
+    let z = c(-8.0, 0.0);
+    let roots = z.roots(3);
+    assert_eq!(roots.len(), 3);
+    assert_close(roots[0], c(1.0, 3f64.sqrt()));
+    assert_close(roots[1], c(-2.0, 0.0));
+    for root in roots {
+        assert_close(root.powi(3), z);
+    }
+    assert!(z.roots(0).is_empty());
+}
+
+#[test]
+fn display_respects_flags() {
+    let z = c(3.0, 4.5);
+    assert_eq!(z.to_string(), "3 + 4.5i");
+    assert_eq!(format!("{z:.2}"), "3.00 + 4.50i");
+    assert_eq!(format!("{z:+.2}"), "+3.00 + 4.50i");
+    assert_eq!(format!("{:+}", c(-1.0, -2.0)), "-1 - 2i");
+    assert_eq!(format!("{:.1}", c(0.0, -0.0)), "0.0 - 0.0i");
+    assert_eq!(format!("{z:>12}|"), "    3 + 4.5i|");
+    assert_eq!(format!("{z:*<10}|"), "3 + 4.5i**|");
+    assert_eq!(format!("{z:^12.1}|"), " 3.0 + 4.5i |");
+    assert_eq!(Complex::new(2, -7).to_string(), "2 - 7i");
+}
+
+#[test]
+fn parses_complex_numbers() {
+    assert_eq!("3 + 4.5i".parse(), Ok(c(3.0, 4.5)));
+    assert_eq!("-2i".parse(), Ok(c(0.0, -2.0)));
+    assert_eq!("3-4i".parse(), Ok(c(3.0, -4.0)));
+    assert_eq!("  7 ".parse(), Ok(c(7.0, 0.0)));
+    assert_eq!("i".parse(), Ok(c(0.0, 1.0)));
+    assert_eq!("1 - i".parse(), Ok(c(1.0, -1.0)));
+    assert_eq!("-1.5e-3 + 2E+2i".parse(), Ok(c(-1.5e-3, 200.0)));
+    assert_eq!("4 - 7i".parse(), Ok(Complex::new(4, -7)));
+
+    for bad in ["", "3 + 4", "3 +", "3 + 4j", "2i + 3", "x + yi", "1 +- 2i", "+2 + +3i"] {
+        assert!(bad.parse::<C>().is_err(), "{bad:?} parsed");
+    }
+    assert_eq!(
+        "3 + 4".parse::<C>().unwrap_err().to_string(),
+        "`3 + 4` is not a complex number like `3 + 4.5i`"
+    );
+}
+
+#[test]
+fn display_parses_back() {
+    for z in [c(3.0, 4.5), c(-0.25, -1e-7), c(1e300, 2.0), c(0.0, 0.0), c(-7.0, 0.0)] {
+        assert_eq!(z.to_string().parse::<C>(), Ok(z));
+    }
+}
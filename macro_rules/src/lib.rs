@@ -1,4 +1,17 @@
-fn hello() {
+#![allow(
+    dead_code,
+    clippy::eq_op,
+    clippy::erasing_op,
+    clippy::ptr_arg,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
+pub mod reflect;
+
+pub fn hello() {
     macro_rules! say_hello {
         // `()` indicates that the macro takes no argument.
         () => {
@@ -10,7 +23,7 @@ fn hello() {
     say_hello!();
 }
 
-fn designators() {
+pub fn designators() {
     macro_rules! create_function {
         // This macro takes an argument of designator `ident` and
         // creates a function named `$func_name`.
@@ -53,7 +66,7 @@ fn designators() {
     });
 }
 
-fn overload() {
+pub fn overload() {
     macro_rules! test {
         ($left:expr; and $right:expr) => {
             println!("{:?} and {:?} is {:?}", stringify!($left), stringify!($right), $left && $right);
@@ -66,7 +79,7 @@ fn overload() {
     test!(true; or false);
 }
 
-fn repeat() {
+pub fn repeat() {
     macro_rules! find_min {
         ($x:expr) => ($x);
         ($x:expr, $($y:expr),+) => {
@@ -78,7 +91,7 @@ fn repeat() {
     println!("{}", find_min!(5, 2*3, 4));
 }
 
-fn dry_code() {
+pub fn dry_code() {
     use std::ops::{Add, Mul, Sub};
 
     macro_rules! assert_equal_len {
@@ -128,7 +141,7 @@ fn dry_code() {
 
 }
 
-fn dsl_code() {
+pub fn dsl_code() {
     macro_rules! calculate {
         (eval $e:expr) => {
             {
@@ -155,11 +168,30 @@ fn dsl_code() {
     }
 }
 
-fn main() {
-    hello();
-    designators();
-    overload();
-    repeat();
-    dry_code();
-    dsl_code();
+pub fn reflection() {
+    // Declares the enum and generates `VARIANTS`, `name()`, `FromStr`,
+    // `TryFrom<i128>`, `Display` and `variants()` for it.
+    crate::reflect_enum! {
+        #[derive(Debug, PartialEq, Eq)]
+        enum TodoWithNumbers {
+            Add,
+            Subtract,
+            Multiply = 10,
+            Divide,
+        }
+    }
+
+    for todo in TodoWithNumbers::variants() {
+        println!("{:<8} = {}", todo, todo.discriminant());
+    }
+    println!("{:?}", TodoWithNumbers::VARIANTS);
+    println!("{:?}", "Divide".parse::<TodoWithNumbers>());
+    println!("{:?}", TodoWithNumbers::try_from(10));
+    match TodoWithNumbers::try_from(2) {
+        Ok(todo) => println!("{}", todo),
+        Err(err) => println!("Error: {}", err),
+    }
+    if let Err(err) = "Modulo".parse::<TodoWithNumbers>() {
+        println!("Error: {}", err);
+    }
 }
@@ -0,0 +1,135 @@
+// `reflect_enum!` declares a fieldless enum together with the boilerplate
+// that usually gets written by hand for it: a list of its variants, their
+// names, parsing by name, conversion from the discriminant and `Display`.
+//
+//     reflect_enum! {
+//         #[derive(Debug, PartialEq, Eq)]
+//         pub enum Level {
+//             Low = 1,
+//             Medium,
+//             High = 10,
+//         }
+//     }
+//
+//     assert_eq!(Level::VARIANTS, [Level::Low, Level::Medium, Level::High]);
+//     assert_eq!("High".parse(), Ok(Level::High));
+//     assert_eq!(Level::try_from(2), Ok(Level::Medium));
+//     assert_eq!(Level::Medium.to_string(), "Medium");
+//
+// Discriminants work as in a plain enum, counting up from the previous one.
+// They're read as an `i128`, which holds them exactly for every `#[repr]`
+// except `u128` values past `i128::MAX`.
+//
+// The generated code needs no derives, so the enum gets exactly the
+// attributes written in front of it, `#[repr]` included.
+
+use std::error;
+use std::fmt;
+
+// A name `FromStr` didn't recognise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant {
+    pub enum_name: &'static str,
+    pub name: String,
+}
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a variant of `{}`", self.name, self.enum_name)
+    }
+}
+
+impl error::Error for UnknownVariant {}
+
+// A number `TryFrom<i128>` found no variant for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownDiscriminant {
+    pub enum_name: &'static str,
+    pub value: i128,
+}
+
+impl fmt::Display for UnknownDiscriminant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no variant of `{}` has the discriminant {}", self.enum_name, self.value)
+    }
+}
+
+impl error::Error for UnknownDiscriminant {}
+
+#[macro_export]
+macro_rules! reflect_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $(= $discriminant:expr)?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant $(= $discriminant)?,
+            )*
+        }
+
+        impl $name {
+            // Every variant, in declaration order.
+            pub const VARIANTS: &'static [$name] = &[$($name::$variant),*];
+
+            // The variant's name as written in the declaration.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => ::std::stringify!($variant),)*
+                }
+            }
+
+            // Every variant by value, without needing `Copy`.
+            pub fn variants(
+            ) -> impl ::std::iter::DoubleEndedIterator<Item = $name> + ::std::iter::ExactSizeIterator
+            {
+                [$($name::$variant),*].into_iter()
+            }
+
+            pub fn discriminant(&self) -> i128 {
+                match self {
+                    $($name::$variant => $name::$variant as i128,)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::reflect::UnknownVariant;
+
+            fn from_str(s: &str) -> ::std::result::Result<$name, Self::Err> {
+                $name::variants().find(|v| v.name() == s).ok_or_else(|| {
+                    $crate::reflect::UnknownVariant {
+                        enum_name: ::std::stringify!($name),
+                        name: ::std::string::String::from(s),
+                    }
+                })
+            }
+        }
+
+        impl ::std::convert::TryFrom<i128> for $name {
+            type Error = $crate::reflect::UnknownDiscriminant;
+
+            fn try_from(value: i128) -> ::std::result::Result<$name, Self::Error> {
+                $name::variants().find(|v| v.discriminant() == value).ok_or(
+                    $crate::reflect::UnknownDiscriminant {
+                        enum_name: ::std::stringify!($name),
+                        value,
+                    },
+                )
+            }
+        }
+    };
+}
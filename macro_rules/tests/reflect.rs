@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use macro_rules::reflect::{UnknownDiscriminant, UnknownVariant};
+use macro_rules::reflect_enum;
+
+reflect_enum! {
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    pub enum Level {
+        Low = 1,
+        Medium,
+        // Attributes on variants are kept.
+        #[allow(dead_code)]
+        High = 10,
+    }
+}
+
+reflect_enum! {
+    #[derive(Debug, PartialEq)]
+    #[repr(i8)]
+    enum Sign {
+        Negative = -1,
+        Zero,
+        Positive,
+    }
+}
+
+// Derives of the enum's own don't clash with the macro's code, which needs
+// none.
+reflect_enum! {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Cloned {
+        One,
+        Two,
+    }
+}
+
+// Discriminants wider than an `i32` come back whole.
+reflect_enum! {
+    #[derive(Debug, PartialEq)]
+    #[repr(u32)]
+    enum Wide {
+        Top = u32::MAX,
+    }
+}
+
+reflect_enum! {
+    #[derive(Debug, PartialEq)]
+    #[repr(i64)]
+    enum Long {
+        Bottom = i64::MIN,
+        Next,
+    }
+}
+
+reflect_enum! {
+    enum Bare {
+        Only = 7,
+    }
+}
+
+#[test]
+fn lists_variants_in_order() {
+    assert_eq!(Level::VARIANTS, [Level::Low, Level::Medium, Level::High]);
+    assert_eq!(Level::variants().collect::<Vec<_>>(), Level::VARIANTS);
+    assert_eq!(Level::variants().len(), 3);
+    assert_eq!(Level::variants().next_back(), Some(Level::High));
+    assert_eq!(Level::variants().collect::<HashSet<_>>().len(), 3);
+}
+
+#[test]
+fn names_and_display() {
+    let names: Vec<&str> = Level::variants().map(|l| l.name()).collect();
+    assert_eq!(names, ["Low", "Medium", "High"]);
+    assert_eq!(Level::Medium.to_string(), "Medium");
+    assert_eq!(format!("[{:>6}]", Level::Low), "[   Low]");
+    assert_eq!(format!("[{:<6}]", Sign::Zero), "[Zero  ]");
+}
+
+#[test]
+fn parses_names_exactly() {
+    assert_eq!("High".parse(), Ok(Level::High));
+    assert_eq!(
+        "high".parse::<Level>(),
+        Err(UnknownVariant { enum_name: "Level", name: "high".into() })
+    );
+    assert_eq!(
+        "".parse::<Sign>().unwrap_err().to_string(),
+        "`` is not a variant of `Sign`"
+    );
+    for level in Level::variants() {
+        assert_eq!(level.to_string().parse(), Ok(level));
+    }
+}
+
+#[test]
+fn converts_from_discriminants() {
+    assert_eq!(Level::Low.discriminant(), 1);
+    assert_eq!(Level::Medium as i32, 2);
+    assert_eq!(Level::try_from(10), Ok(Level::High));
+    assert_eq!(Level::try_from(3), Err(UnknownDiscriminant { enum_name: "Level", value: 3 }));
+    assert_eq!(Sign::try_from(-1), Ok(Sign::Negative));
+    assert_eq!(Sign::try_from(1), Ok(Sign::Positive));
+    assert_eq!(std::mem::size_of::<Sign>(), 1);
+    assert_eq!(
+        Sign::try_from(2).unwrap_err().to_string(),
+        "no variant of `Sign` has the discriminant 2"
+    );
+    for sign in Sign::variants() {
+        assert_eq!(Sign::try_from(sign.discriminant()), Ok(sign));
+    }
+}
+
+#[test]
+fn wide_discriminants_round_trip() {
+    assert_eq!(Wide::Top.discriminant(), u32::MAX as i128);
+    assert_eq!(Wide::try_from(u32::MAX as i128), Ok(Wide::Top));
+    assert_eq!(Wide::try_from(-1), Err(UnknownDiscriminant { enum_name: "Wide", value: -1 }));
+    assert_eq!(Long::Next.discriminant(), i64::MIN as i128 + 1);
+    for long in Long::variants() {
+        assert_eq!(Long::try_from(long.discriminant()), Ok(long));
+    }
+    assert!(Long::try_from(i64::MIN as i128 - 1).is_err());
+}
+
+#[test]
+fn needs_no_derives() {
+    assert_eq!("Two".parse(), Ok(Cloned::Two));
+    assert_eq!(Cloned::try_from(0), Ok(Cloned::One));
+    assert_eq!(Cloned::One.clone().to_string(), "One");
+    assert!(matches!(Bare::try_from(7), Ok(Bare::Only)));
+    assert_eq!(Bare::Only.discriminant(), 7);
+    assert_eq!(Bare::variants().map(|b| b.to_string()).collect::<Vec<_>>(), ["Only"]);
+}
@@ -1,3 +1,13 @@
+#![allow(
+    dead_code,
+    clippy::needless_pub_self,
+    clippy::redundant_field_names,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
 mod my_mod {
     // Items in modules default to private visibility.
     fn private_function() {
@@ -79,7 +89,7 @@ fn function() {
     println!("called `function()`");
 }
 
-fn visiblity() {
+pub fn visiblity() {
     // Modules allow disambiguation between items that have the same name.
     function();
     my_mod::function();
@@ -96,26 +106,26 @@ fn visiblity() {
     // pub(in path) items can only be called from within the module specified
     // Error! function `public_function_in_my_mod` is private
     // my_mod::nested::public_function_in_my_mod();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0603)
 
     // Private items of a module cannot be directly accessed, even if
     // nested in a public module:
 
     // Error! `private_function` is private
     //my_mod::private_function();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0603)
 
     // Error! `private_function` is private
     //my_mod::nested::private_function();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0603)
 
     // Error! `private_nested` is a private module
     //my_mod::private_nested::function();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0603)
 
     // Error! `private_nested` is a private module
     //my_mod::private_nested::restricted_function();
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0603)
 }
 
 mod my {
@@ -139,7 +149,7 @@ mod my {
     }
 }
 
-fn struct_visiblity() {
+pub fn struct_visiblity() {
     // Public structs with public fields can be constructed as usual
     let open_box = my::OpenBox { contents: "public information" };
 
@@ -149,7 +159,7 @@ fn struct_visiblity() {
     // Public structs with private fields cannot be constructed using field names.
     // Error! `ClosedBox` has private fields
     //let closed_box = my::ClosedBox { contents: "classified information" };
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0451)
 
     // However, structs with private fields can be created using
     // public constructors
@@ -158,10 +168,5 @@ fn struct_visiblity() {
     // and the private fields of a public struct cannot be accessed.
     // Error! The `contents` field is private
     //println!("The closed box contains: {}", _closed_box.contents);
-    // TODO ^ Try uncommenting this line
-}
-
-fn main() {
-    visiblity();
-    struct_visiblity();
+    // TODO ^ Try uncommenting this line (E0616)
 }
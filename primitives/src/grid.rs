@@ -0,0 +1,359 @@
+// A 2D grid on the heap, grown out of `ys: [[i32; 10]; 500]` in
+// `arrays_slices`. The dimensions are chosen at runtime and every access is
+// checked like `slice::get`.
+//
+// Cells are addressed as `(x, y)`: `x` is the column counted from the left,
+// `y` the row counted from the top. They are stored row by row, so rows are
+// slices and columns are strided iterators.
+
+use std::error;
+use std::fmt;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// A column of a grid or view, top to bottom.
+pub type Column<'a, T> = StepBy<slice::Iter<'a, T>>;
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Grid { width, height, cells }
+    }
+
+    // Fails with `GridError::Ragged` unless every row has the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, GridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged { line: y + 1, expected: width, found: row.len() });
+            }
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    // Parses one row per line, with `cell` turning each character into a
+    // value or rejecting it with `None`.
+    pub fn parse_with(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, GridError> {
+        let mut rows = Vec::new();
+        for (y, line) in text.lines().enumerate() {
+            let row: Result<Vec<T>, GridError> = line
+                .chars()
+                .enumerate()
+                .map(|(x, c)| cell(c).ok_or(GridError::BadCell { line: y + 1, column: x + 1, found: c }))
+                .collect();
+            rows.push(row?);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.height).then(|| &mut self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<Column<'_, T>> {
+        (x < self.width).then(|| self.column_from(x))
+    }
+
+    // Also fine for a grid without rows, where `cells[x..]` would panic.
+    fn column_from(&self, x: usize) -> Column<'_, T> {
+        self.cells[x.min(self.cells.len())..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> + '_ {
+        (0..self.width).map(|x| self.column_from(x))
+    }
+
+    // Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells.iter().enumerate().map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // The first position, row by row, whose cell satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    // The up to 4 positions sharing an edge with `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self.width, self.height, x, y, &ORTHOGONAL)
+    }
+
+    // The up to 8 positions sharing an edge or a corner with `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self.width, self.height, x, y, &ALL_AROUND)
+    }
+
+    // The `width x height` rectangle with its top left corner at `(x, y)`, or
+    // `None` if it doesn't fit.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<View<'_, T>> {
+        let fits = x.checked_add(width).is_some_and(|right| right <= self.width)
+            && y.checked_add(height).is_some_and(|bottom| bottom <= self.height);
+        fits.then_some(View { grid: self, x, y, width, height })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // Renders the grid one character per cell, one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                text.push('\n');
+            }
+            text.extend(row.iter().map(&mut f));
+        }
+        text
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    // Rows become columns: the result is `height x width`.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    // A quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+
+    // A quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(self.width - 1 - y, x)].clone())
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let mut cells = self.cells.clone();
+        cells.reverse();
+        Grid { cells, ..*self }
+    }
+
+    // Mirrors left and right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+
+    // Mirrors top and bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self[(x, self.height - 1 - y)].clone())
+    }
+}
+
+// Any text with lines of equal length, one cell per character.
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Grid<char>, GridError> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+// Each cell's `Display`, one line per row, so a `Grid<char>` prints as the
+// text it was parsed from.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!("({x}, {y}) is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({x}, {y}) is outside the {width}x{height} grid"),
+        }
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const ALL_AROUND: [(isize, isize); 8] =
+    [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+// The in-bounds neighbours of a cell, clockwise starting from the one above
+// (or above left, for 8-connected neighbours).
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    offsets: slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbours {
+    fn new(width: usize, height: usize, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> Neighbours {
+        Neighbours { width, height, x, y, offsets: offsets.iter() }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        for &(dx, dy) in self.offsets.by_ref() {
+            let (Some(x), Some(y)) = (self.x.checked_add_signed(dx), self.y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if x < self.width && y < self.height {
+                return Some((x, y));
+            }
+        }
+        None
+    }
+}
+
+// A rectangular part of a grid, addressed from its own top left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&'a [T]> {
+        if y >= self.height {
+            return None;
+        }
+        let row = self.grid.row(self.y + y)?;
+        Some(&row[self.x..self.x + self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    // A smaller view inside this one.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<View<'a, T>> {
+        let fits = x.checked_add(width).is_some_and(|right| right <= self.width)
+            && y.checked_add(height).is_some_and(|bottom| bottom <= self.height);
+        fits.then_some(View { grid: self.grid, x: self.x + x, y: self.y + y, width, height })
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self.grid[(self.x + x, self.y + y)].clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    // A row whose length differs from the first one. Lines count from 1.
+    Ragged { line: usize, expected: usize, found: usize },
+    // A character `parse_with` rejected.
+    BadCell { line: usize, column: usize, found: char },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::Ragged { line, expected, found } => {
+                write!(f, "line {line} has {found} cells, expected {expected}")
+            }
+            GridError::BadCell { line, column, found } => {
+                write!(f, "unexpected {found:?} at line {line}, column {column}")
+            }
+        }
+    }
+}
+
+impl error::Error for GridError {}
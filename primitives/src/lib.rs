@@ -1,7 +1,19 @@
-use std::fmt;
+#![allow(
+    unused_assignments,
+    unused_variables,
+    clippy::nonminimal_bool,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
+pub mod grid;
+pub mod matrix;
+
 use std::mem;
 
-fn basic_operations() {
+pub fn basic_operations() {
     // Variables can be type annotated.
     let _logical: bool = true;
 
@@ -28,7 +40,7 @@ fn basic_operations() {
     let _mutable = true;
 }
 
-fn literals_operators() {
+pub fn literals_operators() {
     // Integer addition
     println!("1 + 2 = {}", 1u32 + 2);
 
@@ -55,25 +67,34 @@ fn literals_operators() {
     println!("One million is written as {}", 1_000_000u32);
 }
 
-fn tuples() {
-    #[derive(Debug)]
-    struct Matrix(f32, f32, f32, f32);
+pub fn tuples() {
+    // The `Matrix(f32, f32, f32, f32)` tuple struct grew into the
+    // const-generic `Matrix` in `matrix.rs`, and `reverse` into `transpose`.
+    use matrix::Matrix;
 
-    impl fmt::Display for Matrix {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "({}, {})\n({}, {})", self.0, self.1, self.2, self.3)
-        }
-    }
+    let matrix: Matrix<f32, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    println!("Transpose:\n{}", matrix.transpose());
 
-    fn reverse(matrix: Matrix) -> Matrix {
-        Matrix(matrix.0, matrix.2, matrix.1, matrix.3)
-    }
+    // The dimensions are part of the type: a 2x3 times a 3x1 is a 2x1.
+    let wide: Matrix<f32, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let tall: Matrix<f32, 3, 1> = Matrix::new([[1.0], [0.0], [-1.0]]);
+    println!("Product:\n{}", wide * tall);
+
+    // Error! A 2x3 matrix can't be multiplied by a 2x2 one.
+    // println!("{}", wide * matrix);
+    // TODO ^ Try uncommenting this line (E0277)
 
-    let matrix: Matrix = Matrix(1.0, 2.0, 3.0, 4.0);
-    println!("Transpose:\n{}", reverse(matrix));
+    println!("Determinant: {:.1}", matrix.determinant());
+    if let Some(inverse) = matrix.inverse() {
+        println!("Inverse:\n{:.1}", inverse);
+    }
+    if let Some([x, y]) = matrix.solve([5.0, 6.0]) {
+        println!("x + 2y = 5 and 3x + 4y = 6 at x = {:.1}, y = {:.1}", x, y);
+    }
 }
 
-fn arrays_slices() {
+// Panics: `a[..][too_far]` indexes one past the end of the slice.
+pub fn arrays_slices() {
     let a: [i32; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
     let ys: [[i32; 10]; 500] = [[0; 10]; 500];
     let slice = &a[2..5];
@@ -98,13 +119,17 @@ fn arrays_slices() {
     assert_eq!(Some(&2), a.get(1));
     // Out of bound indexing on array causes compile time error.
     println!("{}", a[9]);
-    // Out of bound indexing on slice causes runtime error.
-    println!("{}", a[..][9]);
-}
 
-fn main() {
-    basic_operations();
-    literals_operators();
-    tuples();
-    arrays_slices();
+    // `ys` again, as a `Grid` whose size is only known at runtime.
+    let mut grid = grid::Grid::new(10, 500, 0);
+    grid[(3, 2)] = 7;
+    println!("{:?} {:?}", grid.get(3, 2), grid.get(10, 0));
+
+    let maze: grid::Grid<char> = "#..\n##.".parse().unwrap();
+    println!("{}\nRotated:\n{}", maze, maze.rotate_cw());
+    println!("Next to (0, 0): {:?}", maze.neighbours4(0, 0).collect::<Vec<_>>());
+
+    // Out of bound indexing on slice causes runtime error.
+    let too_far = a.len();
+    println!("{}", a[..][too_far]);
 }
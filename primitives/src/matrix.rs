@@ -0,0 +1,356 @@
+// Matrices with their dimensions in the type, grown out of the 2x2 tuple
+// struct in `tuples`.
+//
+// `Matrix<T, R, C>` has `R` rows and `C` columns, so multiplying an `R x N`
+// by anything but an `N x C` doesn't compile. Arithmetic works for any
+// `Scalar`; determinants, inverses and solving linear systems need a `Field`
+// (`f32`/`f64`) and go through an LU decomposition with partial pivoting.
+
+use std::array;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+// What a matrix entry needs for `+`, `-` and `*`.
+pub trait Scalar: Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_scalar {
+    ($zero:literal, $one:literal: $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: $t = $zero;
+                const ONE: $t = $one;
+            }
+        )*
+    };
+}
+
+impl_scalar!(0, 1: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_scalar!(0.0, 1.0: f32, f64);
+
+// Entries that can be divided without losing the fraction, for elimination.
+pub trait Field: Scalar + PartialOrd + Div<Output = Self> + Neg<Output = Self> {
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_field {
+    ($($t:ident),*) => {
+        $(
+            impl Field for $t {
+                const EPSILON: $t = $t::EPSILON;
+
+                fn abs(self) -> $t {
+                    $t::abs(self)
+                }
+
+                fn from_usize(n: usize) -> $t {
+                    n as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_field!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub const fn new(rows: [[T; C]; R]) -> Matrix<T, R, C> {
+        Matrix { rows }
+    }
+
+    // Builds each entry from its `(row, column)`.
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Matrix<T, R, C> {
+        Matrix::new(array::from_fn(|r| array::from_fn(|c| f(r, c))))
+    }
+
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.rows
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn row(&self, r: usize) -> [T; C] {
+        self.rows[r]
+    }
+
+    pub fn column(&self, c: usize) -> [T; R] {
+        array::from_fn(|r| self.rows[r][c])
+    }
+
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|r, c| self.rows[c][r])
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(T) -> U) -> Matrix<U, R, C> {
+        Matrix::from_fn(|r, c| f(self.rows[r][c]))
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn zero() -> Matrix<T, R, C> {
+        Matrix::new([[T::ZERO; C]; R])
+    }
+}
+
+impl<T: Scalar, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Matrix<T, N, N> {
+        Matrix::from_fn(|r, c| if r == c { T::ONE } else { T::ZERO })
+    }
+
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::ZERO, |sum, i| sum + self.rows[i][i])
+    }
+}
+
+impl<T: Field, const N: usize> Matrix<T, N, N> {
+    // `None` when the matrix is singular, or so close to it that the
+    // elimination would only amplify rounding errors. A pivot counts as zero
+    // when it is within rounding error of the column it came from, so a
+    // column of small entries, like the `1e-20` in `[[1, 0], [0, 1e-20]]`,
+    // is judged on its own scale and not the matrix's.
+    pub fn lu(&self) -> Option<Lu<T, N>> {
+        let tolerances: [T; N] = array::from_fn(|k| {
+            let scale = self.rows.iter().fold(T::ZERO, |max, row| {
+                if row[k].abs() > max {
+                    row[k].abs()
+                } else {
+                    max
+                }
+            });
+            scale * T::EPSILON * T::from_usize(N)
+        });
+
+        let mut a = self.rows;
+        let mut permutation: [usize; N] = array::from_fn(|i| i);
+        let mut swaps = 0;
+        for k in 0..N {
+            // Partial pivoting: use the largest entry left in the column.
+            let mut pivot = k;
+            for i in k + 1..N {
+                if a[i][k].abs() > a[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            // A NaN pivot compares as `None` and counts as singular too.
+            if a[pivot][k].abs().partial_cmp(&tolerances[k]) != Some(Ordering::Greater) {
+                return None;
+            }
+            if pivot != k {
+                a.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            let (done, rest) = a.split_at_mut(k + 1);
+            let pivot_row = &done[k];
+            for row in rest {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (x, &p) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *x = *x - factor * p;
+                }
+            }
+        }
+        Some(Lu { lu: Matrix::new(a), permutation, swaps })
+    }
+
+    // Zero for a singular matrix.
+    pub fn determinant(&self) -> T {
+        self.lu().map_or(T::ZERO, |lu| lu.determinant())
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T, N, N>> {
+        self.lu().map(|lu| lu.inverse())
+    }
+
+    // The `x` with `self * x == b`, if there is exactly one.
+    pub fn solve(&self, b: [T; N]) -> Option<[T; N]> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+}
+
+// `P * A = L * U` with `L` unit lower triangular and `U` upper triangular,
+// both stored in one matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    lu: Matrix<T, N, N>,
+    // Row `i` of `P * A` is row `permutation[i]` of `A`.
+    permutation: [usize; N],
+    swaps: usize,
+}
+
+impl<T: Field, const N: usize> Lu<T, N> {
+    pub fn lower(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|r, c| match r.cmp(&c) {
+            Ordering::Greater => self.lu[(r, c)],
+            Ordering::Equal => T::ONE,
+            Ordering::Less => T::ZERO,
+        })
+    }
+
+    pub fn upper(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|r, c| if r <= c { self.lu[(r, c)] } else { T::ZERO })
+    }
+
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    pub fn determinant(&self) -> T {
+        let det = (0..N).fold(T::ONE, |det, i| det * self.lu[(i, i)]);
+        if self.swaps.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    pub fn solve(&self, b: [T; N]) -> [T; N] {
+        let a = &self.lu.rows;
+        // Forward substitution through `L`, then back substitution through `U`.
+        let mut x: [T; N] = array::from_fn(|i| b[self.permutation[i]]);
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - a[i][j] * x[j];
+            }
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] = x[i] - a[i][j] * x[j];
+            }
+            x[i] = x[i] / a[i][i];
+        }
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        let columns: [[T; N]; N] =
+            array::from_fn(|c| self.solve(array::from_fn(|r| if r == c { T::ONE } else { T::ZERO })));
+        Matrix::new(columns).transpose()
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Matrix<T, R, C> {
+        Matrix::new(rows)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        &self.rows[r][c]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        &mut self.rows[r][c]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn add(self, rhs: Matrix<T, R, C>) -> Matrix<T, R, C> {
+        Matrix::from_fn(|r, c| self.rows[r][c] + rhs.rows[r][c])
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn sub(self, rhs: Matrix<T, R, C>) -> Matrix<T, R, C> {
+        Matrix::from_fn(|r, c| self.rows[r][c] - rhs.rows[r][c])
+    }
+}
+
+impl<T: Scalar + Neg<Output = T>, const R: usize, const C: usize> Neg for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn neg(self) -> Matrix<T, R, C> {
+        self.map(|x| -x)
+    }
+}
+
+// `R x N` times `N x C`; any other shape is a type error.
+impl<T: Scalar, const R: usize, const N: usize, const C: usize> Mul<Matrix<T, N, C>>
+    for Matrix<T, R, N>
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: Matrix<T, N, C>) -> Matrix<T, R, C> {
+        Matrix::from_fn(|r, c| (0..N).fold(T::ZERO, |sum, k| sum + self.rows[r][k] * rhs.rows[k][c]))
+    }
+}
+
+// A matrix times a column vector.
+impl<T: Scalar, const R: usize, const C: usize> Mul<[T; C]> for Matrix<T, R, C> {
+    type Output = [T; R];
+
+    fn mul(self, rhs: [T; C]) -> [T; R] {
+        array::from_fn(|r| (0..C).fold(T::ZERO, |sum, k| sum + self.rows[r][k] * rhs[k]))
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: T) -> Matrix<T, R, C> {
+        self.map(|x| x * rhs)
+    }
+}
+
+// One row per line, each column right-aligned to its widest entry:
+//
+//     ( 1, 2.5)
+//     (10,  -3)
+//
+// The formatter's precision and `+` flag apply to every entry.
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match (f.precision(), f.sign_plus()) {
+                        (Some(p), true) => format!("{x:+.p$}"),
+                        (Some(p), false) => format!("{x:.p$}"),
+                        (None, true) => format!("{x:+}"),
+                        (None, false) => format!("{x}"),
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..C)
+            .map(|c| cells.iter().map(|row| row[c].chars().count()).max().unwrap_or(0))
+            .collect();
+
+        for (r, row) in cells.iter().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "(")?;
+            for (c, cell) in row.iter().enumerate() {
+                if c > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{cell:>width$}", width = widths[c])?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use primitives::grid::{Grid, GridError};
+
+// 3 wide, 2 high:
+//
+//     abc
+//     def
+fn letters() -> Grid<char> {
+    "abc\ndef".parse().unwrap()
+}
+
+#[test]
+fn checked_access() {
+    let mut grid = letters();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(2, 1), Some(&'f'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(grid[(1, 0)], 'b');
+
+    *grid.get_mut(1, 1).unwrap() = 'E';
+    grid[(0, 0)] = 'A';
+    assert_eq!(grid.to_string(), "Abc\ndEf");
+    assert_eq!(grid.get_mut(5, 5), None);
+}
+
+#[test]
+#[should_panic(expected = "(3, 0) is outside the 3x2 grid")]
+fn indexing_out_of_bounds_panics() {
+    let _ = letters()[(3, 0)];
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = letters();
+    assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+    assert_eq!(grid.row(2), None);
+    assert_eq!(grid.column(1).unwrap().collect::<String>(), "be");
+    assert!(grid.column(3).is_none());
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, ["abc", "def"]);
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+
+    let mut grid = grid;
+    grid.row_mut(0).unwrap().reverse();
+    assert_eq!(grid.to_string(), "cba\ndef");
+
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    assert_eq!(grid.positions().last(), Some((2, 1)));
+}
+
+#[test]
+fn empty_grids() {
+    let wide: Grid<u8> = Grid::new(4, 0, 0);
+    assert_eq!(wide.columns().map(|c| c.count()).collect::<Vec<_>>(), [0, 0, 0, 0]);
+    assert_eq!(wide.rows().count(), 0);
+    let tall: Grid<u8> = Grid::new(0, 3, 0);
+    assert_eq!(tall.rows().map(<[u8]>::len).collect::<Vec<_>>(), [0, 0, 0]);
+    assert_eq!(tall.iter().count(), 0);
+    assert_eq!("".parse::<Grid<char>>().unwrap().to_string(), "");
+}
+
+#[test]
+fn neighbours() {
+    let grid: Grid<u8> = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbours4(1, 1).collect::<Vec<_>>(), [(1, 0), (2, 1), (1, 2), (0, 1)]);
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), [(1, 1), (2, 1), (1, 2)]);
+    assert_eq!(grid.neighbours8(0, 2).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn views() {
+    let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+    let view = grid.view(1, 1, 2, 2).unwrap();
+    assert_eq!((view.width(), view.height()), (2, 2));
+    assert_eq!(view.get(0, 0), Some(&'f'));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(view.row(1), Some(&['j', 'k'][..]));
+    assert_eq!(view.row(2), None);
+    // Out of range, however far, without overflowing the offset.
+    assert_eq!(view.row(usize::MAX), None);
+    assert_eq!(view.get(usize::MAX, usize::MAX), None);
+    assert_eq!(view.rows().count(), 2);
+    assert_eq!(view.to_grid().to_string(), "fg\njk");
+    assert_eq!(view.view(1, 0, 1, 2).unwrap().to_grid().to_string(), "g\nk");
+    assert!(view.view(1, 1, 2, 1).is_none());
+
+    assert!(grid.view(3, 0, 2, 1).is_none());
+    assert!(grid.view(0, 0, 4, 3).is_some());
+    assert!(grid.view(usize::MAX, 0, 2, 1).is_none());
+}
+
+#[test]
+fn rotations_and_flips() {
+    let grid = letters();
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_180().to_string(), "fed\ncba");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+
+    assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw(), grid.rotate_ccw());
+    assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+}
+
+#[test]
+fn parse_and_render() {
+    let text = "#.#\n..#\n";
+    let walls = Grid::parse_with(text, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(walls.get(2, 1), Some(&true));
+    assert_eq!(walls.render(|&wall| if wall { '█' } else { ' ' }), "█ █\n  █");
+    assert_eq!(walls.map(|&wall| u8::from(wall)).to_string(), "101\n001");
+
+    assert_eq!(
+        Grid::parse_with("##\n#x", |c| (c == '#').then_some(())),
+        Err(GridError::BadCell { line: 2, column: 2, found: 'x' })
+    );
+    let ragged = "abc\nde".parse::<Grid<char>>().unwrap_err();
+    assert_eq!(ragged, GridError::Ragged { line: 2, expected: 3, found: 2 });
+    assert_eq!(ragged.to_string(), "line 2 has 2 cells, expected 3");
+
+    // `Display` gives back the text it was parsed from.
+    let text = "..#..\n.###.\n#####";
+    assert_eq!(text.parse::<Grid<char>>().unwrap().to_string(), text);
+
+    let numbers = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!(numbers, Grid::from_fn(2, 2, |x, y| 1 + x + 2 * y));
+}
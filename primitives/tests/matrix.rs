@@ -0,0 +1,100 @@
+use primitives::matrix::Matrix;
+
+fn assert_close<const R: usize, const C: usize>(a: Matrix<f64, R, C>, b: Matrix<f64, R, C>) {
+    let worst = (a - b).into_rows().iter().flatten().fold(0.0f64, |m, x| m.max(x.abs()));
+    assert!(worst < 1e-9, "\n{a}\n!=\n{b}");
+}
+
+#[test]
+fn construction_and_access() {
+    let m = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(m[(1, 2)], 6);
+    assert_eq!(m.row(0), [1, 2, 3]);
+    assert_eq!(m.column(1), [2, 5]);
+    assert_eq!(m.transpose(), Matrix::new([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(Matrix::<i32, 2, 3>::from_fn(|r, c| (r * 3 + c + 1) as i32), m);
+    assert_eq!(Matrix::<u8, 2, 2>::identity(), Matrix::new([[1, 0], [0, 1]]));
+    assert_eq!(Matrix::<i32, 1, 2>::zero(), Matrix::new([[0, 0]]));
+
+    let mut m = m;
+    m[(0, 0)] = 10;
+    assert_eq!(m.row(0), [10, 2, 3]);
+}
+
+#[test]
+fn arithmetic() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[0, 1], [1, 0]]);
+    assert_eq!(a + b, Matrix::new([[1, 3], [4, 4]]));
+    assert_eq!(a - b, Matrix::new([[1, 1], [2, 4]]));
+    assert_eq!(-a, Matrix::new([[-1, -2], [-3, -4]]));
+    assert_eq!(a * 2, Matrix::new([[2, 4], [6, 8]]));
+    assert_eq!(a * b, Matrix::new([[2, 1], [4, 3]]));
+    assert_eq!(a * Matrix::identity(), a);
+    assert_eq!(a * [1, 1], [3, 7]);
+    assert_eq!(a.trace(), 5);
+
+    // 2x3 * 3x1 = 2x1
+    let wide = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let tall = Matrix::new([[1], [0], [-1]]);
+    let product: Matrix<i32, 2, 1> = wide * tall;
+    assert_eq!(product, Matrix::new([[-2], [-2]]));
+    assert_eq!(tall.transpose() * wide.transpose(), product.transpose());
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Matrix::new([[3.0]]).determinant(), 3.0);
+    assert!((Matrix::new([[1.0f64, 2.0], [3.0, 4.0]]).determinant() + 2.0).abs() < 1e-12);
+    let m = Matrix::new([[2.0f64, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]]);
+    assert!((m.determinant() - 49.0).abs() < 1e-9);
+    // Swapping two rows flips the sign.
+    let swapped = Matrix::new([m.row(1), m.row(0), m.row(2)]);
+    assert!((swapped.determinant() + 49.0).abs() < 1e-9);
+    assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).determinant(), 0.0);
+    assert_eq!(Matrix::<f64, 0, 0>::new([]).determinant(), 1.0);
+}
+
+#[test]
+fn lu_reassembles_the_matrix() {
+    let m = Matrix::new([[0.0, 2.0, 1.0], [1.0, 1.0, 1.0], [4.0, -2.0, 3.0]]);
+    let lu = m.lu().unwrap();
+    let p = lu.permutation();
+    let permuted = Matrix::new([m.row(p[0]), m.row(p[1]), m.row(p[2])]);
+    assert_close(lu.lower() * lu.upper(), permuted);
+}
+
+#[test]
+fn inverse_and_solve() {
+    let m = Matrix::new([[4.0, 7.0, 2.0], [3.0, 6.0, 1.0], [2.0, 5.0, 3.0]]);
+    let inverse = m.inverse().unwrap();
+    assert_close(m * inverse, Matrix::identity());
+    assert_close(inverse * m, Matrix::identity());
+
+    let x = [1.0, -2.0, 0.5];
+    let b = m * x;
+    let solved = m.solve(b).unwrap();
+    assert_close(Matrix::new([solved]), Matrix::new([x]));
+
+    let singular = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(singular.inverse(), None);
+    assert_eq!(singular.solve([1.0, 2.0, 3.0]), None);
+    assert_eq!(Matrix::new([[f64::NAN]]).inverse(), None);
+
+    // Small entries aren't rounding error.
+    let small = Matrix::new([[1.0, 0.0], [0.0, 1e-20]]);
+    assert_eq!(small.determinant(), 1e-20);
+    assert_eq!(small.inverse(), Some(Matrix::new([[1.0, 0.0], [0.0, 1e20]])));
+    assert_eq!(Matrix::new([[1e-300, 0.0], [0.0, 1e-300]]).solve([1e-300, 2e-300]), Some([1.0, 2.0]));
+}
+
+#[test]
+fn display_aligns_columns() {
+    let m = Matrix::new([[1.0, 2.5], [10.0, -3.0]]);
+    assert_eq!(m.to_string(), "( 1, 2.5)\n(10,  -3)");
+    assert_eq!(format!("{m:.1}"), "( 1.0,  2.5)\n(10.0, -3.0)");
+    assert_eq!(format!("{m:+}"), "( +1, +2.5)\n(+10,   -3)");
+    assert_eq!(Matrix::new([[1, 2, 3]]).to_string(), "(1, 2, 3)");
+    assert_eq!(Matrix::<i32, 0, 0>::new([]).to_string(), "");
+}
@@ -0,0 +1,257 @@
+// Generates the chapter registry by scanning every example crate for its
+// chapters. A chapter is any top-level `pub fn name()` in the crate's
+// `src/lib.rs`, taking nothing and returning nothing, so adding an example is
+// enough to make it runnable. The signature may be split across lines and
+// have attributes or a `where` clause; `pub fn`s nested in modules, impls or
+// other functions are not chapters.
+//
+// A chapter that fails on purpose declares it with a `// Panics: <why>`
+// comment in the comment block above the function; the runner then reports
+// its panic as expected instead of as a failure.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+// In the order of the book, which is also the order `list` prints them in.
+const CRATES: &[&str] = &[
+    "hello_world",
+    "primitives",
+    "custom_types",
+    "flow_control",
+    "functions",
+    "modules",
+    "crates",
+    "generics",
+    "scoping_rules",
+    "traits",
+    "macro_rules",
+    "error_handling",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+    // A `//` comment, without the slashes.
+    Comment(String),
+    // An empty line, which ends a comment block.
+    BlankLine,
+    // A string, character or number literal; only its position matters.
+    Literal,
+}
+
+// Splits Rust source into just enough tokens to find top-level items.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            let start = i;
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            if chars[start..i].iter().filter(|&&c| c == '\n').count() > 1 {
+                tokens.push(Token::BlankLine);
+            }
+        } else if c == '/' && next == Some('/') {
+            let end = chars[i..].iter().position(|&c| c == '\n').map_or(chars.len(), |n| i + n);
+            let text: String = chars[i + 2..end].iter().collect();
+            tokens.push(Token::Comment(text.trim_start_matches('/').trim().to_string()));
+            i = end;
+        } else if c == '/' && next == Some('*') {
+            // Block comments nest.
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+        } else if let Some(end) = raw_string_end(&chars, i) {
+            tokens.push(Token::Literal);
+            i = end;
+        } else if c == '"' || (c == 'b' && next == Some('"')) {
+            i += if c == 'b' { 2 } else { 1 };
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            tokens.push(Token::Literal);
+            i += 1;
+        } else if c == '\'' {
+            // A character literal, unless it is a lifetime like `'a`.
+            let end = if next == Some('\\') {
+                chars[i + 3..].iter().position(|&c| c == '\'').map(|n| i + 3 + n)
+            } else {
+                Some(i + 2).filter(|&end| chars.get(end) == Some(&'\''))
+            };
+            match end {
+                Some(end) => {
+                    tokens.push(Token::Literal);
+                    i = end + 1;
+                }
+                None => {
+                    tokens.push(Token::Punct('\''));
+                    i += 1;
+                }
+            }
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            if c.is_ascii_digit() {
+                tokens.push(Token::Literal);
+            } else {
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+// Where a raw string like `r#"..."#` starting at `i` ends, if one does.
+fn raw_string_end(chars: &[char], i: usize) -> Option<usize> {
+    let mut j = i;
+    if chars.get(j) == Some(&'b') {
+        j += 1;
+    }
+    if chars.get(j) != Some(&'r') {
+        return None;
+    }
+    j += 1;
+    let hashes = chars[j..].iter().take_while(|&&c| c == '#').count();
+    j += hashes;
+    if chars.get(j) != Some(&'"') {
+        return None;
+    }
+    j += 1;
+    while j < chars.len() {
+        if chars[j] == '"' && chars[j + 1..].iter().take(hashes).filter(|&&c| c == '#').count() == hashes
+        {
+            return Some(j + 1 + hashes);
+        }
+        j += 1;
+    }
+    Some(chars.len())
+}
+
+struct ChapterFn {
+    name: String,
+    expected_panic: Option<String>,
+}
+
+// The chapters among the top-level items of `tokens`.
+fn chapters(tokens: &[Token]) -> Vec<ChapterFn> {
+    let mut found = Vec::new();
+    let mut depth = 0usize;
+    // The comment block above the item being read.
+    let mut comments: Vec<&str> = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Comment(text) => {
+                comments.push(text);
+                i += 1;
+                continue;
+            }
+            Token::BlankLine => comments.clear(),
+            Token::Punct('{' | '(' | '[') => depth += 1,
+            Token::Punct(')' | ']') => depth = depth.saturating_sub(1),
+            Token::Punct('}') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    comments.clear();
+                }
+            }
+            Token::Punct(';') if depth == 0 => comments.clear(),
+            Token::Ident(word) if depth == 0 && word == "pub" => {
+                if let Some((name, body)) = chapter_signature(&tokens[i + 1..]) {
+                    let expected_panic = comments
+                        .iter()
+                        .find_map(|comment| comment.strip_prefix("Panics:"))
+                        .map(|why| why.trim().to_string());
+                    found.push(ChapterFn { name, expected_panic });
+                    // Carry on from the body's opening brace.
+                    i += 1 + body;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    found
+}
+
+// For tokens following a `pub`, the chapter's name and the offset of its
+// body's `{` if they are `fn name()` with no generics, parameters or return
+// type.
+fn chapter_signature(tokens: &[Token]) -> Option<(String, usize)> {
+    let ident = |i: usize| match tokens.get(i) {
+        Some(Token::Ident(word)) => Some(word.as_str()),
+        _ => None,
+    };
+    let punct = |i: usize, c: char| tokens.get(i) == Some(&Token::Punct(c));
+    let signature: Vec<usize> = (0..tokens.len())
+        .filter(|&i| !matches!(tokens[i], Token::Comment(_) | Token::BlankLine))
+        .collect();
+    let at = |n: usize| signature.get(n).copied().unwrap_or(tokens.len());
+    if ident(at(0)) != Some("fn") {
+        return None;
+    }
+    let name = ident(at(1))?;
+    if !(punct(at(2), '(') && punct(at(3), ')')) {
+        return None;
+    }
+    match (ident(at(4)), punct(at(4), '{')) {
+        (_, true) => Some((name.to_string(), at(4))),
+        // A `where` clause runs up to the body.
+        (Some("where"), _) => {
+            let body = (4..).map(at).take_while(|&i| i < tokens.len()).find(|&i| punct(i, '{'))?;
+            Some((name.to_string(), body))
+        }
+        _ => None,
+    }
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let workspace = Path::new(&manifest_dir).parent().unwrap();
+
+    let mut registry = String::from("pub static CHAPTERS: &[Chapter] = &[\n");
+    for krate in CRATES {
+        let path = workspace.join(krate).join("src").join("lib.rs");
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let source = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("can't read {}: {e}", path.display()));
+        for ChapterFn { name, expected_panic } in chapters(&tokenize(&source)) {
+            writeln!(
+                registry,
+                "    Chapter {{ krate: {krate:?}, name: {name:?}, run: {krate}::{name}, \
+                 expected_panic: {expected_panic:?} }},"
+            )
+            .unwrap();
+        }
+    }
+    registry.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("chapters.rs");
+    fs::write(out, registry).unwrap();
+}
@@ -0,0 +1,188 @@
+// Runs chapters so that a panic in one of them is caught and reported instead
+// of taking the whole process down.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Once;
+
+use crate::Chapter;
+
+thread_local! {
+    // Set while a chapter runs under `run_isolated` on this thread.
+    static ISOLATING: Cell<bool> = const { Cell::new(false) };
+    // Filled in by the quiet hook when an isolated chapter panics.
+    static LAST_PANIC: RefCell<Option<PanicDetails>> = const { RefCell::new(None) };
+}
+
+// Where and why a chapter panicked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicDetails {
+    pub message: String,
+    pub location: String,
+}
+
+impl PanicDetails {
+    fn from_hook(info: &PanicHookInfo) -> PanicDetails {
+        let payload = info.payload();
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            String::from("Box<dyn Any>")
+        };
+        let location = match info.location() {
+            Some(l) => format!("{}:{}:{}", l.file(), l.line(), l.column()),
+            None => String::from("<unknown>"),
+        };
+        PanicDetails { message, location }
+    }
+}
+
+impl fmt::Display for PanicDetails {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Multi-line messages such as `assert_eq!` failures go on one line.
+        let message: Vec<&str> = self.message.lines().map(str::trim).collect();
+        write!(f, "{} ({})", message.join(" "), self.location)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    // The chapter panicked and declares that it should, for the given reason.
+    ExpectedPanic(&'static str, PanicDetails),
+    Panicked(PanicDetails),
+    // The chapter declares a panic for the given reason but returned normally.
+    MissingPanic(&'static str),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Panicked(_) | Outcome::MissingPanic(_))
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Passed => "ok",
+            Outcome::ExpectedPanic(..) => "expected",
+            Outcome::Panicked(_) => "PANIC",
+            Outcome::MissingPanic(_) => "NO PANIC",
+        }
+    }
+}
+
+// Replaces the panic hook with one that stays quiet for isolated chapters and
+// records the panic for the report. Other panics still reach the old hook.
+fn install_quiet_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATING.get() {
+                LAST_PANIC.set(Some(PanicDetails::from_hook(info)));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+impl Chapter {
+    // Runs the chapter like `run_to`, but catches a panic and reports it in
+    // the outcome. The output printed before the panic still reaches `out`.
+    pub fn run_isolated(&self, out: &mut dyn Write) -> io::Result<Outcome> {
+        install_quiet_hook();
+        LAST_PANIC.set(None);
+        ISOLATING.set(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| self.run_to(out)));
+        ISOLATING.set(false);
+
+        let outcome = match result {
+            Ok(written) => {
+                written?;
+                match self.expected_panic {
+                    Some(reason) => Outcome::MissingPanic(reason),
+                    None => Outcome::Passed,
+                }
+            }
+            Err(_) => {
+                let details = LAST_PANIC.take().unwrap_or_else(|| PanicDetails {
+                    message: String::from("<panic hook was replaced>"),
+                    location: String::from("<unknown>"),
+                });
+                match self.expected_panic {
+                    Some(reason) => Outcome::ExpectedPanic(reason, details),
+                    None => Outcome::Panicked(details),
+                }
+            }
+        };
+        Ok(outcome)
+    }
+}
+
+// Summary of a run, displayed as one table row per chapter.
+#[derive(Debug, Default)]
+pub struct Report {
+    entries: Vec<(&'static Chapter, Outcome)>,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    pub fn push(&mut self, chapter: &'static Chapter, outcome: Outcome) {
+        self.entries.push((chapter, outcome));
+    }
+
+    pub fn failures(&self) -> usize {
+        self.entries.iter().filter(|(_, outcome)| outcome.is_failure()).count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .entries
+            .iter()
+            .map(|(chapter, _)| chapter.to_string().len())
+            .chain(Some("chapter".len()))
+            .max()
+            .unwrap();
+
+        writeln!(f, "{:width$}  {:8}  details", "chapter", "result")?;
+        for (chapter, outcome) in &self.entries {
+            let chapter = chapter.to_string();
+            let details = match outcome {
+                Outcome::Passed => String::new(),
+                Outcome::ExpectedPanic(reason, details) => {
+                    format!("declared to panic ({reason}): {details}")
+                }
+                Outcome::Panicked(details) => details.to_string(),
+                Outcome::MissingPanic(reason) => {
+                    format!("declared to panic ({reason}) but returned normally")
+                }
+            };
+            let row = format!("{chapter:width$}  {:8}  {details}", outcome.label());
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        let expected = self
+            .entries
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, Outcome::ExpectedPanic(..)))
+            .count();
+        let failed = self.failures();
+        write!(
+            f,
+            "{} chapters: {} passed, {} panicked as expected, {} failed",
+            self.entries.len(),
+            self.entries.len() - expected - failed,
+            expected,
+            failed
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::io::{self, Write};
+
+mod isolate;
+
+pub use isolate::{Outcome, PanicDetails, Report};
+
+// A single runnable example, e.g. `traits::trait_drop`.
+#[derive(Debug, Clone, Copy)]
+pub struct Chapter {
+    pub krate: &'static str,
+    pub name: &'static str,
+    pub run: fn(),
+    // Why the chapter panics, for chapters that demonstrate a failure.
+    pub expected_panic: Option<&'static str>,
+}
+
+impl fmt::Display for Chapter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.krate, self.name)
+    }
+}
+
+impl Chapter {
+    // Runs the chapter, sending everything it prints to `out` instead of stdout.
+    pub fn run_to(&self, out: &mut dyn Write) -> io::Result<()> {
+        chapter::capture(out, self.run)
+    }
+}
+
+// `CHAPTERS` is generated by `build.rs` from the example crates.
+include!(concat!(env!("OUT_DIR"), "/chapters.rs"));
+
+// Matches `text` against a shell-style `pattern`, where `*` matches any run
+// of characters and `?` matches exactly one.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Where to resume after the last `*`: (pattern index, text index).
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character and retry.
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    t = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// A pattern without `::` names a whole crate, so `traits` means `traits::*`.
+fn matches(pattern: &str, chapter: &Chapter) -> bool {
+    match pattern.split_once("::") {
+        Some((krate, name)) => glob_match(krate, chapter.krate) && glob_match(name, chapter.name),
+        None => glob_match(pattern, chapter.krate),
+    }
+}
+
+// Returns the chapters matching `pattern`, in registry order.
+pub fn select(pattern: &str) -> Vec<&'static Chapter> {
+    CHAPTERS.iter().filter(|chapter| matches(pattern, chapter)).collect()
+}
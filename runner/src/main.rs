@@ -0,0 +1,91 @@
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+use runner::{select, Chapter, Report, CHAPTERS};
+
+const USAGE: &str = "\
+usage: runner <command>
+
+commands:
+    list [PATTERN...]   list the chapters, optionally only those matching
+    run PATTERN...      run the chapters matching any of the patterns
+    run-all             run every chapter
+
+A pattern is `crate::function`, where either side may use `*` and `?`
+wildcards (`run traits::trait_*`). A bare crate name runs the whole crate.";
+
+// Collects the chapters matching any of `patterns`, each at most once.
+fn collect(patterns: &[String]) -> Result<Vec<&'static Chapter>, String> {
+    let mut chapters: Vec<&'static Chapter> = Vec::new();
+    for pattern in patterns {
+        let matched = select(pattern);
+        if matched.is_empty() {
+            return Err(format!("no chapter matches `{pattern}`"));
+        }
+        for chapter in matched {
+            if !chapters.iter().any(|c| std::ptr::eq(*c, chapter)) {
+                chapters.push(chapter);
+            }
+        }
+    }
+    Ok(chapters)
+}
+
+// Runs each chapter with its panics caught, then prints a summary table.
+fn run(chapters: &[&'static Chapter]) -> io::Result<Report> {
+    let mut report = Report::new();
+    for chapter in chapters {
+        println!("== {chapter} ==");
+        let outcome = chapter.run_isolated(&mut io::stdout())?;
+        println!();
+        report.push(chapter, outcome);
+    }
+    println!("{report}");
+    Ok(report)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some((command, patterns)) = args.split_first() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    let chapters = match command.as_str() {
+        "list" if patterns.is_empty() => Ok(CHAPTERS.iter().collect()),
+        "list" | "run" if !patterns.is_empty() => collect(patterns),
+        "run-all" if patterns.is_empty() => Ok(CHAPTERS.iter().collect()),
+        "help" | "--help" | "-h" => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let chapters = match chapters {
+        Ok(chapters) => chapters,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if command == "list" {
+        for chapter in chapters {
+            println!("{chapter}");
+        }
+        return ExitCode::SUCCESS;
+    }
+    match run(&chapters) {
+        Ok(report) if report.failures() == 0 => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate chapter;
+
+use runner::{Chapter, Outcome, Report, CHAPTERS};
+
+fn passes() {
+    println!("fine");
+}
+
+fn fails() {
+    println!("before");
+    panic!("on purpose");
+}
+
+static PASSES: Chapter =
+    Chapter { krate: "test", name: "passes", run: passes, expected_panic: None };
+static FAILS: Chapter = Chapter { krate: "test", name: "fails", run: fails, expected_panic: None };
+static EXPECTED: Chapter =
+    Chapter { krate: "test", name: "expected", run: fails, expected_panic: Some("it should") };
+static MISSING: Chapter =
+    Chapter { krate: "test", name: "missing", run: passes, expected_panic: Some("it should") };
+
+fn run(chapter: &Chapter) -> (String, Outcome) {
+    let mut out = Vec::new();
+    let outcome = chapter.run_isolated(&mut out).unwrap();
+    (String::from_utf8(out).unwrap(), outcome)
+}
+
+#[test]
+fn a_chapter_that_returns_passes() {
+    assert_eq!(run(&PASSES), ("fine\n".to_string(), Outcome::Passed));
+    assert!(!Outcome::Passed.is_failure());
+}
+
+#[test]
+fn an_undeclared_panic_is_a_failure() {
+    let (out, outcome) = run(&FAILS);
+    // The output up to the panic is kept.
+    assert_eq!(out, "before\n");
+    let Outcome::Panicked(details) = &outcome else {
+        panic!("{outcome:?}");
+    };
+    assert_eq!(details.message, "on purpose");
+    assert!(details.location.starts_with("runner/tests/isolate.rs:"), "{}", details.location);
+    assert!(outcome.is_failure());
+}
+
+#[test]
+fn a_declared_panic_is_expected() {
+    let (out, outcome) = run(&EXPECTED);
+    assert_eq!(out, "before\n");
+    let Outcome::ExpectedPanic(reason, details) = &outcome else {
+        panic!("{outcome:?}");
+    };
+    assert_eq!((*reason, details.message.as_str()), ("it should", "on purpose"));
+    assert!(!outcome.is_failure());
+}
+
+#[test]
+fn a_declared_panic_that_never_happens_is_a_failure() {
+    let (out, outcome) = run(&MISSING);
+    assert_eq!(out, "fine\n");
+    assert_eq!(outcome, Outcome::MissingPanic("it should"));
+    assert!(outcome.is_failure());
+}
+
+#[test]
+fn the_report_counts_each_outcome() {
+    let mut report = Report::new();
+    for chapter in [&PASSES, &FAILS, &EXPECTED, &MISSING] {
+        report.push(chapter, run(chapter).1);
+    }
+    assert_eq!(report.failures(), 2);
+
+    let text = report.to_string();
+    let rows: Vec<&str> = text.lines().collect();
+    assert_eq!(rows[0], "chapter         result    details");
+    assert_eq!(rows[1], "test::passes    ok");
+    assert!(rows[2].starts_with("test::fails     PANIC     on purpose (runner/tests/isolate.rs:"));
+    let expected = "test::expected  expected  declared to panic (it should): on purpose (";
+    assert!(rows[3].starts_with(expected), "{}", rows[3]);
+    let missing = "test::missing   NO PANIC  declared to panic (it should) but returned normally";
+    assert_eq!(rows[4], missing);
+    assert_eq!(rows[5], "4 chapters: 1 passed, 1 panicked as expected, 2 failed");
+}
+
+#[test]
+fn arrays_slices_panics_as_declared() {
+    let chapter = CHAPTERS
+        .iter()
+        .find(|c| c.krate == "primitives" && c.name == "arrays_slices")
+        .unwrap();
+    assert!(chapter.expected_panic.is_some());
+    let (out, outcome) = run(chapter);
+    // Everything before the out-of-bounds index still runs.
+    assert!(out.contains("Next to (0, 0)"), "{out}");
+    let Outcome::ExpectedPanic(reason, details) = outcome else {
+        panic!("{outcome:?}");
+    };
+    assert_eq!(Some(reason), chapter.expected_panic);
+    assert!(details.message.starts_with("index out of bounds"), "{}", details.message);
+}
@@ -0,0 +1,54 @@
+// Golden-file tests for the output of every chapter.
+//
+// Each chapter's output is stored in `snapshots/<crate>/<chapter>.txt`. Any
+// drift, or a missing snapshot, fails the test with a line diff. Run with
+// `UPDATE_SNAPSHOTS=1` to record the current output.
+
+use std::path::{Path, PathBuf};
+
+use chapter::snapshot;
+
+use runner::{Chapter, Outcome, CHAPTERS};
+
+fn snapshot_path(chapter: &Chapter) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(chapter.krate)
+        .join(format!("{}.txt", chapter.name))
+}
+
+// Runs a chapter and returns what it printed. The message of an expected
+// panic is part of the snapshot; its location is left out because it moves
+// with every edit to the file.
+fn output_of(chapter: &Chapter) -> (String, Outcome) {
+    let mut out = Vec::new();
+    let outcome = chapter.run_isolated(&mut out).unwrap();
+    let mut text = String::from_utf8_lossy(&out).into_owned();
+    if let Outcome::ExpectedPanic(_, details) | Outcome::Panicked(details) = &outcome {
+        text.push_str(&format!("<panicked: {}>\n", details.message));
+    }
+    (text, outcome)
+}
+
+#[test]
+fn chapter_output_matches_snapshots() {
+    let mut failures = Vec::new();
+
+    for chapter in CHAPTERS {
+        let (actual, outcome) = output_of(chapter);
+        if outcome.is_failure() {
+            failures.push(format!("{chapter}: {outcome:?}\n"));
+            continue;
+        }
+        if let Err(drift) = snapshot::check(&snapshot_path(chapter), &actual) {
+            failures.push(format!("{chapter}: {drift}"));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} chapter(s) failed or drifted from their snapshots:\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
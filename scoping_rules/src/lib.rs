@@ -1,6 +1,17 @@
+#![allow(
+    dead_code,
+    clippy::extra_unused_lifetimes,
+    clippy::needless_lifetimes,
+    clippy::toplevel_ref_arg,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
 use std::fmt::Debug;
 
-fn raii() {
+pub fn raii() {
     fn create_box() {
         let _box1 = Box::new(3i32);
     }
@@ -25,7 +36,7 @@ fn raii() {
     // `_box2` is destroyed here, and memory gets freed
 }
 
-fn drop_test() {
+pub fn drop_test() {
     struct ToDrop;
 
     impl Drop for ToDrop {
@@ -38,7 +49,7 @@ fn drop_test() {
     println!("Made a ToDrop!");
 }
 
-fn ownership_moves() {
+pub fn ownership_moves() {
     fn mutability() {
         let immutable_box = Box::new(5u32);
 
@@ -114,7 +125,7 @@ fn ownership_moves() {
     // Error! `a` can no longer access the data, because it no longer owns the
     // heap memory
     // println!("a contains: {}", a);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0382)
 
     // This function takes ownership of the heap allocated memory from `b`
     destroy_box(b);
@@ -123,7 +134,7 @@ fn ownership_moves() {
     // result in dereferencing freed memory, but it's forbidden by the compiler
     // Error! Same reason as the previous Error
     //println!("b contains: {}", b);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0382)
 
 
     mutability();
@@ -132,7 +143,7 @@ fn ownership_moves() {
 }
 
 
-fn borrowing_mutability() {
+pub fn borrowing_mutability() {
     #[allow(dead_code)]
     #[derive(Clone, Copy)]
     struct Book {
@@ -178,7 +189,7 @@ fn borrowing_mutability() {
     // FIXME ^ Comment out this line
 }
 
-fn borrowing_aliasing() {
+pub fn borrowing_aliasing() {
     struct Point { x: i32, y: i32, z: i32 }
 
     let mut point = Point { x: 0, y: 0, z: 0 };
@@ -193,7 +204,7 @@ fn borrowing_aliasing() {
     // Error! Can't borrow `point` as mutable because it's currently
     // borrowed as immutable.
     // let mutable_borrow = &mut point;
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0502)
 
     // The borrowed values are used again here
     println!("Point has coordinates: ({}, {}, {})",
@@ -211,11 +222,11 @@ fn borrowing_aliasing() {
     // Error! Can't borrow `point` as immutable because it's currently
     // borrowed as mutable.
     // let y = &point.y;
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0502)
 
     // Error! Can't print because `println!` takes an immutable reference.
     // println!("Point Z coordinate is {}", point.z);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0502)
 
     // Ok! Mutable references can be passed as immutable to `println!`
     println!("Point has coordinates: ({}, {}, {})",
@@ -228,7 +239,7 @@ fn borrowing_aliasing() {
              new_borrowed_point.x, new_borrowed_point.y, new_borrowed_point.z);
 }
 
-fn borrowing_ref_pattern() {
+pub fn borrowing_ref_pattern() {
     #[derive(Clone, Copy)]
     struct Point { x: i32, y: i32 }
 
@@ -278,7 +289,7 @@ fn borrowing_ref_pattern() {
     println!("tuple is {:?}", mutable_tuple);
 }
 
-fn lifetime_explicit_annotation() {
+pub fn lifetime_explicit_annotation() {
     fn print_refs<'a, 'b>(x: &'a i32, y: &'b i32) {
         println!("x is {x} and y is {y}");
     }
@@ -293,7 +304,7 @@ fn lifetime_explicit_annotation() {
     failed_borrow();
 }
 
-fn lifetime_functions() {
+pub fn lifetime_functions() {
     fn print_one<'a>(x: &'a i32) {
         println!("'print one': x is {x}");
     }
@@ -320,7 +331,7 @@ fn lifetime_functions() {
     print_one(&t);
 }
 
-fn lifetime_methods() {
+pub fn lifetime_methods() {
     struct Owner(i32);
 
     impl Owner {
@@ -337,7 +348,7 @@ fn lifetime_methods() {
     owner.print();
 }
 
-fn lifetime_structures() {
+pub fn lifetime_structures() {
     #[derive(Debug)]
     struct Borrowed<'a>(&'a i32);
 
@@ -366,7 +377,7 @@ fn lifetime_structures() {
     println!("y is *not* borrowed in {number:?}");
 }
 
-fn lifetime_traits() {
+pub fn lifetime_traits() {
     #[derive(Debug)]
     struct Borrowed<'a> {
         x: &'a i32,
@@ -387,7 +398,7 @@ fn lifetime_traits() {
     println!("b is {b} and c is {c}");
 }
 
-fn lifetime_bounds() {
+pub fn lifetime_bounds() {
     #[derive(Debug)]
     struct Ref<'a, T: 'a>(&'a T);
 
@@ -405,7 +416,7 @@ fn lifetime_bounds() {
     print(ref_x);
 }
 
-fn lifetime_coercion() {
+pub fn lifetime_coercion() {
     fn multiply<'a>(first: &'a i32, second: &'a i32) -> i32 {
         first * second
     }
@@ -424,7 +435,7 @@ fn lifetime_coercion() {
     println!("{}", first);
 }
 
-fn lifetime_static() {
+pub fn lifetime_static() {
     static NUM: i32 = 18;
     fn coerce_static<'a, 'b>(_: &'a i32, _: &'b i32) -> &'b i32 {
         &NUM
@@ -453,7 +464,8 @@ fn lifetime_static() {
     }
     let first: &'static [usize; 100] = random_vec();
     let second: &'static [usize; 100] = random_vec();
-    assert_ne!(first, second);
+    // Both arrays are all zeros, so compare the leaked allocations themselves.
+    assert!(!std::ptr::eq(first, second));
 
     fn print_it( input: impl Debug+'static ) {
         println!( "'static value passed in is: {:?}", input );
@@ -469,7 +481,7 @@ fn lifetime_static() {
 
 }
 
-fn lifetime_elision() {
+pub fn lifetime_elision() {
     fn elided_input(x: &i32) {
         println!("`elided_input`: {}", x);
     }
@@ -490,21 +502,3 @@ fn lifetime_elision() {
     println!("`elided_pass`: {}", elided_pass(&x));
     println!("`annotated_pass`: {}", annotated_pass(&x));
 }
-
-fn main() {
-    raii();
-    drop_test();
-    ownership_moves();
-    borrowing_mutability();
-    borrowing_aliasing();
-    borrowing_ref_pattern();
-    lifetime_explicit_annotation();
-    lifetime_functions();
-    lifetime_methods();
-    lifetime_structures();
-    lifetime_traits();
-    lifetime_bounds();
-    lifetime_coercion();
-    lifetime_static();
-    lifetime_elision();
-}
@@ -0,0 +1,27 @@
+// Helpers for the example crates' integration tests and benches.
+
+// A xorshift generator, so property tests are repeatable without pulling in a
+// crate. The seed must not be zero.
+#[derive(Debug, Clone)]
+pub struct Rng(pub u64);
+
+impl Rng {
+    // Not `Iterator::next`: the stream never ends, so there's no `Option`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // Uniform in `0..1`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // In `0..n`, near enough uniform for `n` far below `u64::MAX`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
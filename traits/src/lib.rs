@@ -1,7 +1,20 @@
+#![allow(
+    dead_code,
+    clippy::assign_op_pattern,
+    clippy::let_and_return,
+    clippy::ptr_arg,
+    clippy::redundant_field_names,
+    clippy::useless_conversion,
+)]
+
+// `print!`/`println!` come from `chapter` so callers can capture the output.
+#[macro_use]
+extern crate chapter;
+
 use std::iter;
 use std::vec::IntoIter;
 
-fn hello() {
+pub fn hello() {
     struct Sheep {
         naked: bool,
         name: &'static str,
@@ -61,7 +74,7 @@ fn hello() {
     dolly.talk();
 }
 
-fn derive() {
+pub fn derive() {
     #[derive(PartialEq, PartialOrd)]
     struct Centimeters(f64);
 
@@ -83,11 +96,11 @@ fn derive() {
 
     // Error: `Seconds` can't be printed; it doesn't implement the `Debug` trait
     //println!("One second looks like: {:?}", _one_second);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0277)
 
     // Error: `Seconds` can't be compared; it doesn't implement the `PartialEq` trait
     //let _this_is_true = (_one_second == _one_second);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0369)
 
     let foot = Inches(12);
 
@@ -105,7 +118,7 @@ fn derive() {
     println!("One foot is {} than one meter.", cmp);
 }
 
-fn return_dyn() {
+pub fn return_dyn() {
     struct Sheep;
     struct Cow;
 
@@ -137,7 +150,7 @@ fn return_dyn() {
     println!("You've randomly chosen an animal, and it says {}", animal.noise());
 }
 
-fn operator_overloading() {
+pub fn operator_overloading() {
     use std::ops;
 
     struct Foo;
@@ -170,7 +183,7 @@ fn operator_overloading() {
     println!("Foo + Bar = {:?}", Bar+Foo);
 }
 
-fn trait_drop() {
+pub fn trait_drop() {
     struct Droppable {
         name: &'static str,
     }
@@ -197,7 +210,7 @@ fn trait_drop() {
     println!("End of drop function");
 }
 
-fn trait_iterator() {
+pub fn trait_iterator() {
     struct Fibonacci {
         curr: u32,
         next: u32,
@@ -254,7 +267,7 @@ fn trait_iterator() {
     }
 }
 
-fn trait_impl() {
+pub fn trait_impl() {
     fn parse_csv_document<R: std::io::BufRead>(src: R) -> std::io::Result<Vec<Vec<String>>> {
         src.lines()
             .map(|line| {
@@ -329,7 +342,7 @@ fn trait_impl() {
     assert_eq!(doubles.collect::<Vec<i32>>(), vec![4, 6]);
 }
 
-fn trait_clone() {
+pub fn trait_clone() {
     // A unit struct without resources
     #[derive(Debug, Clone, Copy)]
     struct Unit;
@@ -357,7 +370,7 @@ fn trait_clone() {
 
     // Error! `pair` has lost its resources
     //println!("original: {:?}", pair);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0382)
 
     // Clone `moved_pair` into `cloned_pair` (resources are included)
     let cloned_pair = moved_pair.clone();
@@ -366,13 +379,13 @@ fn trait_clone() {
 
     // Error! `moved_pair` has been dropped
     //println!("copy: {:?}", moved_pair);
-    // TODO ^ Try uncommenting this line
+    // TODO ^ Try uncommenting this line (E0382)
 
     // The result from .clone() can still be used!
     println!("clone: {:?}", cloned_pair);
 }
 
-fn supertraits() {
+pub fn supertraits() {
     trait Person {
         fn name(&self) -> String;
     }
@@ -404,7 +417,7 @@ fn supertraits() {
     }
 }
 
-fn disambiguating_overlaping_traits() {
+pub fn disambiguating_overlaping_traits() {
     trait UsernameWidget {
         fn get(&self) -> String;
     }
@@ -440,16 +453,3 @@ fn disambiguating_overlaping_traits() {
     let age = <dyn AgeWidget>::get(&form);
     assert_eq!(28, age);
 }
-
-fn main() {
-    hello();
-    derive();
-    return_dyn();
-    operator_overloading();
-    trait_drop();
-    trait_iterator();
-    trait_impl();
-    trait_clone();
-    supertraits();
-    disambiguating_overlaping_traits();
-}